@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use mesh_geometry_utils::{data_structures::VertexId, operations::constraints::Constraint};
+
+/// Constraint of a polygonal mesh.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolygonalMeshConstraint {
+    /// Geometric constraint on the polygon of the mesh.
+    Polygon(Constraint),
+    /// Vertex of the mesh stays on a vertex of another polygonal mesh.
+    Coincident {
+        /// Constrained vertex of the mesh.
+        vertex: VertexId,
+        /// Other polygonal mesh entity.
+        target: Entity,
+        /// Vertex of the other polygonal mesh.
+        target_vertex: VertexId,
+    },
+}
+
+/// Polygonal Mesh Constraints component.
+///
+/// Constraints solved while dragging the vertices of the polygonal mesh in edit mode.
+///
+/// Ref: [`solve_constraints`](mesh_geometry_utils::operations::constraints::solve_constraints).
+#[derive(Debug, Clone, Default, Component)]
+pub struct PolygonalMeshConstraints {
+    /// Constraints of the mesh.
+    pub constraints: Vec<PolygonalMeshConstraint>,
+    /// Indices of the constraints left unsatisfied by the last solve.
+    pub conflicts: Vec<usize>,
+}
+
+impl PolygonalMeshConstraints {
+    /// Create from a list of constraints.
+    pub fn new(constraints: Vec<PolygonalMeshConstraint>) -> Self {
+        Self {
+            constraints,
+            conflicts: vec![],
+        }
+    }
+
+    /// Add a constraint.
+    pub fn with(mut self, constraint: PolygonalMeshConstraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+}
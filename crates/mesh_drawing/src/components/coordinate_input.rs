@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// Coordinate input overlay (UI text) marker component.
+#[derive(Debug, Component)]
+pub struct CoordinateInputOverlay;
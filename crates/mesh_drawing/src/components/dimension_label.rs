@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use mesh_geometry_utils::data_structures::Edge;
+
+/// On-screen dimension label (UI text) marker component.
+///
+/// Holds what the label measures.
+#[derive(Debug, Component, Clone, PartialEq, Eq, Hash)]
+pub enum DimensionLabel {
+    /// Length of the edge of the selected polygon.
+    EdgeLength(Edge),
+    /// Area of the selected polygon.
+    Area,
+}
@@ -5,12 +5,14 @@ use mesh_geometry_utils::data_structures::{Edge, VertexId};
 ///
 /// Component to store all the active indicator entities(ids) on the Polygonal Mesh(s).
 ///
-#[derive(Debug, Component)]
+#[derive(Debug, Default, Component)]
 pub struct PolygonalMeshIndicators {
     /// Active edges indicator entities on this polygonal mesh.
     pub edges: Vec<Entity>,
     /// Active vertices indicator entities on this polygonal mesh.
     pub vertices: Vec<Entity>,
+    /// Active tangent handle indicator entities on this polygonal mesh.
+    pub tangent_handles: Vec<Entity>,
 }
 
 /// Vertex indicator marker component.
@@ -24,3 +26,16 @@ pub struct VertexIndicator(pub VertexId);
 /// Holds the `Edge` data (`from`/`to` *vertex id* of the vertices this indicator connects to).
 #[derive(Debug, Component)]
 pub struct EdgeIndicator(pub Edge);
+
+/// Tangent handle indicator marker component.
+///
+/// Holds the curved `Edge` and the index of the curve handle this indicator controls.
+///
+/// Ref: [`EdgeCurve::handles`](mesh_geometry_utils::data_structures::EdgeCurve::handles).
+#[derive(Debug, Component)]
+pub struct TangentHandleIndicator {
+    /// Curved edge of the handle.
+    pub edge: Edge,
+    /// Index of the handle on the edge curve.
+    pub index: usize,
+}
@@ -1,13 +1,25 @@
 pub mod camera;
 pub mod canvas;
 pub mod cleanup;
+pub mod constraints;
+pub mod coordinate_input;
+pub mod dimension_label;
 pub mod grab_transformable;
 pub mod indicators;
 pub mod polygonal_mesh;
+pub mod symmetry;
 
 pub use camera::MeshDrawingCamera;
 pub use canvas::Canvas;
 pub use cleanup::Cleanup;
+pub use constraints::{PolygonalMeshConstraint, PolygonalMeshConstraints};
+pub use coordinate_input::CoordinateInputOverlay;
+pub use dimension_label::DimensionLabel;
 pub use grab_transformable::GrabTransformable;
-pub use indicators::{EdgeIndicator, PolygonalMeshIndicators, VertexIndicator};
-pub use polygonal_mesh::PolygonalMesh;
+pub use indicators::{
+    EdgeIndicator, PolygonalMeshIndicators, TangentHandleIndicator, VertexIndicator,
+};
+pub use polygonal_mesh::{
+    PolygonalMesh, PolygonalMeshGenerationPending, PolygonalMeshGenerationTask, PolygonalMeshSolid,
+};
+pub use symmetry::{SymmetricPolygonalMesh, SymmetryLink};
@@ -1,5 +1,5 @@
-use bevy::prelude::*;
-use mesh_geometry_utils::data_structures::MeshPolygon;
+use bevy::{prelude::*, tasks::Task};
+use mesh_geometry_utils::{data_structures::MeshPolygon, mesh_builder::lathe::LatheOptions};
 
 /// Polygonal Mesh component.
 ///
@@ -12,4 +12,74 @@ pub struct PolygonalMesh {
     pub mesh_polygon: MeshPolygon,
     /// Handle to the current mesh of the editing entity.
     pub mesh_handle: Option<Handle<Mesh>>,
+    /// Kind of solid generated from the polygon.
+    pub solid: PolygonalMeshSolid,
 }
+
+impl PolygonalMesh {
+    /// Generate a new [`Mesh`] from the polygon based on the [`PolygonalMeshSolid`] kind.
+    ///
+    /// `extrude_size` is only used for [`PolygonalMeshSolid::Extrusion`].
+    pub fn generate_mesh(&mut self, extrude_size: f32) -> Option<Mesh> {
+        match self.solid {
+            PolygonalMeshSolid::Extrusion => self.mesh_polygon.extrude_to_bevy_mesh(extrude_size),
+            PolygonalMeshSolid::Lathe(options) => self.mesh_polygon.lathe_to_bevy_mesh(&options),
+        }
+    }
+
+    /// Update the mesh generated from the polygon in place, keeping its triangulation.
+    ///
+    /// Returns `false` (mesh untouched) if the mesh needs to be generated again
+    /// (changed topology, invalid triangulation or [`PolygonalMeshSolid::Lathe`]).
+    pub fn update_mesh(&mut self, mesh: &mut Mesh, extrude_size: f32) -> bool {
+        match self.solid {
+            PolygonalMeshSolid::Extrusion => self
+                .mesh_polygon
+                .update_extruded_bevy_mesh(mesh, extrude_size),
+            PolygonalMeshSolid::Lathe(_) => false,
+        }
+    }
+
+    /// Volume of the solid generated from the polygon.
+    ///
+    /// `extrude_size` is only used for [`PolygonalMeshSolid::Extrusion`].
+    pub fn volume(&self, extrude_size: f32) -> f32 {
+        match self.solid {
+            PolygonalMeshSolid::Extrusion => self.mesh_polygon.extruded_volume(extrude_size),
+            PolygonalMeshSolid::Lathe(options) => self.mesh_polygon.lathe_volume(&options),
+        }
+    }
+
+    /// Surface area of the solid generated from the polygon.
+    ///
+    /// `extrude_size` is only used for [`PolygonalMeshSolid::Extrusion`].
+    pub fn surface_area(&self, extrude_size: f32) -> f32 {
+        match self.solid {
+            PolygonalMeshSolid::Extrusion => self.mesh_polygon.extruded_surface_area(extrude_size),
+            PolygonalMeshSolid::Lathe(options) => self.mesh_polygon.lathe_surface_area(&options),
+        }
+    }
+}
+
+/// Kind of solid generated from the [`MeshPolygon`] of a [`PolygonalMesh`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum PolygonalMeshSolid {
+    /// Extrude the polygon along the canvas normal.
+    #[default]
+    Extrusion,
+    /// Lathe (revolve) the polygon as a profile around an axis in canvas space.
+    Lathe(LatheOptions),
+}
+
+/// Marker of the [`PolygonalMesh`] entities whose mesh is being generated in
+/// the background, the previous mesh is shown until the new one is ready.
+///
+/// Use to show the progress (eg: a spinner) in the UI.
+#[derive(Debug, Component)]
+pub struct PolygonalMeshGenerationPending;
+
+/// Background task generating the mesh of a [`PolygonalMesh`].
+///
+/// Replaced (dropping and cancelling it) when the polygon changes again before it finishes.
+#[derive(Debug, Component)]
+pub struct PolygonalMeshGenerationTask(pub(crate) Task<Option<Mesh>>);
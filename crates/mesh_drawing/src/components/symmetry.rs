@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+use mesh_geometry_utils::operations::symmetry::Symmetry;
+
+/// Symmetric Polygonal Mesh component.
+///
+/// Marks meshes whose polygon is welded from its symmetric halves/sectors.
+/// Moving a vertex in edit mode moves its symmetric counterparts, vertices
+/// on the mirror axis (at the radial center) stay on it.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct SymmetricPolygonalMesh {
+    /// Symmetry (mesh local) of the polygon.
+    pub symmetry: Symmetry,
+    /// Distance within which vertices are treated as symmetric counterparts.
+    pub weld_tolerance: f32,
+}
+
+/// Symmetry Link component.
+///
+/// Links the symmetric copies of a polygonal mesh. Editing any of the copies
+/// updates the others.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct SymmetryLink {
+    /// Symmetry (mesh local) of the copies.
+    pub symmetry: Symmetry,
+    /// Mesh entity the copies were created from (identity image), shared by all the copies.
+    pub source: Entity,
+    /// Image of the symmetry this copy is.
+    pub index: usize,
+}
@@ -0,0 +1,14 @@
+use bevy::prelude::{Entity, Event};
+
+use crate::components::constraints::PolygonalMeshConstraint;
+
+/// Triggered when the constraints of a polygonal mesh can not all be satisfied.
+///
+/// Sent when the set of conflicting constraints changes.
+#[derive(Debug, Clone, Event)]
+pub struct PolygonalMeshConstraintConflict {
+    /// Polygonal mesh entity.
+    pub entity: Entity,
+    /// Constraints left unsatisfied.
+    pub constraints: Vec<PolygonalMeshConstraint>,
+}
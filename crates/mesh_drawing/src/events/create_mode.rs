@@ -6,7 +6,17 @@ pub enum CreateModeEvent {
     /// Triggered when a vertex needs to be added to active `MeshPolygon`.
     VertexAdd(Vec3),
     /// Triggered when we need to close the polygon. And Create/extrude it into mesh.
+    ///
+    /// Cancels the shape being dragged out instead, if any.
     PolygonCloseAndIntoMeshExtrude,
+    /// Triggered when the pointer is pressed on canvas with a shape tool.
+    ShapePointerDown(Vec3),
+    /// Triggered when the pointer moves on canvas while a shape is being dragged out.
+    ShapePointerMove(Vec3),
+    /// Triggered when the pointer is released while a shape is being dragged out.
+    ShapePointerUp,
+    /// Triggered when the create mode tool needs to be switched to the next one.
+    ToolCycle,
     /// Triggered when drawing mode is switched to `EditMode`.
     EditModeSwitch,
 }
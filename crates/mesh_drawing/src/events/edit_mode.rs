@@ -1,4 +1,4 @@
-use bevy::prelude::{Entity, Vec3, Event};
+use bevy::prelude::{Entity, Event, Vec2, Vec3};
 use mesh_geometry_utils::data_structures::Edge;
 
 /// Edit Mode Event enum.
@@ -14,6 +14,11 @@ pub enum EditModeEvent {
     VertexIndicatorJustPressed(Entity),
     /// Triggered when a vertex indicator is just released.
     VertexIndicatorJustReleased,
+    /// Triggered when an exact (typed) position is entered for a vertex.
+    ///
+    /// `Entity`: Vertex indicator entity.
+    /// `Vec2`: Position of the vertex (mesh local `x`, `z`).
+    VertexPositionSet(Entity, Vec2),
     /// Reset. When all entity/indicators need to be deselected & deactivated.
     Reset,
     /// Triggered when drawing mode is switched to `CreateMode`.
@@ -24,6 +29,40 @@ pub enum EditModeEvent {
     ///
     /// `Entity`: Vertex indicator entity.
     VertexRemove(Entity),
+    /// Triggered when an edge needs to be converted from straight to curved or back.
+    ///
+    /// `Edge`: Edge to toggle.
+    EdgeCurveToggle(Edge),
+    /// Triggered when the corner at a vertex needs to be rounded (fillet).
+    ///
+    /// `Entity`: Vertex indicator entity.
+    VertexFillet(Entity),
+    /// Triggered when the corner at a vertex needs to be cut (chamfer).
+    ///
+    /// `Entity`: Vertex indicator entity.
+    VertexChamfer(Entity),
+    /// Triggered when a Mesh entity is added to/removed from the multi selection.
+    ///
+    /// `Entity`: Toggled polygonal mesh entity.
+    PolygonalMeshMultiSelect(Entity),
+    /// Triggered when the multi selected meshes need to be merged into one.
+    MergeSelected,
+    /// Triggered when the active mesh needs to be deleted.
+    PolygonalMeshDelete,
+    /// Triggered when the active mesh needs to be duplicated (moved by the duplicate offset).
+    PolygonalMeshDuplicate,
+    /// Triggered when a mirrored copy of the active mesh needs to be created.
+    PolygonalMeshMirror,
+    /// Triggered when a linear array of the active mesh needs to be created.
+    PolygonalMeshLinearArray,
+    /// Triggered when a circular array of the active mesh needs to be created.
+    PolygonalMeshCircularArray,
+    /// Triggered when the knife tool needs to be activated/deactivated on the active mesh.
+    KnifeToggle,
+    /// Triggered when a point of the knife cut path is clicked.
+    ///
+    /// `Vec3`: Translation of the point (wrt canvas).
+    KnifePointAdd(Vec3),
 }
 
 /// Data of the `VertexInsert` event.
@@ -0,0 +1,88 @@
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::{Entity, Event, EventWriter},
+};
+use mesh_geometry_utils::data_structures::VertexId;
+
+use crate::resources::DrawingMode;
+
+/// Triggered when a polygonal mesh is created.
+///
+/// Sent for meshes created in create mode (including symmetric copies) and
+/// for the results of edit mode operations (knife pieces, merged meshes, duplicates/arrays).
+#[derive(Debug, Clone, Event)]
+pub struct PolygonalMeshCreated {
+    /// Created polygonal mesh entity.
+    pub entity: Entity,
+}
+
+/// Kind of change of the geometry of a polygonal mesh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryChangeKind {
+    /// Vertices moved (drag finished or typed position applied).
+    VertexMoved,
+    /// Vertex inserted on an edge.
+    VertexInserted,
+    /// Vertex removed.
+    VertexRemoved,
+    /// Edge toggled between straight and curved, or its tangent handles moved.
+    EdgeCurveChanged,
+    /// Corner rounded (fillet) or cut (chamfer).
+    CornerModified,
+}
+
+/// Triggered when the geometry of a polygonal mesh is changed in edit mode.
+///
+/// Drags are reported once, when the drag finishes.
+#[derive(Debug, Clone, Event)]
+pub struct PolygonalMeshGeometryChanged {
+    /// Changed polygonal mesh entity.
+    pub entity: Entity,
+    /// Kind of the change.
+    pub kind: GeometryChangeKind,
+    /// Affected vertices.
+    ///
+    /// Removed vertices (incl. the corner replaced by fillet/chamfer) come first.
+    pub vertices: Vec<VertexId>,
+}
+
+/// Triggered when a polygonal mesh is deleted in edit mode.
+///
+/// Also sent for meshes replaced by the results of edit mode operations (knife, merge).
+/// The entity (along with its children) is despawned at the end of the frame.
+#[derive(Debug, Clone, Event)]
+pub struct PolygonalMeshDeleted {
+    /// Deleted polygonal mesh entity.
+    pub entity: Entity,
+}
+
+/// Triggered when the active (selected) polygonal mesh of the edit mode changes.
+#[derive(Debug, Clone, Event)]
+pub struct PolygonalMeshSelectionChanged {
+    /// Newly selected polygonal mesh entity, if any.
+    pub selected: Option<Entity>,
+    /// Previously selected polygonal mesh entity, if any.
+    pub previous: Option<Entity>,
+}
+
+/// Triggered when the drawing mode is switched.
+#[derive(Debug, Clone, Event)]
+pub struct DrawingModeChanged {
+    /// New drawing mode.
+    pub mode: DrawingMode,
+}
+
+/// Writers of the polygonal mesh lifecycle events.
+#[derive(SystemParam)]
+pub struct LifecycleEventWriters<'w> {
+    /// Writer of [`PolygonalMeshCreated`].
+    pub created: EventWriter<'w, PolygonalMeshCreated>,
+    /// Writer of [`PolygonalMeshGeometryChanged`].
+    pub geometry_changed: EventWriter<'w, PolygonalMeshGeometryChanged>,
+    /// Writer of [`PolygonalMeshDeleted`].
+    pub deleted: EventWriter<'w, PolygonalMeshDeleted>,
+    /// Writer of [`PolygonalMeshSelectionChanged`].
+    pub selection_changed: EventWriter<'w, PolygonalMeshSelectionChanged>,
+    /// Writer of [`DrawingModeChanged`].
+    pub mode_changed: EventWriter<'w, DrawingModeChanged>,
+}
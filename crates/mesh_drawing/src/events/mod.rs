@@ -1,3 +1,5 @@
+pub mod constraint;
 pub mod create_mode;
 pub mod edit_mode;
+pub mod lifecycle;
 pub mod picker;
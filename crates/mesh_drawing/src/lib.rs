@@ -10,12 +10,42 @@ pub mod prelude {
     // plugin...
     pub use crate::plugin::MeshDrawingPlugin;
     // components...
-    pub use crate::components::{Canvas, MeshDrawingCamera, PolygonalMesh};
+    pub use crate::components::{
+        Canvas, MeshDrawingCamera, PolygonalMesh, PolygonalMeshConstraint,
+        PolygonalMeshConstraints, PolygonalMeshGenerationPending, PolygonalMeshSolid,
+        SymmetricPolygonalMesh, SymmetryLink,
+    };
+    // events...
+    pub use crate::events::constraint::PolygonalMeshConstraintConflict;
+    pub use crate::events::lifecycle::{
+        DrawingModeChanged, GeometryChangeKind, PolygonalMeshCreated, PolygonalMeshDeleted,
+        PolygonalMeshGeometryChanged, PolygonalMeshSelectionChanged,
+    };
+    // geometry...
+    pub use mesh_geometry_utils::data_structures::{Edge, MeshPolygon, VertexId};
+    pub use mesh_geometry_utils::mesh_builder::lathe::{LatheAxis, LatheOptions};
+    pub use mesh_geometry_utils::operations::constraints::{Constraint, SolverOptions};
+    pub use mesh_geometry_utils::operations::stroke::{StrokeOptions, StrokeSimplification};
+    pub use mesh_geometry_utils::operations::symmetry::Symmetry;
+    // commands...
+    pub use crate::utils::commands::{PolygonalMeshCommandsExt, PolygonalMeshOptions};
+    // queries...
+    pub use crate::systems::raycast::CanvasRaycast;
+    pub use crate::utils::spatial_query::PolygonalMeshSpatialQuery;
+    // canvas coordinates...
+    pub use crate::utils::canvas_correction::{canvas_to_world, world_to_canvas};
     // settings...
-    pub use crate::resources::{MeshDrawingPluginInputBinds, MeshDrawingPluginSettings};
+    pub use crate::resources::{
+        ArraySettings, CanvasRaycastMode, CreateModeTool, DimensionLabelSettings, DimensionUnits,
+        IndicatorAssets, IndicatorMode, IndicatorState, MeshDrawingPluginInputBinds,
+        MeshDrawingPluginSettings, SymmetryMode, SymmetrySettings,
+    };
+    // theme...
+    pub use crate::resources::{IndicatorColors, IndicatorStyle, MeshDrawingTheme};
 }
 
 /// Re-exports of internal libraries.
 pub mod re_exports {
     pub use bevy_mod_raycast;
-}
\ No newline at end of file
+    pub use mesh_geometry_utils;
+}
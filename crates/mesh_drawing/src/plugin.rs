@@ -1,23 +1,50 @@
-use bevy::prelude::*;
+use bevy::{input::InputSystem, prelude::*, transform::TransformSystem, ui::UiSystem};
 use bevy_mod_picking::DefaultPickingPlugins;
 use bevy_mod_raycast::deferred::DeferredRaycastingPlugin;
 use bevy_mod_raycast::prelude::RaycastSystem;
 
 use crate::{
-    events::{create_mode::CreateModeEvent, edit_mode::EditModeEvent, picker::PickerClickEvent},
-    resources::MeshDrawingPluginSettings,
-    resources::{DrawingMode, DrawingState, PluginState},
+    events::{
+        constraint::PolygonalMeshConstraintConflict,
+        create_mode::CreateModeEvent,
+        edit_mode::EditModeEvent,
+        lifecycle::{
+            DrawingModeChanged, PolygonalMeshCreated, PolygonalMeshDeleted,
+            PolygonalMeshGeometryChanged, PolygonalMeshSelectionChanged,
+        },
+        picker::PickerClickEvent,
+    },
+    resources::{
+        CanvasRaycastMode, DrawingMode, DrawingState, IndicatorAssets, IndicatorMode,
+        MeshDrawingPluginSettings, MeshDrawingTheme, PluginState,
+    },
     systems::{
+        array::handle_array_input,
         cleanup::cleanup_all,
-        create_mode::handle_create_mode_events,
+        constraints::draw_constraint_glyphs,
+        coordinate_input::{handle_coordinate_input, update_coordinate_input_overlay},
+        corner::handle_corner_input,
+        create_mode::{draw_shape_draft_preview, handle_create_mode_events},
         debug::debug_edit_mode_events,
+        delete::handle_delete_input,
+        dimension_labels::{handle_dimension_labels_toggle, update_dimension_labels},
         drawing_mode::handle_drawing_mode_transition,
-        edit_mode::{handle_active_indicator, handle_edit_mode_events},
+        edit_mode::{draw_edge_curve_guides, handle_active_indicator, handle_edit_mode_events},
         grab_transformer::handle_vertex_indicator_grab,
+        indicators::{
+            apply_theme_changes, draw_gizmo_indicators, handle_gizmo_indicator_picking,
+            scale_indicators_to_screen_size, update_indicator_states,
+        },
+        knife::{draw_knife_preview, handle_knife_input},
+        merge::{draw_multi_selection_outlines, handle_merge_input},
         picker::{
-            add_picker_click_event_to_pickable, handle_picker_events,
+            add_picker_click_event_to_pickable, handle_canvas_plane_clicks, handle_picker_events,
             remove_picker_click_event_from_prev_pickable,
         },
+        polygonal_mesh::{
+            apply_mesh_generation_tasks, insert_polygonal_mesh_materials,
+            regenerate_changed_polygonal_meshes,
+        },
         raycast::{
             disable_raycast_on_camera_remove, disable_raycast_on_canvas_remove,
             enable_raycast_on_camera_add, enable_raycast_on_canvas_add,
@@ -26,6 +53,7 @@ use crate::{
             MeshDrawingRaycastSet, VertexGrabbingRaycastSet,
         },
         state::initialize_plugin_if_ready,
+        symmetry::{draw_symmetry_guides, handle_symmetry_toggle, sync_symmetry_links},
     },
 };
 
@@ -41,11 +69,19 @@ impl Plugin for MeshDrawingPlugin {
             // Plugin settings
             .insert_resource(MeshDrawingPluginSettings::default())
             .add_plugins(DefaultPickingPlugins)
+            // Theme (kept if inserted before the plugin)
+            .init_resource::<MeshDrawingTheme>()
             // Drawing state
             .insert_resource(DrawingState::default())
             // Configure events...
             .add_event::<EditModeEvent>()
             .add_event::<CreateModeEvent>()
+            .add_event::<PolygonalMeshConstraintConflict>()
+            .add_event::<PolygonalMeshCreated>()
+            .add_event::<PolygonalMeshGeometryChanged>()
+            .add_event::<PolygonalMeshDeleted>()
+            .add_event::<PolygonalMeshSelectionChanged>()
+            .add_event::<DrawingModeChanged>()
             // Ray-cast stuff...
             .add_plugins(DeferredRaycastingPlugin::<MeshDrawingRaycastSet>::default())
             .add_plugins(DeferredRaycastingPlugin::<VertexGrabbingRaycastSet>::default())
@@ -77,7 +113,15 @@ impl Plugin for MeshDrawingPlugin {
                 Update,
                 handle_vertex_grabbing_raycast_intersections
                     .run_if(in_state(PluginState::Initialized))
-                    .run_if(is_running_in_edit_mode),
+                    .run_if(is_running_in_edit_mode)
+                    .run_if(is_using_entity_indicators),
+            )
+            .add_systems(
+                Update,
+                handle_gizmo_indicator_picking
+                    .run_if(in_state(PluginState::Initialized))
+                    .run_if(is_running_in_edit_mode)
+                    .run_if(is_using_gizmo_indicators),
             )
             // grab transformer
             .add_systems(
@@ -102,6 +146,13 @@ impl Plugin for MeshDrawingPlugin {
                 PostUpdate,
                 handle_picker_events.run_if(in_state(PluginState::Initialized)),
             )
+            .add_systems(
+                Update,
+                handle_canvas_plane_clicks
+                    .run_if(in_state(PluginState::Initialized))
+                    .run_if(is_running_in_edit_mode)
+                    .run_if(is_using_canvas_plane),
+            )
             // edit mode stuff..
             .add_systems(
                 First,
@@ -111,7 +162,32 @@ impl Plugin for MeshDrawingPlugin {
             )
             .add_systems(
                 Update,
-                handle_active_indicator
+                (handle_active_indicator, sync_symmetry_links)
+                    .chain()
+                    .run_if(in_state(PluginState::Initialized))
+                    .run_if(is_running_in_edit_mode),
+            )
+            .add_systems(
+                Update,
+                (handle_knife_input, draw_knife_preview)
+                    .run_if(in_state(PluginState::Initialized))
+                    .run_if(is_running_in_edit_mode),
+            )
+            .add_systems(
+                Update,
+                (
+                    handle_corner_input,
+                    handle_array_input,
+                    handle_delete_input,
+                    handle_merge_input,
+                    draw_multi_selection_outlines,
+                )
+                    .run_if(in_state(PluginState::Initialized))
+                    .run_if(is_running_in_edit_mode),
+            )
+            .add_systems(
+                Update,
+                (draw_edge_curve_guides, draw_constraint_glyphs)
                     .run_if(in_state(PluginState::Initialized))
                     .run_if(is_running_in_edit_mode),
             )
@@ -122,11 +198,73 @@ impl Plugin for MeshDrawingPlugin {
                     .run_if(in_state(PluginState::Initialized))
                     .run_if(is_running_in_create_mode),
             )
+            .add_systems(
+                Update,
+                draw_shape_draft_preview
+                    .run_if(in_state(PluginState::Initialized))
+                    .run_if(is_running_in_create_mode),
+            )
             // drawing mode transition...
             .add_systems(
                 Update,
                 handle_drawing_mode_transition.run_if(in_state(PluginState::Initialized)),
             )
+            // indicator visuals (after the picking highlights), theme...
+            .add_systems(
+                Update,
+                (apply_theme_changes, update_indicator_states)
+                    .chain()
+                    .run_if(in_state(PluginState::Initialized)),
+            )
+            .add_systems(
+                Update,
+                draw_gizmo_indicators
+                    .run_if(in_state(PluginState::Initialized))
+                    .run_if(is_using_gizmo_indicators),
+            )
+            // coordinate input...
+            .add_systems(
+                PreUpdate,
+                handle_coordinate_input
+                    .run_if(in_state(PluginState::Initialized))
+                    .after(InputSystem),
+            )
+            .add_systems(
+                Update,
+                update_coordinate_input_overlay.run_if(in_state(PluginState::Initialized)),
+            )
+            // dimension labels...
+            .add_systems(
+                Update,
+                handle_dimension_labels_toggle.run_if(in_state(PluginState::Initialized)),
+            )
+            .add_systems(
+                PostUpdate,
+                update_dimension_labels
+                    .run_if(in_state(PluginState::Initialized))
+                    .after(TransformSystem::TransformPropagate)
+                    .before(UiSystem::Layout),
+            )
+            // symmetry...
+            .add_systems(
+                Update,
+                (handle_symmetry_toggle, draw_symmetry_guides)
+                    .run_if(in_state(PluginState::Initialized)),
+            )
+            // mesh regeneration (once per frame, after all the edits), background tasks...
+            .add_systems(
+                PostUpdate,
+                (
+                    insert_polygonal_mesh_materials,
+                    apply_mesh_generation_tasks,
+                    regenerate_changed_polygonal_meshes,
+                    scale_indicators_to_screen_size,
+                )
+                    .chain()
+                    .run_if(in_state(PluginState::Initialized))
+                    .after(handle_picker_events)
+                    .before(TransformSystem::TransformPropagate),
+            )
             // cleanup stuff...
             .add_systems(Last, cleanup_all)
             // debug stuff...
@@ -137,6 +275,11 @@ impl Plugin for MeshDrawingPlugin {
                     .run_if(is_running_in_edit_mode),
             );
     }
+
+    fn finish(&self, app: &mut App) {
+        // Shared indicator meshes/materials, once the asset plugins are built
+        app.init_resource::<IndicatorAssets>();
+    }
 }
 
 fn is_running_in_edit_mode(drawing_state: Res<DrawingState>) -> bool {
@@ -152,3 +295,18 @@ fn is_running_in_create_mode(drawing_state: Res<DrawingState>) -> bool {
         DrawingMode::CreateMode(_) => true,
     }
 }
+
+fn is_using_entity_indicators(plugin_settings: Res<MeshDrawingPluginSettings>) -> bool {
+    plugin_settings.indicator_mode == IndicatorMode::Entities
+}
+
+fn is_using_gizmo_indicators(plugin_settings: Res<MeshDrawingPluginSettings>) -> bool {
+    plugin_settings.indicator_mode == IndicatorMode::Gizmos
+}
+
+fn is_using_canvas_plane(plugin_settings: Res<MeshDrawingPluginSettings>) -> bool {
+    matches!(
+        plugin_settings.canvas_raycast_mode,
+        CanvasRaycastMode::Plane { .. }
+    )
+}
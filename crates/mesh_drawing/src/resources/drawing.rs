@@ -1,11 +1,23 @@
-use bevy::prelude::{Entity, Resource};
-use mesh_geometry_utils::data_structures::MeshPolygon;
+use bevy::prelude::{Entity, Resource, Vec2};
+use mesh_geometry_utils::{
+    data_structures::{Edge, MeshPolygon, VertexId},
+    operations::{
+        primitives::{circle, ellipse, rectangle, regular_polygon, rotated_rectangle},
+        stroke::stroke_to_mesh_polygon,
+    },
+};
+
+use crate::events::lifecycle::PolygonalMeshGeometryChanged;
+
+use super::MeshDrawingPluginSettings;
 
 /// State of the drawing.
 #[derive(Debug, Default, Clone, Resource)]
 pub struct DrawingState {
     /// Mod of drawing.
     pub mode: DrawingMode,
+    /// Typed coordinates, `Some` while the coordinate input overlay is open.
+    pub coordinate_input: Option<String>,
 }
 
 /// Mode of the drawing.
@@ -30,6 +42,32 @@ pub struct EditModeState {
     pub active_mesh: Option<Entity>,
     /// Currently active indicator or None if no active.
     pub active_vertex_indicator: Option<Entity>,
+    /// Last pressed vertex indicator, target of vertex operations (eg: fillet).
+    pub selected_vertex_indicator: Option<Entity>,
+    /// Cut path (mesh local) clicked with the knife tool, `Some` while the knife is active.
+    pub knife_path: Option<Vec<Vec2>>,
+    /// Meshes selected together using the multi select key (eg: for merging).
+    pub multi_selection: Vec<Entity>,
+    /// Geometry change of the current drag, sent when the drag finishes.
+    pub drag_change: Option<PolygonalMeshGeometryChanged>,
+    /// Indicator under the cursor, only picked in [`IndicatorMode::Gizmos`](super::IndicatorMode::Gizmos).
+    pub hovered_gizmo_indicator: Option<GizmoIndicator>,
+}
+
+/// Indicator drawn (and picked) in [`IndicatorMode::Gizmos`](super::IndicatorMode::Gizmos).
+#[derive(Debug, Clone, PartialEq)]
+pub enum GizmoIndicator {
+    /// Vertex indicator.
+    Vertex(VertexId),
+    /// Edge indicator.
+    Edge(Edge),
+    /// Tangent handle indicator of a curved edge.
+    TangentHandle {
+        /// Curved edge of the handle.
+        edge: Edge,
+        /// Index of the handle on the edge curve.
+        index: usize,
+    },
 }
 
 /// Create mode drawing state.
@@ -39,4 +77,107 @@ pub struct CreateModeState {
     pub mesh_polygon: MeshPolygon,
     /// Check if hovering over draw canvas. Not Yet Implemented.
     pub is_hovering_on_canvas: bool,
+    /// Shape being dragged out with one of the shape tools, if any.
+    pub shape_draft: Option<ShapeDraft>,
+}
+
+/// Tool used to create new polygons in create mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CreateModeTool {
+    /// Click vertices one by one.
+    #[default]
+    Polygon,
+    /// Drag from one corner to the opposite corner.
+    Rectangle,
+    /// Drag out the base edge, then click to set the width.
+    RotatedRectangle,
+    /// Drag from center to set the radius.
+    Circle,
+    /// Drag from center to set the radii along canvas axes.
+    Ellipse,
+    /// Drag from center to the first vertex.
+    RegularPolygon,
+    /// Hold and sketch the outline, closed on release.
+    Lasso,
+}
+
+impl CreateModeTool {
+    /// Next tool, used to cycle through the tools.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Polygon => Self::Rectangle,
+            Self::Rectangle => Self::RotatedRectangle,
+            Self::RotatedRectangle => Self::Circle,
+            Self::Circle => Self::Ellipse,
+            Self::Ellipse => Self::RegularPolygon,
+            Self::RegularPolygon => Self::Lasso,
+            Self::Lasso => Self::Polygon,
+        }
+    }
+
+    /// Check if the tool drags out a shape (instead of clicking vertices).
+    pub fn is_shape(self) -> bool {
+        self != Self::Polygon
+    }
+}
+
+/// Shape being dragged out in create mode.
+#[derive(Debug, Clone)]
+pub struct ShapeDraft {
+    /// Tool the shape was started with.
+    pub tool: CreateModeTool,
+    /// Fixed points of the shape (canvas local).
+    ///
+    /// For [`CreateModeTool::Lasso`] these are the sampled stroke points.
+    pub anchors: Vec<Vec2>,
+    /// Current cursor position (canvas local).
+    pub cursor: Vec2,
+}
+
+impl ShapeDraft {
+    /// Start a new draft at the given point.
+    pub fn new(tool: CreateModeTool, start: Vec2) -> Self {
+        Self {
+            tool,
+            anchors: vec![start],
+            cursor: start,
+        }
+    }
+
+    /// Check if another anchor is needed before the shape can be completed.
+    pub fn needs_more_anchors(&self) -> bool {
+        match self.tool {
+            CreateModeTool::RotatedRectangle => self.anchors.len() < 2,
+            _ => self.anchors.is_empty(),
+        }
+    }
+
+    /// Build the [`MeshPolygon`] of the shape with the current cursor.
+    ///
+    /// Shape parameters (segments, sides, stroke options) are taken from the `settings`.
+    /// Returns `None` if the shape is not complete or is degenerate.
+    pub fn mesh_polygon(&self, settings: &MeshDrawingPluginSettings) -> Option<MeshPolygon> {
+        if self.needs_more_anchors() {
+            return None;
+        }
+        let start = self.anchors[0];
+        let segments = settings.shape_segments;
+        match self.tool {
+            CreateModeTool::Polygon => None,
+            CreateModeTool::Rectangle => rectangle(start, self.cursor),
+            CreateModeTool::RotatedRectangle => {
+                rotated_rectangle(start, self.anchors[1], self.cursor)
+            }
+            CreateModeTool::Circle => circle(start, start.distance(self.cursor), segments),
+            CreateModeTool::Ellipse => ellipse(start, self.cursor - start, 0., segments),
+            CreateModeTool::RegularPolygon => {
+                regular_polygon(start, self.cursor, settings.regular_polygon_sides)
+            }
+            CreateModeTool::Lasso => {
+                let mut stroke = self.anchors.clone();
+                stroke.push(self.cursor);
+                stroke_to_mesh_polygon(&stroke, &settings.lasso)
+            }
+        }
+    }
 }
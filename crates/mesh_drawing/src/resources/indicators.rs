@@ -0,0 +1,181 @@
+use bevy::prelude::*;
+
+use super::theme::{IndicatorStyle, MeshDrawingTheme};
+
+/// State of an indicator, shown by swapping its material.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component)]
+pub enum IndicatorState {
+    /// Idle indicator.
+    #[default]
+    Idle,
+    /// Indicator under the pointer.
+    Hovered,
+    /// Pressed/grabbed indicator.
+    Active,
+    /// Selected indicator.
+    Selected,
+    /// Indicator of a conflicting constraint.
+    Invalid,
+}
+
+/// Meshes and materials shared by all the indicators.
+///
+/// Created once when the plugin is built from the [`MeshDrawingTheme`], and
+/// updated in place when the theme changes. Indicators only swap between the
+/// materials of their [`IndicatorState`].
+#[derive(Debug, Clone, Resource)]
+pub struct IndicatorAssets {
+    /// Assets of the vertex indicators.
+    pub vertex: IndicatorStyleAssets,
+    /// Assets of the edge indicators (unit long, scaled along the edge).
+    pub edge: IndicatorStyleAssets,
+    /// Assets of the tangent handle indicators.
+    pub tangent_handle: IndicatorStyleAssets,
+}
+
+/// Mesh, materials and scene of a kind of indicator.
+#[derive(Debug, Clone)]
+pub struct IndicatorStyleAssets {
+    /// Mesh of the indicators (also used for picking).
+    pub mesh: Handle<Mesh>,
+    /// Material of [`IndicatorState::Idle`] indicators.
+    pub idle_material: Handle<StandardMaterial>,
+    /// Material of [`IndicatorState::Hovered`] indicators.
+    pub hovered_material: Handle<StandardMaterial>,
+    /// Material of [`IndicatorState::Active`] indicators.
+    pub active_material: Handle<StandardMaterial>,
+    /// Material of [`IndicatorState::Selected`] indicators.
+    pub selected_material: Handle<StandardMaterial>,
+    /// Material of [`IndicatorState::Invalid`] indicators.
+    pub invalid_material: Handle<StandardMaterial>,
+    /// Custom scene of the indicators, drawn instead of the mesh.
+    pub scene: Option<Handle<Scene>>,
+}
+
+impl IndicatorStyleAssets {
+    /// Material of the indicator state.
+    pub fn material(&self, state: IndicatorState) -> &Handle<StandardMaterial> {
+        match state {
+            IndicatorState::Idle => &self.idle_material,
+            IndicatorState::Hovered => &self.hovered_material,
+            IndicatorState::Active => &self.active_material,
+            IndicatorState::Selected => &self.selected_material,
+            IndicatorState::Invalid => &self.invalid_material,
+        }
+    }
+
+    /// Create the assets of the style.
+    fn new(
+        style: &IndicatorStyle,
+        mesh: Mesh,
+        meshes: &mut Assets<Mesh>,
+        materials: &mut Assets<StandardMaterial>,
+    ) -> Self {
+        let mut unlit_material = |state: IndicatorState| {
+            materials.add(StandardMaterial {
+                unlit: true,
+                base_color: style.colors.color(state),
+                ..default()
+            })
+        };
+        Self {
+            mesh: meshes.add(mesh),
+            idle_material: unlit_material(IndicatorState::Idle),
+            hovered_material: unlit_material(IndicatorState::Hovered),
+            active_material: unlit_material(IndicatorState::Active),
+            selected_material: unlit_material(IndicatorState::Selected),
+            invalid_material: unlit_material(IndicatorState::Invalid),
+            scene: style.scene.clone(),
+        }
+    }
+
+    /// Update the assets in place to match the style.
+    fn update(
+        &mut self,
+        style: &IndicatorStyle,
+        mesh: Mesh,
+        meshes: &mut Assets<Mesh>,
+        materials: &mut Assets<StandardMaterial>,
+    ) {
+        meshes.insert(&self.mesh, mesh);
+        for state in [
+            IndicatorState::Idle,
+            IndicatorState::Hovered,
+            IndicatorState::Active,
+            IndicatorState::Selected,
+            IndicatorState::Invalid,
+        ] {
+            if let Some(material) = materials.get_mut(self.material(state)) {
+                material.base_color = style.colors.color(state);
+            }
+        }
+        self.scene.clone_from(&style.scene);
+    }
+}
+
+impl IndicatorAssets {
+    /// Update the meshes, materials and scenes in place to match the theme.
+    pub fn apply_theme(
+        &mut self,
+        theme: &MeshDrawingTheme,
+        meshes: &mut Assets<Mesh>,
+        materials: &mut Assets<StandardMaterial>,
+    ) {
+        let [vertex_mesh, edge_mesh, tangent_handle_mesh] = indicator_meshes(theme);
+        self.vertex
+            .update(&theme.vertex_indicator, vertex_mesh, meshes, materials);
+        self.edge
+            .update(&theme.edge_indicator, edge_mesh, meshes, materials);
+        self.tangent_handle.update(
+            &theme.tangent_handle_indicator,
+            tangent_handle_mesh,
+            meshes,
+            materials,
+        );
+    }
+}
+
+impl FromWorld for IndicatorAssets {
+    fn from_world(world: &mut World) -> Self {
+        let theme = world
+            .get_resource::<MeshDrawingTheme>()
+            .cloned()
+            .unwrap_or_default();
+        world.resource_scope(|world, mut meshes: Mut<Assets<Mesh>>| {
+            let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+            let [vertex_mesh, edge_mesh, tangent_handle_mesh] = indicator_meshes(&theme);
+            Self {
+                vertex: IndicatorStyleAssets::new(
+                    &theme.vertex_indicator,
+                    vertex_mesh,
+                    &mut meshes,
+                    &mut materials,
+                ),
+                edge: IndicatorStyleAssets::new(
+                    &theme.edge_indicator,
+                    edge_mesh,
+                    &mut meshes,
+                    &mut materials,
+                ),
+                tangent_handle: IndicatorStyleAssets::new(
+                    &theme.tangent_handle_indicator,
+                    tangent_handle_mesh,
+                    &mut meshes,
+                    &mut materials,
+                ),
+            }
+        })
+    }
+}
+
+/// Vertex, edge and tangent handle indicator meshes of the theme.
+fn indicator_meshes(theme: &MeshDrawingTheme) -> [Mesh; 3] {
+    let edge_width = theme.edge_indicator.size;
+    [
+        Sphere::new(theme.vertex_indicator.size).mesh().build(),
+        Cuboid::new(1., edge_width, edge_width).mesh().build(),
+        Sphere::new(theme.tangent_handle_indicator.size)
+            .mesh()
+            .build(),
+    ]
+}
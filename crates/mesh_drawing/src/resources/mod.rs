@@ -1,7 +1,17 @@
 /// Drawing level resources.
 pub mod drawing;
+/// Indicator level resources.
+pub mod indicators;
 /// Plugin level resources.
 pub mod plugin;
+/// Appearance resources.
+pub mod theme;
 
-pub use drawing::{DrawingMode, DrawingState};
-pub use plugin::{MeshDrawingPluginInputBinds, MeshDrawingPluginSettings, PluginState};
+pub use drawing::{CreateModeTool, DrawingMode, DrawingState};
+pub use indicators::{IndicatorAssets, IndicatorState, IndicatorStyleAssets};
+pub use plugin::{
+    ArraySettings, CanvasRaycastMode, DimensionLabelSettings, DimensionUnits, IndicatorMode,
+    MeshDrawingPluginInputBinds, MeshDrawingPluginSettings, PluginState, SymmetryMode,
+    SymmetrySettings,
+};
+pub use theme::{IndicatorColors, IndicatorStyle, MeshDrawingTheme};
@@ -1,5 +1,13 @@
 use bevy::prelude::*;
 
+use mesh_geometry_utils::operations::{
+    constraints::SolverOptions, stroke::StrokeOptions, symmetry::Symmetry,
+};
+
+use crate::components::PolygonalMeshSolid;
+
+use super::CreateModeTool;
+
 /// State of the plugin.
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Hash, Default, States)]
 pub enum PluginState {
@@ -15,10 +23,75 @@ pub enum PluginState {
 pub struct MeshDrawingPluginSettings {
     /// Size/height of the extruded [`Mesh`] from [`MeshPolygon`](mesh_geometry_utils::data_structures::MeshPolygon)
     pub extrude_size: f32,
+    /// Kind of solid generated for newly created meshes.
+    ///
+    /// Use [`PolygonalMeshSolid::Lathe`] to revolve drawn profiles instead of extruding them.
+    pub solid: PolygonalMeshSolid,
+    /// Tool used to create new polygons in create mode.
+    pub create_mode_tool: CreateModeTool,
+    /// Number of segments used for circles and ellipses created with the shape tools.
+    pub shape_segments: u32,
+    /// Number of sides of regular polygons created with [`CreateModeTool::RegularPolygon`].
+    pub regular_polygon_sides: u32,
+    /// Simplification/smoothing of strokes sketched with [`CreateModeTool::Lasso`].
+    pub lasso: StrokeOptions,
     /// Enable insert vertex functionality in edit mode.
     pub is_edit_mode_insert_vertex_enabled: bool,
     /// Enable remove vertex functionality in edit mode.
     pub is_edit_mode_remove_vertex_enabled: bool,
+    /// Enable toggling edges between straight and curved in edit mode.
+    pub is_edit_mode_edge_curve_enabled: bool,
+    /// Enable the knife tool (splitting meshes in two) in edit mode.
+    pub is_edit_mode_knife_enabled: bool,
+    /// Distance within which knife cut points snap to the boundary/vertices of the mesh.
+    pub knife_snap_distance: f32,
+    /// Enable rounding (fillet) and cutting (chamfer) corners in edit mode.
+    pub is_edit_mode_corner_enabled: bool,
+    /// Radius of the corners rounded with fillet.
+    pub fillet_radius: f32,
+    /// Number of segments of the arcs of rounded corners.
+    pub fillet_segments: u32,
+    /// Distance from the corner (along both edges) of the chamfer cut.
+    pub chamfer_distance: f32,
+    /// Enable multi selecting and merging adjacent meshes in edit mode.
+    pub is_edit_mode_merge_enabled: bool,
+    /// Distance within which edges of merged meshes are treated as shared.
+    pub merge_tolerance: f32,
+    /// Enable deleting the selected mesh in edit mode.
+    pub is_edit_mode_delete_enabled: bool,
+    /// Enable duplicating, mirroring and arraying the selected mesh in edit mode.
+    pub is_edit_mode_array_enabled: bool,
+    /// Offsets, axis, counts and spacings of the duplicate/mirror/array operations.
+    pub array: ArraySettings,
+    /// Iterations/tolerance of the solver of the [`PolygonalMeshConstraints`](crate::components::PolygonalMeshConstraints).
+    pub constraint_solver: SolverOptions,
+    /// Enable typing exact vertex coordinates (new vertices in create mode, selected vertex in edit mode).
+    pub is_coordinate_input_enabled: bool,
+    /// Enable on-screen dimension labels (edge lengths and area).
+    pub is_dimension_labels_enabled: bool,
+    /// Units, precision and style of the dimension labels.
+    pub dimension_labels: DimensionLabelSettings,
+    /// Enable mirror/radial symmetry of the vertices placed and moved in create and edit mode.
+    pub is_symmetry_enabled: bool,
+    /// Symmetry, mode and weld tolerance used while symmetry is active.
+    pub symmetry: SymmetrySettings,
+    /// Enable generating the meshes of large polygons in the background (on the
+    /// [`AsyncComputeTaskPool`](bevy::tasks::AsyncComputeTaskPool)) instead of blocking the frame.
+    ///
+    /// Pending meshes are marked with [`PolygonalMeshGenerationPending`](crate::components::PolygonalMeshGenerationPending).
+    pub is_async_mesh_generation_enabled: bool,
+    /// Minimum number of vertices of the polygons generated in the background.
+    pub async_mesh_generation_min_vertices: usize,
+    /// Rendering and picking of the vertex/edge/tangent handle indicators.
+    ///
+    /// Applies to the meshes selected (polygons started) afterwards.
+    pub indicator_mode: IndicatorMode,
+    /// Distance (in pixels) from the cursor within which [`IndicatorMode::Gizmos`] indicators are picked.
+    pub gizmo_indicator_pick_radius: f32,
+    /// How the cursor is located on the canvas.
+    ///
+    /// Applies to the canvases added afterwards.
+    pub canvas_raycast_mode: CanvasRaycastMode,
     /// Input bindings for the plugin.
     pub input_binds: MeshDrawingPluginInputBinds,
 }
@@ -27,13 +100,219 @@ impl Default for MeshDrawingPluginSettings {
     fn default() -> Self {
         Self {
             extrude_size: 2.0,
+            solid: PolygonalMeshSolid::Extrusion,
+            create_mode_tool: CreateModeTool::Polygon,
+            shape_segments: 32,
+            regular_polygon_sides: 6,
+            lasso: StrokeOptions::default(),
             is_edit_mode_insert_vertex_enabled: true,
             is_edit_mode_remove_vertex_enabled: true,
+            is_edit_mode_edge_curve_enabled: true,
+            is_edit_mode_knife_enabled: true,
+            knife_snap_distance: 0.2,
+            is_edit_mode_corner_enabled: true,
+            fillet_radius: 0.5,
+            fillet_segments: 8,
+            chamfer_distance: 0.5,
+            is_edit_mode_merge_enabled: true,
+            merge_tolerance: 0.05,
+            is_edit_mode_delete_enabled: true,
+            is_edit_mode_array_enabled: true,
+            array: ArraySettings::default(),
+            constraint_solver: SolverOptions::default(),
+            is_coordinate_input_enabled: true,
+            is_dimension_labels_enabled: true,
+            dimension_labels: DimensionLabelSettings::default(),
+            is_symmetry_enabled: true,
+            symmetry: SymmetrySettings::default(),
+            is_async_mesh_generation_enabled: false,
+            async_mesh_generation_min_vertices: 1000,
+            indicator_mode: IndicatorMode::Entities,
+            gizmo_indicator_pick_radius: 10.,
+            canvas_raycast_mode: CanvasRaycastMode::Mesh,
             input_binds: MeshDrawingPluginInputBinds::default(),
         }
     }
 }
 
+impl MeshDrawingPluginSettings {
+    /// Get the symmetry settings if symmetry is enabled and active.
+    pub fn active_symmetry(&self) -> Option<SymmetrySettings> {
+        (self.is_symmetry_enabled && self.symmetry.is_active).then_some(self.symmetry)
+    }
+}
+
+/// Rendering and picking of the indicators.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IndicatorMode {
+    /// Pickable mesh entity per indicator (with the indicator components).
+    #[default]
+    Entities,
+    /// Indicators drawn with [`Gizmos`] every frame, picked in screen space
+    /// against the polygon. Lightweight for huge polygons.
+    ///
+    /// Only the picked vertex/tangent handle gets an (invisible) indicator entity.
+    Gizmos,
+}
+
+/// How the cursor is located on the canvas.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum CanvasRaycastMode {
+    /// Raycast against the triangles of the canvas mesh.
+    #[default]
+    Mesh,
+    /// Intersect the cursor ray with the (flat) canvas plane, canvas local `y = 0`.
+    ///
+    /// Cheaper than raycasting the canvas mesh, and the canvas doesn't need a mesh. The
+    /// canvas isn't made pickable, clicks on it are also handled on the plane.
+    Plane {
+        /// Bounds of the canvas (local `x`, `z`), cursor outside of them misses the canvas.
+        bounds: Option<Rect>,
+    },
+}
+
+/// Units used by the dimension labels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DimensionUnits {
+    /// Meters and square meters.
+    #[default]
+    Metric,
+    /// Feet and square feet.
+    Imperial,
+}
+
+/// Feet in a meter.
+const FEET_PER_METER: f32 = 3.280_84;
+
+/// Settings of the on-screen dimension labels.
+#[derive(Debug, Clone, Copy)]
+pub struct DimensionLabelSettings {
+    /// Show the labels, toggled with [`MeshDrawingPluginInputBinds::dimension_labels_toggle_key`].
+    pub is_visible: bool,
+    /// Units the dimensions are displayed in.
+    pub units: DimensionUnits,
+    /// Length in meters of one canvas unit.
+    pub meters_per_unit: f32,
+    /// Number of decimals displayed.
+    pub decimals: usize,
+    /// Font size of the labels.
+    pub font_size: f32,
+}
+
+impl DimensionLabelSettings {
+    /// Format a length (in canvas units) for display.
+    pub fn format_length(&self, length: f32) -> String {
+        let meters = length * self.meters_per_unit;
+        match self.units {
+            DimensionUnits::Metric => format!("{:.*} m", self.decimals, meters),
+            DimensionUnits::Imperial => {
+                format!("{:.*} ft", self.decimals, meters * FEET_PER_METER)
+            }
+        }
+    }
+
+    /// Format an area (in canvas units squared) for display.
+    pub fn format_area(&self, area: f32) -> String {
+        let square_meters = area * self.meters_per_unit.powi(2);
+        match self.units {
+            DimensionUnits::Metric => format!("{:.*} m²", self.decimals, square_meters),
+            DimensionUnits::Imperial => format!(
+                "{:.*} ft²",
+                self.decimals,
+                square_meters * FEET_PER_METER.powi(2)
+            ),
+        }
+    }
+}
+
+impl Default for DimensionLabelSettings {
+    fn default() -> Self {
+        Self {
+            is_visible: true,
+            units: DimensionUnits::Metric,
+            meters_per_unit: 1.,
+            decimals: 2,
+            font_size: 16.,
+        }
+    }
+}
+
+/// Settings of the duplicate, mirror and array operations in edit mode.
+///
+/// Positions are canvas local.
+#[derive(Debug, Clone, Copy)]
+pub struct ArraySettings {
+    /// Offset of the duplicated mesh.
+    pub duplicate_offset: Vec2,
+    /// Point on the axis the mirrored mesh is reflected across.
+    pub mirror_origin: Vec2,
+    /// Direction of the axis the mirrored mesh is reflected across.
+    pub mirror_direction: Vec2,
+    /// Number of items (including the original) of linear and circular arrays.
+    pub count: u32,
+    /// Offset between consecutive items of linear arrays.
+    pub linear_spacing: Vec2,
+    /// Center of circular arrays.
+    pub circular_center: Vec2,
+    /// Angle (radians) between consecutive items of circular arrays.
+    pub circular_angle: f32,
+}
+
+impl Default for ArraySettings {
+    fn default() -> Self {
+        Self {
+            duplicate_offset: Vec2::new(1., 1.),
+            mirror_origin: Vec2::ZERO,
+            mirror_direction: Vec2::Y,
+            count: 4,
+            linear_spacing: Vec2::new(3., 0.),
+            circular_center: Vec2::ZERO,
+            circular_angle: std::f32::consts::FRAC_PI_2,
+        }
+    }
+}
+
+/// How symmetric polygons are created.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SymmetryMode {
+    /// Single polygon welded from the drawn path and its images.
+    ///
+    /// The drawn (open) path is one half (mirror) or one sector (radial) of the polygon.
+    #[default]
+    Weld,
+    /// Separate meshes for the drawn polygon and its images, linked together in edit mode.
+    LinkedCopies,
+}
+
+/// Settings of the symmetry.
+#[derive(Debug, Clone, Copy)]
+pub struct SymmetrySettings {
+    /// Symmetry is active, toggled with [`MeshDrawingPluginInputBinds::symmetry_toggle_key`].
+    pub is_active: bool,
+    /// Mirror axis or radial center (canvas local) with the number of copies.
+    pub symmetry: Symmetry,
+    /// Weld the images into a single polygon or create linked copies.
+    ///
+    /// Shapes created with the shape tools are always linked copies.
+    pub mode: SymmetryMode,
+    /// Distance within which vertices are welded/treated as symmetric counterparts.
+    pub weld_tolerance: f32,
+}
+
+impl Default for SymmetrySettings {
+    fn default() -> Self {
+        Self {
+            is_active: false,
+            symmetry: Symmetry::Mirror {
+                origin: Vec2::ZERO,
+                direction: Vec2::Y,
+            },
+            mode: SymmetryMode::Weld,
+            weld_tolerance: 0.1,
+        }
+    }
+}
+
 /// Input/Key binds for the plugin.
 #[derive(Debug, Clone, Copy, Resource)]
 pub struct MeshDrawingPluginInputBinds {
@@ -49,6 +328,44 @@ pub struct MeshDrawingPluginInputBinds {
     ///
     /// Vertex is inserted when this `KeyDown` + `LMB Click` on the desired Edge.
     pub edit_mode_insert_vertex_key: KeyCode,
+    /// [`KeyCode`] used to convert a straight edge into a curve and back.
+    ///
+    /// Edge is toggled when this `KeyDown` + `LMB Click` on the desired Edge.
+    pub edit_mode_toggle_edge_curve_key: KeyCode,
+    /// [`KeyCode`] used to activate/deactivate the knife tool on the selected mesh.
+    ///
+    /// While active, `LMB Click` adds cut points, starting and ending on the boundary.
+    pub edit_mode_knife_key: KeyCode,
+    /// [`KeyCode`] used to round (fillet) the corner at the last pressed vertex.
+    pub edit_mode_fillet_key: KeyCode,
+    /// [`KeyCode`] used to cut (chamfer) the corner at the last pressed vertex.
+    pub edit_mode_chamfer_key: KeyCode,
+    /// [`KeyCode`] used to add/remove meshes to/from the multi selection.
+    ///
+    /// Mesh is toggled when this `KeyDown` + `LMB Click` on the desired Mesh.
+    pub edit_mode_multi_select_key: KeyCode,
+    /// [`KeyCode`] used to merge the multi selected meshes into one.
+    pub edit_mode_merge_key: KeyCode,
+    /// [`KeyCode`] used to delete the selected mesh.
+    pub edit_mode_delete_key: KeyCode,
+    /// [`KeyCode`] used to duplicate the selected mesh (moved by the duplicate offset).
+    pub edit_mode_duplicate_key: KeyCode,
+    /// [`KeyCode`] used to create a mirrored copy of the selected mesh.
+    pub edit_mode_mirror_key: KeyCode,
+    /// [`KeyCode`] used to create a linear array of the selected mesh.
+    pub edit_mode_linear_array_key: KeyCode,
+    /// [`KeyCode`] used to create a circular array of the selected mesh.
+    pub edit_mode_circular_array_key: KeyCode,
+    /// [`KeyCode`] used to open the coordinate input overlay and to submit the typed coordinates.
+    ///
+    /// Accepts `x,z`, `@dx,dz` or `@length<angle`. `Escape` closes the overlay.
+    pub coordinate_input_key: KeyCode,
+    /// [`KeyCode`] used to show/hide the dimension labels.
+    pub dimension_labels_toggle_key: KeyCode,
+    /// [`KeyCode`] used to activate/deactivate the symmetry.
+    pub symmetry_toggle_key: KeyCode,
+    /// [`KeyCode`] used to cycle through the [`CreateModeTool`]s in [`CreateMode`](`super::DrawingMode::CreateMode`)
+    pub create_mode_cycle_tool_key: KeyCode,
     /// [`MouseButton`] input used to _add vertex_ in [`CreateMode`](`super::DrawingMode::CreateMode`)
     pub create_mode_add_vertex_btn: MouseButton,
     /// [`MouseButton`] input used to _close polygon and create mesh_ in [`CreateMode`](`super::DrawingMode::CreateMode`)
@@ -62,6 +379,21 @@ impl Default for MeshDrawingPluginInputBinds {
             create_mode_switch_key: KeyCode::Digit2,
             edit_mode_remove_vertex_key: KeyCode::AltLeft,
             edit_mode_insert_vertex_key: KeyCode::ControlLeft,
+            edit_mode_toggle_edge_curve_key: KeyCode::ShiftLeft,
+            edit_mode_knife_key: KeyCode::KeyK,
+            edit_mode_fillet_key: KeyCode::KeyF,
+            edit_mode_chamfer_key: KeyCode::KeyC,
+            edit_mode_multi_select_key: KeyCode::KeyS,
+            edit_mode_merge_key: KeyCode::KeyM,
+            edit_mode_delete_key: KeyCode::Delete,
+            edit_mode_duplicate_key: KeyCode::KeyD,
+            edit_mode_mirror_key: KeyCode::KeyX,
+            edit_mode_linear_array_key: KeyCode::KeyA,
+            edit_mode_circular_array_key: KeyCode::KeyR,
+            coordinate_input_key: KeyCode::Enter,
+            dimension_labels_toggle_key: KeyCode::KeyL,
+            symmetry_toggle_key: KeyCode::KeyY,
+            create_mode_cycle_tool_key: KeyCode::Tab,
             create_mode_add_vertex_btn: MouseButton::Left,
             create_mode_close_and_extrude_mesh_btn: MouseButton::Right,
         }
@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+
+use super::IndicatorState;
+
+/// Appearance of the indicators, meshes and previews of the plugin.
+///
+/// Insert before adding the plugin to start with a custom theme, changes made
+/// later are applied to the existing indicators (except their scenes).
+#[derive(Debug, Clone, Resource)]
+pub struct MeshDrawingTheme {
+    /// Style of the vertex indicators, size is the radius.
+    pub vertex_indicator: IndicatorStyle,
+    /// Style of the edge indicators, size is the width.
+    pub edge_indicator: IndicatorStyle,
+    /// Style of the tangent handle indicators, size is the radius.
+    pub tangent_handle_indicator: IndicatorStyle,
+    /// Keep the indicators at a constant size on screen, in pixels per unit of indicator size.
+    ///
+    /// e.g. `Some(100.)` draws a vertex indicator of radius `0.1` with a radius of `10px`.
+    /// `None` keeps the indicator sizes in world units.
+    pub indicator_pixels_per_unit: Option<f32>,
+    /// Material of the newly created meshes.
+    pub mesh_material: StandardMaterial,
+    /// Color of the preview of the shape being dragged out.
+    pub shape_preview_color: Color,
+    /// Color of the symmetric images of the polygon being created.
+    pub symmetry_preview_color: Color,
+    /// Color of the knife cut path preview.
+    pub knife_preview_color: Color,
+    /// Color of the curved edge guides (flattened curve and control point lines).
+    pub edge_curve_guide_color: Color,
+    /// Color of the symmetry axis/center guides.
+    pub symmetry_guide_color: Color,
+    /// Color of the glyphs of satisfied constraints.
+    pub constraint_glyph_color: Color,
+    /// Color of the glyphs of conflicting constraints.
+    pub constraint_conflict_color: Color,
+    /// Color of the outlines of multi selected meshes.
+    pub multi_selection_color: Color,
+    /// Text color of the dimension labels and the coordinate input overlay.
+    pub dimension_label_color: Color,
+}
+
+impl Default for MeshDrawingTheme {
+    fn default() -> Self {
+        Self {
+            vertex_indicator: IndicatorStyle {
+                size: 0.1,
+                ..default()
+            },
+            edge_indicator: IndicatorStyle {
+                size: 0.1,
+                ..default()
+            },
+            tangent_handle_indicator: IndicatorStyle {
+                size: 0.07,
+                ..default()
+            },
+            indicator_pixels_per_unit: None,
+            mesh_material: Color::srgba(0.8, 0.7, 0.6, 1.0).into(),
+            shape_preview_color: Color::srgb(0.9, 0.9, 0.9),
+            symmetry_preview_color: Color::srgb(0.6, 0.6, 0.6),
+            knife_preview_color: Color::srgb(0.9, 0.2, 0.2),
+            edge_curve_guide_color: Color::srgb(0.9, 0.9, 0.9),
+            symmetry_guide_color: Color::srgb(0.4, 0.6, 0.9),
+            constraint_glyph_color: Color::srgb(0.3, 0.8, 0.4),
+            constraint_conflict_color: Color::srgb(0.9, 0.2, 0.2),
+            multi_selection_color: Color::srgb(0.2, 0.6, 0.9),
+            dimension_label_color: Color::WHITE,
+        }
+    }
+}
+
+/// Style of a kind of indicator.
+#[derive(Debug, Clone)]
+pub struct IndicatorStyle {
+    /// Size of the indicator mesh.
+    pub size: f32,
+    /// Colors of the (unlit) indicator material per state.
+    pub colors: IndicatorColors,
+    /// Custom scene drawn instead of the indicator mesh.
+    ///
+    /// The (hidden) indicator mesh is still used for picking. Edge indicator
+    /// scenes are stretched along their `x` axis, unit long and centered.
+    pub scene: Option<Handle<Scene>>,
+}
+
+impl Default for IndicatorStyle {
+    fn default() -> Self {
+        Self {
+            size: 0.1,
+            colors: IndicatorColors::default(),
+            scene: None,
+        }
+    }
+}
+
+/// Colors of the indicators per [`IndicatorState`].
+#[derive(Debug, Clone, Copy)]
+pub struct IndicatorColors {
+    /// Idle indicator.
+    pub idle: Color,
+    /// Indicator under the pointer.
+    pub hovered: Color,
+    /// Pressed/grabbed indicator.
+    pub active: Color,
+    /// Selected indicator.
+    pub selected: Color,
+    /// Indicator of a conflicting constraint.
+    pub invalid: Color,
+}
+
+impl IndicatorColors {
+    /// Color of the indicator state.
+    pub fn color(&self, state: IndicatorState) -> Color {
+        match state {
+            IndicatorState::Idle => self.idle,
+            IndicatorState::Hovered => self.hovered,
+            IndicatorState::Active => self.active,
+            IndicatorState::Selected => self.selected,
+            IndicatorState::Invalid => self.invalid,
+        }
+    }
+}
+
+impl Default for IndicatorColors {
+    fn default() -> Self {
+        Self {
+            idle: Color::WHITE,
+            hovered: Color::srgb(1.0, 0.85, 0.3),
+            active: Color::srgb(1.0, 0.5, 0.1),
+            selected: Color::srgb(0.3, 0.7, 1.0),
+            invalid: Color::srgb(0.9, 0.2, 0.2),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use crate::{
+    events::edit_mode::EditModeEvent,
+    resources::{drawing::EditModeState, DrawingMode, DrawingState, MeshDrawingPluginSettings},
+};
+
+/// Handle duplicate/mirror/array inputs.
+///
+/// Dispatch `EditModeEvent` for the copies of the active mesh.
+pub fn handle_array_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    settings: Res<MeshDrawingPluginSettings>,
+    drawing_state: Res<DrawingState>,
+    mut edit_mode_event: EventWriter<EditModeEvent>,
+) {
+    if !settings.is_edit_mode_array_enabled {
+        return;
+    }
+    let DrawingMode::EditMode(EditModeState {
+        active_mesh: Some(_),
+        knife_path: None,
+        ..
+    }) = drawing_state.mode
+    else {
+        return;
+    };
+    let MeshDrawingPluginSettings { input_binds, .. } = *settings;
+    if keyboard_input.just_pressed(input_binds.edit_mode_duplicate_key) {
+        edit_mode_event.send(EditModeEvent::PolygonalMeshDuplicate);
+    } else if keyboard_input.just_pressed(input_binds.edit_mode_mirror_key) {
+        edit_mode_event.send(EditModeEvent::PolygonalMeshMirror);
+    } else if keyboard_input.just_pressed(input_binds.edit_mode_linear_array_key) {
+        edit_mode_event.send(EditModeEvent::PolygonalMeshLinearArray);
+    } else if keyboard_input.just_pressed(input_binds.edit_mode_circular_array_key) {
+        edit_mode_event.send(EditModeEvent::PolygonalMeshCircularArray);
+    }
+}
@@ -0,0 +1,141 @@
+use bevy::{prelude::*, utils::HashMap};
+use mesh_geometry_utils::{
+    data_structures::{Edge, MeshPolygon, VertexId},
+    operations::constraints::Constraint,
+};
+
+use crate::{
+    components::{PolygonalMesh, PolygonalMeshConstraint, PolygonalMeshConstraints},
+    resources::{drawing::EditModeState, DrawingMode, DrawingState, MeshDrawingTheme},
+};
+
+/// Half size of the constraint glyphs.
+const CONSTRAINT_GLYPH_SIZE: f32 = 0.1;
+/// Distance between the edge and its first glyph, and between stacked glyphs.
+const CONSTRAINT_GLYPH_SPACING: f32 = 0.3;
+
+/// Draw glyphs of the constraints of the active mesh next to their edges/vertices.
+///
+/// - Fixed length: dimension line along the edge.
+/// - Fixed angle: angle mark.
+/// - Horizontal/Vertical: line along the canvas `x`/`z` axis.
+/// - Parallel: double line along the edge.
+/// - Perpendicular: right angle mark.
+/// - Fixed position: cross on the vertex.
+/// - Coincident: diamond around the vertex.
+pub fn draw_constraint_glyphs(
+    mut gizmos: Gizmos,
+    theme: Res<MeshDrawingTheme>,
+    drawing_state: Res<DrawingState>,
+    query_meshes: Query<(&PolygonalMesh, &PolygonalMeshConstraints, &GlobalTransform)>,
+) {
+    let DrawingMode::EditMode(EditModeState {
+        active_mesh: Some(active_mesh),
+        ..
+    }) = drawing_state.mode
+    else {
+        return;
+    };
+    let Ok((PolygonalMesh { mesh_polygon, .. }, mesh_constraints, global_transform)) =
+        query_meshes.get(active_mesh)
+    else {
+        return;
+    };
+    let to_world = |point: Vec2| global_transform.transform_point(Vec3::new(point.x, 0., point.y));
+    let winding = mesh_polygon.signed_area().signum();
+    let mut glyphs_per_edge: HashMap<Edge, usize> = HashMap::new();
+    for (index, constraint) in mesh_constraints.constraints.iter().enumerate() {
+        let color = if mesh_constraints.conflicts.contains(&index) {
+            theme.constraint_conflict_color
+        } else {
+            theme.constraint_glyph_color
+        };
+        let mut line = |start: Vec2, end: Vec2| gizmos.line(to_world(start), to_world(end), color);
+        let constraint = match constraint {
+            PolygonalMeshConstraint::Polygon(constraint) => constraint,
+            PolygonalMeshConstraint::Coincident { vertex, .. } => {
+                if let Some(vert) = mesh_polygon.vertices.get(*vertex) {
+                    let corners = [Vec2::X, Vec2::Y, Vec2::NEG_X, Vec2::NEG_Y]
+                        .map(|corner| *vert + corner * CONSTRAINT_GLYPH_SIZE);
+                    for (idx, corner) in corners.iter().enumerate() {
+                        line(*corner, corners[(idx + 1) % corners.len()]);
+                    }
+                }
+                continue;
+            }
+        };
+        if let Constraint::FixedPosition { vertex, .. } = constraint {
+            if let Some(vert) = mesh_polygon.vertices.get(*vertex) {
+                let size = CONSTRAINT_GLYPH_SIZE;
+                line(
+                    *vert + Vec2::new(-size, -size),
+                    *vert + Vec2::new(size, size),
+                );
+                line(
+                    *vert + Vec2::new(-size, size),
+                    *vert + Vec2::new(size, -size),
+                );
+            }
+            continue;
+        }
+        for edge in constraint.edges() {
+            let Some((anchor, direction, normal)) =
+                glyph_frame(mesh_polygon, edge, winding, &mut glyphs_per_edge)
+            else {
+                continue;
+            };
+            let size = CONSTRAINT_GLYPH_SIZE;
+            match constraint {
+                Constraint::FixedLength { .. } => {
+                    line(anchor - direction * size, anchor + direction * size);
+                    for end in [anchor - direction * size, anchor + direction * size] {
+                        line(end - normal * size / 2., end + normal * size / 2.);
+                    }
+                }
+                Constraint::FixedAngle { .. } => {
+                    let corner = anchor - direction * size;
+                    line(corner, corner + direction * size * 2.);
+                    line(
+                        corner,
+                        corner + Vec2::from_angle(0.5).rotate(direction) * size * 2.,
+                    );
+                }
+                Constraint::Horizontal(_) => line(anchor - Vec2::X * size, anchor + Vec2::X * size),
+                Constraint::Vertical(_) => line(anchor - Vec2::Y * size, anchor + Vec2::Y * size),
+                Constraint::Parallel(..) => {
+                    for offset in [-size / 3., size / 3.] {
+                        let center = anchor + normal * offset;
+                        line(center - direction * size, center + direction * size);
+                    }
+                }
+                Constraint::Perpendicular(..) => {
+                    line(anchor - direction * size, anchor + direction * size);
+                    line(anchor, anchor + normal * size * 2.);
+                }
+                Constraint::FixedPosition { .. } => {}
+            }
+        }
+    }
+}
+
+/// Get anchor (outside the polygon, stacked per edge), direction and
+/// outward normal of the next glyph of the edge.
+fn glyph_frame(
+    mesh_polygon: &MeshPolygon,
+    edge: &Edge,
+    winding: f32,
+    glyphs_per_edge: &mut HashMap<Edge, usize>,
+) -> Option<(Vec2, Vec2, Vec2)> {
+    let vert = |id: VertexId| mesh_polygon.vertices.get(id).cloned();
+    let (from_vert, to_vert) = (vert(edge.from)?, vert(edge.to)?);
+    let direction = (to_vert - from_vert).try_normalize()?;
+    let normal = -direction.perp() * winding;
+    let midpoint = match mesh_polygon.edge_curve(edge) {
+        Some(curve) => curve.point_at(from_vert, to_vert, 0.5),
+        None => (from_vert + to_vert) / 2.,
+    };
+    let count = glyphs_per_edge.entry(edge.clone()).or_default();
+    *count += 1;
+    let anchor = midpoint + normal * CONSTRAINT_GLYPH_SPACING * *count as f32;
+    Some((anchor, direction, normal))
+}
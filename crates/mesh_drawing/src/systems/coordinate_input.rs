@@ -0,0 +1,187 @@
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState,
+    },
+    prelude::*,
+};
+
+use crate::{
+    components::{Canvas, Cleanup, CoordinateInputOverlay, VertexIndicator},
+    events::{create_mode::CreateModeEvent, edit_mode::EditModeEvent},
+    resources::{
+        drawing::EditModeState, CreateModeTool, DrawingMode, DrawingState,
+        MeshDrawingPluginSettings, MeshDrawingTheme,
+    },
+    utils::{
+        canvas_correction::get_canvas_uncorrected_translation,
+        coordinate_input::parse_coordinate_input,
+    },
+};
+
+/// Prompt shown in the coordinate input overlay.
+const COORDINATE_INPUT_PROMPT: &str = "x,z | @dx,dz | @length<angle";
+
+/// Handle coordinate input.
+///
+/// Opens the overlay with the coordinate input key, captures the typed text
+/// and dispatches it on submit:
+/// - Create mode: `CreateModeEvent::VertexAdd`, overlay stays open for the next vertex.
+/// - Edit mode: `EditModeEvent::VertexPositionSet` for the selected vertex.
+///
+/// Keyboard input is consumed while the overlay is open.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_coordinate_input(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    settings: Res<MeshDrawingPluginSettings>,
+    mut drawing_state: ResMut<DrawingState>,
+    query_canvas: Query<&GlobalTransform, With<Canvas>>,
+    query_vertex_indicators: Query<&Transform, With<VertexIndicator>>,
+    mut create_mode_event: EventWriter<CreateModeEvent>,
+    mut edit_mode_event: EventWriter<EditModeEvent>,
+) {
+    if !settings.is_coordinate_input_enabled {
+        return;
+    }
+    let submit_key = settings.input_binds.coordinate_input_key;
+    let DrawingState {
+        mode,
+        coordinate_input,
+    } = &mut *drawing_state;
+    let Some(input) = coordinate_input else {
+        let is_available = match mode {
+            DrawingMode::CreateMode(create_mode_state) => {
+                settings.create_mode_tool == CreateModeTool::Polygon
+                    && create_mode_state.shape_draft.is_none()
+            }
+            DrawingMode::EditMode(edit_mode_state) => {
+                edit_mode_state.selected_vertex_indicator.is_some()
+            }
+        };
+        if is_available && keyboard_input.just_pressed(submit_key) {
+            *coordinate_input = Some(String::new());
+            keyboard_events.clear();
+            keyboard_input.reset_all();
+        }
+        return;
+    };
+    let mut is_submitted = false;
+    let mut is_closed = false;
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        if event.key_code == submit_key {
+            is_submitted = true;
+            break;
+        }
+        match &event.logical_key {
+            Key::Escape => {
+                is_closed = true;
+                break;
+            }
+            Key::Backspace => {
+                input.pop();
+            }
+            Key::Character(characters) => input.extend(
+                characters
+                    .chars()
+                    .filter(|c| c.is_ascii_digit() || "@<,.-+eE ".contains(*c)),
+            ),
+            _ => {}
+        }
+    }
+    keyboard_input.reset_all();
+    if is_submitted {
+        match mode {
+            DrawingMode::CreateMode(create_mode_state) => {
+                let Ok(canvas_transform) = query_canvas.get_single() else {
+                    return;
+                };
+                let reference = create_mode_state.mesh_polygon.vertices.last().copied();
+                match parse_coordinate_input(input, reference) {
+                    Ok(position) => {
+                        create_mode_event.send(CreateModeEvent::VertexAdd(
+                            get_canvas_uncorrected_translation(
+                                Vec3::new(position.x, 0., position.y),
+                                canvas_transform,
+                            ),
+                        ));
+                    }
+                    Err(error) => warn!("Invalid coordinate input {:?}: {}", input, error),
+                }
+                input.clear();
+            }
+            DrawingMode::EditMode(EditModeState {
+                selected_vertex_indicator: Some(selected_vertex_indicator),
+                ..
+            }) => {
+                let reference = query_vertex_indicators
+                    .get(*selected_vertex_indicator)
+                    .ok()
+                    .map(|transform| transform.translation.xz());
+                match parse_coordinate_input(input, reference) {
+                    Ok(position) => {
+                        edit_mode_event.send(EditModeEvent::VertexPositionSet(
+                            *selected_vertex_indicator,
+                            position,
+                        ));
+                        is_closed = true;
+                    }
+                    Err(error) => {
+                        warn!("Invalid coordinate input {:?}: {}", input, error);
+                        input.clear();
+                    }
+                }
+            }
+            DrawingMode::EditMode(_) => is_closed = true,
+        }
+    }
+    if is_closed {
+        *coordinate_input = None;
+    }
+}
+
+/// Spawn/update/despawn the coordinate input overlay to match the typed text.
+#[allow(clippy::type_complexity)]
+pub fn update_coordinate_input_overlay(
+    mut commands: Commands,
+    settings: Res<MeshDrawingPluginSettings>,
+    theme: Res<MeshDrawingTheme>,
+    drawing_state: Res<DrawingState>,
+    mut query_overlay: Query<(Entity, &mut Text), (With<CoordinateInputOverlay>, Without<Cleanup>)>,
+) {
+    let Some(input) = &drawing_state.coordinate_input else {
+        for (entity, _) in query_overlay.iter() {
+            commands.entity(entity).insert(Cleanup::Recursive);
+        }
+        return;
+    };
+    let value = format!("{}: {}_", COORDINATE_INPUT_PROMPT, input);
+    if let Ok((_, mut text)) = query_overlay.get_single_mut() {
+        if let Some(section) = text.sections.first_mut() {
+            if section.value != value {
+                section.value = value;
+            }
+        }
+        return;
+    }
+    commands.spawn((
+        TextBundle::from_section(
+            value,
+            TextStyle {
+                font_size: settings.dimension_labels.font_size,
+                color: theme.dimension_label_color,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(12.),
+            bottom: Val::Px(12.),
+            ..default()
+        }),
+        CoordinateInputOverlay,
+    ));
+}
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use crate::{
+    events::edit_mode::EditModeEvent,
+    resources::{drawing::EditModeState, DrawingMode, DrawingState, MeshDrawingPluginSettings},
+};
+
+/// Handle corner (fillet/chamfer) inputs.
+///
+/// Dispatch `EditModeEvent` for the corner at the last pressed vertex indicator.
+pub fn handle_corner_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    settings: Res<MeshDrawingPluginSettings>,
+    drawing_state: Res<DrawingState>,
+    mut edit_mode_event: EventWriter<EditModeEvent>,
+) {
+    if !settings.is_edit_mode_corner_enabled {
+        return;
+    }
+    let DrawingMode::EditMode(EditModeState {
+        selected_vertex_indicator: Some(selected_vertex_indicator),
+        ..
+    }) = drawing_state.mode
+    else {
+        return;
+    };
+    let MeshDrawingPluginSettings { input_binds, .. } = *settings;
+    if keyboard_input.just_pressed(input_binds.edit_mode_fillet_key) {
+        edit_mode_event.send(EditModeEvent::VertexFillet(selected_vertex_indicator));
+    } else if keyboard_input.just_pressed(input_binds.edit_mode_chamfer_key) {
+        edit_mode_event.send(EditModeEvent::VertexChamfer(selected_vertex_indicator));
+    }
+}
@@ -1,15 +1,27 @@
 use bevy::{math::Vec3Swizzles, prelude::*};
-use bevy_mod_picking::prelude::{Highlight, HighlightKind, PickableBundle};
-use mesh_geometry_utils::data_structures::Edge;
+use mesh_geometry_utils::{data_structures::Edge, operations::symmetry::symmetric_polygon};
 
 use crate::{
-    components::{Canvas, Cleanup, EdgeIndicator, PolygonalMesh, VertexIndicator},
-    events::create_mode::CreateModeEvent,
-    resources::MeshDrawingPluginSettings,
-    resources::{drawing::EditModeState, DrawingMode, DrawingState},
+    components::{
+        Canvas, Cleanup, EdgeIndicator, PolygonalMesh, PolygonalMeshSolid, SymmetricPolygonalMesh,
+        SymmetryLink, VertexIndicator,
+    },
+    events::{
+        create_mode::CreateModeEvent,
+        lifecycle::{DrawingModeChanged, LifecycleEventWriters, PolygonalMeshCreated},
+    },
+    resources::{
+        drawing::{CreateModeState, CreateModeTool, EditModeState, ShapeDraft},
+        DrawingMode, DrawingState,
+    },
+    resources::{
+        IndicatorAssets, IndicatorMode, MeshDrawingPluginSettings, MeshDrawingTheme, SymmetryMode,
+        SymmetrySettings,
+    },
     utils::{
-        canvas_correction::get_canvas_corrected_translation,
+        canvas_correction::{canvas_to_world, get_canvas_corrected_translation, world_to_canvas},
         indicators::{spawn_edge_indicator, spawn_vertex_indicators},
+        polygonal_mesh::spawn_polygonal_mesh,
     },
 };
 
@@ -20,14 +32,15 @@ const MERGE_BELOW_DIST_SQUARED: f32 = 0.1;
 #[allow(clippy::too_many_arguments)]
 pub fn handle_create_mode_events(
     mut commands: Commands,
-    query_canvas: Query<(Entity, &Transform), With<Canvas>>,
+    query_canvas: Query<(Entity, &GlobalTransform), With<Canvas>>,
     query_indicators: Query<Entity, (With<VertexIndicator>, Without<Cleanup>)>,
     query_edge_indicators: Query<Entity, (With<EdgeIndicator>, Without<Cleanup>)>,
     mut events: EventReader<CreateModeEvent>,
-    settings: Res<MeshDrawingPluginSettings>,
+    mut settings: ResMut<MeshDrawingPluginSettings>,
     mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    indicator_assets: Res<IndicatorAssets>,
     mut drawing_state: ResMut<DrawingState>,
+    mut lifecycle_events: LifecycleEventWriters,
 ) {
     let Ok((canvas_entity, canvas_transform)) = query_canvas.get_single() else {
         return;
@@ -49,11 +62,13 @@ pub fn handle_create_mode_events(
                     if dist_square_from_first_vert <= MERGE_BELOW_DIST_SQUARED {
                         if let Err(error) = close_polygon_and_extrude_mesh(
                             settings.extrude_size,
+                            settings.solid,
+                            settings.active_symmetry(),
                             create_mode_state,
                             &mut meshes,
-                            &mut materials,
                             &mut commands,
                             canvas_entity,
+                            &mut lifecycle_events,
                         ) {
                             error!("error: {:?}", error);
                             return;
@@ -70,12 +85,15 @@ pub fn handle_create_mode_events(
                 let vertex_id = create_mode_state
                     .mesh_polygon
                     .push_vertex(intersection_point.xz());
+                if settings.indicator_mode == IndicatorMode::Gizmos {
+                    // drawn with gizmos
+                    continue;
+                }
                 // Draw vertex indicator
                 let entity = spawn_vertex_indicators(
                     intersection_point.xz(),
                     &mut commands,
-                    &mut meshes,
-                    &mut materials,
+                    &indicator_assets,
                 );
                 // Mark as indicator
                 commands.entity(entity).insert(VertexIndicator(vertex_id));
@@ -97,8 +115,7 @@ pub fn handle_create_mode_events(
                             *from_vert,
                             *to_vert,
                             &mut commands,
-                            &mut meshes,
-                            &mut materials,
+                            &indicator_assets,
                         );
                         commands
                             .entity(entity)
@@ -109,13 +126,19 @@ pub fn handle_create_mode_events(
                 }
             }
             CreateModeEvent::PolygonCloseAndIntoMeshExtrude => {
+                if create_mode_state.shape_draft.take().is_some() {
+                    // cancel the shape being dragged out
+                    continue;
+                }
                 if let Err(error) = close_polygon_and_extrude_mesh(
                     settings.extrude_size,
+                    settings.solid,
+                    settings.active_symmetry(),
                     create_mode_state,
                     &mut meshes,
-                    &mut materials,
                     &mut commands,
                     canvas_entity,
+                    &mut lifecycle_events,
                 ) {
                     error!("error: {:?}", error);
                     continue;
@@ -126,6 +149,77 @@ pub fn handle_create_mode_events(
                     &query_indicators,
                 );
             }
+            CreateModeEvent::ShapePointerDown(intersection_point) => {
+                let point = world_to_canvas(*intersection_point, canvas_transform);
+                let Some(draft) = create_mode_state.shape_draft.as_mut() else {
+                    // start a new shape, discarding any partially clicked polygon
+                    create_mode_state.mesh_polygon.clear_with_reset();
+                    cleanup_create_mode_entities(
+                        &mut commands,
+                        &query_edge_indicators,
+                        &query_indicators,
+                    );
+                    create_mode_state.shape_draft =
+                        Some(ShapeDraft::new(settings.create_mode_tool, point));
+                    continue;
+                };
+                // pressing again completes shapes waiting for more input (eg: rotated rectangle width)
+                draft.cursor = point;
+                if let Err(error) = complete_shape_draft(
+                    &settings,
+                    create_mode_state,
+                    &mut meshes,
+                    &mut commands,
+                    canvas_entity,
+                    &mut lifecycle_events,
+                ) {
+                    warn!("Shape discarded: {:?}", error);
+                }
+            }
+            CreateModeEvent::ShapePointerMove(intersection_point) => {
+                let Some(draft) = create_mode_state.shape_draft.as_mut() else {
+                    continue;
+                };
+                draft.cursor = world_to_canvas(*intersection_point, canvas_transform);
+                if draft.tool == CreateModeTool::Lasso
+                    && draft.anchors.last() != Some(&draft.cursor)
+                {
+                    // sample the stroke
+                    draft.anchors.push(draft.cursor);
+                }
+            }
+            CreateModeEvent::ShapePointerUp => {
+                let Some(draft) = create_mode_state.shape_draft.as_mut() else {
+                    continue;
+                };
+                if draft.needs_more_anchors() {
+                    let Some(last_anchor) = draft.anchors.last() else {
+                        continue;
+                    };
+                    if last_anchor.distance_squared(draft.cursor) <= MERGE_BELOW_DIST_SQUARED {
+                        // released without dragging, discard
+                        create_mode_state.shape_draft = None;
+                    } else {
+                        draft.anchors.push(draft.cursor);
+                    }
+                    continue;
+                }
+                if let Err(error) = complete_shape_draft(
+                    &settings,
+                    create_mode_state,
+                    &mut meshes,
+                    &mut commands,
+                    canvas_entity,
+                    &mut lifecycle_events,
+                ) {
+                    warn!("Shape discarded: {:?}", error);
+                }
+            }
+            CreateModeEvent::ToolCycle => {
+                settings.create_mode_tool = settings.create_mode_tool.next();
+                info!("Create mode tool: {:?}", settings.create_mode_tool);
+                create_mode_state.shape_draft = None;
+            }
             CreateModeEvent::EditModeSwitch => {
                 cleanup_create_mode_entities(
                     &mut commands,
@@ -134,20 +228,76 @@ pub fn handle_create_mode_events(
                 );
                 // switch to create mode.
                 drawing_state.mode = DrawingMode::EditMode(EditModeState::default());
+                lifecycle_events.mode_changed.send(DrawingModeChanged {
+                    mode: drawing_state.mode.clone(),
+                });
             }
         }
     }
 }
 
+/// Complete the shape being dragged out and spawn it as a mesh.
+///
+/// The draft is consumed even if the shape turns out to be degenerate.
+fn complete_shape_draft(
+    settings: &MeshDrawingPluginSettings,
+    create_mode_state: &mut CreateModeState,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    commands: &mut Commands,
+    canvas_entity: Entity,
+    lifecycle_events: &mut LifecycleEventWriters,
+) -> Result<(), String> {
+    let draft = create_mode_state
+        .shape_draft
+        .take()
+        .ok_or_else(|| "No shape is being dragged out!".to_string())?;
+    create_mode_state.mesh_polygon = draft
+        .mesh_polygon(settings)
+        .ok_or_else(|| "Shape is incomplete or degenerate!".to_string())?;
+    // closed shapes can't be welded, always create linked copies
+    let symmetry = settings.active_symmetry().map(|symmetry| SymmetrySettings {
+        mode: SymmetryMode::LinkedCopies,
+        ..symmetry
+    });
+    close_polygon_and_extrude_mesh(
+        settings.extrude_size,
+        settings.solid,
+        symmetry,
+        create_mode_state,
+        meshes,
+        commands,
+        canvas_entity,
+        lifecycle_events,
+    )
+}
+
+/// Close the polygon being created and spawn it as a mesh.
+///
+/// With symmetry, the polygon is either welded with its images into a single
+/// [`SymmetricPolygonalMesh`] or spawned along with its images as linked copies.
 #[allow(clippy::too_many_arguments)]
 fn close_polygon_and_extrude_mesh(
     extrude_size: f32,
-    create_mode_state: &mut crate::resources::drawing::CreateModeState,
+    solid: PolygonalMeshSolid,
+    symmetry: Option<SymmetrySettings>,
+    create_mode_state: &mut CreateModeState,
     meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
     commands: &mut Commands,
     canvas_entity: Entity,
+    lifecycle_events: &mut LifecycleEventWriters,
 ) -> Result<(), String> {
+    if let Some(SymmetrySettings {
+        symmetry,
+        mode: SymmetryMode::Weld,
+        weld_tolerance,
+        ..
+    }) = symmetry
+    {
+        // drawn path is one half/sector of the polygon
+        let path = create_mode_state.mesh_polygon.vertices.get_all_owned();
+        create_mode_state.mesh_polygon = symmetric_polygon(&path, &symmetry, weld_tolerance)
+            .ok_or_else(|| "Symmetric polygon is degenerate!".to_string())?;
+    }
     if create_mode_state.mesh_polygon.vertices.len() < 3 {
         return Err("Vertices are less than 3!".to_string());
     }
@@ -158,36 +308,69 @@ fn close_polygon_and_extrude_mesh(
         // but internally it results in ccw. Somehow. IT IS OPPOSITE!
         create_mode_state.mesh_polygon.reverse();
     }
-    // Create mesh from vertices
-    let generated_mesh = create_mode_state
-        .mesh_polygon
-        .extrude_to_bevy_mesh(extrude_size)
-        .ok_or_else(|| "Vertices are less than 3!".to_string())?;
-    // create comp for mesh spawning
-    let mesh_handle = meshes.add(generated_mesh);
     let polygonal_mesh = PolygonalMesh {
         mesh_polygon: create_mode_state.mesh_polygon.clone(),
-        mesh_handle: Some(mesh_handle.clone()),
+        mesh_handle: None,
+        solid,
     };
-    let manual_mesh_material = materials.add(Color::srgba(0.8, 0.7, 0.6, 1.0));
-    let highlight_mat_kind = HighlightKind::<StandardMaterial>::Fixed(manual_mesh_material.clone());
-    let new_mesh_entity = commands
-        .spawn(MaterialMeshBundle {
-            mesh: mesh_handle,
-            material: manual_mesh_material.clone(),
-            transform: Transform::from_translation(Vec3::ZERO),
-            ..default()
-        })
-        .insert(polygonal_mesh)
-        .insert(PickableBundle::default())
-        .insert(Highlight::<StandardMaterial> {
-            hovered: Some(highlight_mat_kind.clone()),
-            pressed: Some(highlight_mat_kind.clone()),
-            selected: Some(highlight_mat_kind.clone()),
-        })
-        .id();
-    // add new mesh as child of canvas
-    commands.entity(canvas_entity).add_child(new_mesh_entity);
+    let entity = spawn_polygonal_mesh(
+        polygonal_mesh,
+        extrude_size,
+        meshes,
+        commands,
+        canvas_entity,
+    )?;
+    lifecycle_events
+        .created
+        .send(PolygonalMeshCreated { entity });
+    match symmetry {
+        Some(SymmetrySettings {
+            symmetry,
+            mode: SymmetryMode::Weld,
+            weld_tolerance,
+            ..
+        }) => {
+            commands.entity(entity).insert(SymmetricPolygonalMesh {
+                symmetry,
+                weld_tolerance,
+            });
+        }
+        Some(SymmetrySettings {
+            symmetry,
+            mode: SymmetryMode::LinkedCopies,
+            ..
+        }) => {
+            commands.entity(entity).insert(SymmetryLink {
+                symmetry,
+                source: entity,
+                index: 0,
+            });
+            // spawn the images, reflected ones are reversed to stay in cw order
+            for index in 1..symmetry.image_count() {
+                let polygonal_mesh = PolygonalMesh {
+                    mesh_polygon: symmetry.apply_to_polygon(&create_mode_state.mesh_polygon, index),
+                    mesh_handle: None,
+                    solid,
+                };
+                let copy_entity = spawn_polygonal_mesh(
+                    polygonal_mesh,
+                    extrude_size,
+                    meshes,
+                    commands,
+                    canvas_entity,
+                )?;
+                commands.entity(copy_entity).insert(SymmetryLink {
+                    symmetry,
+                    source: entity,
+                    index,
+                });
+                lifecycle_events.created.send(PolygonalMeshCreated {
+                    entity: copy_entity,
+                });
+            }
+        }
+        None => {}
+    }
     // reset polygon state
     create_mode_state.mesh_polygon.clear_with_reset();
     Ok(())
@@ -207,3 +390,38 @@ fn cleanup_create_mode_entities(
         commands.entity(entity).insert(Cleanup::Recursive);
     }
 }
+
+/// Draw preview of the shape being dragged out.
+pub fn draw_shape_draft_preview(
+    mut gizmos: Gizmos,
+    settings: Res<MeshDrawingPluginSettings>,
+    theme: Res<MeshDrawingTheme>,
+    drawing_state: Res<DrawingState>,
+    query_canvas: Query<&GlobalTransform, With<Canvas>>,
+) {
+    let DrawingMode::CreateMode(CreateModeState {
+        shape_draft: Some(draft),
+        ..
+    }) = &drawing_state.mode
+    else {
+        return;
+    };
+    let Ok(canvas_transform) = query_canvas.get_single() else {
+        return;
+    };
+    let to_world = |point: Vec2| canvas_to_world(point, canvas_transform);
+    let points = match draft.mesh_polygon(&settings) {
+        Some(mesh_polygon) => {
+            let mut points = mesh_polygon.outline();
+            points.extend(points.first().cloned());
+            points
+        }
+        // not complete yet, just connect the anchors to the cursor
+        None => {
+            let mut points = draft.anchors.clone();
+            points.push(draft.cursor);
+            points
+        }
+    };
+    gizmos.linestrip(points.into_iter().map(to_world), theme.shape_preview_color);
+}
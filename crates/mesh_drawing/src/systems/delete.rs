@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use crate::{
+    events::edit_mode::EditModeEvent,
+    resources::{drawing::EditModeState, DrawingMode, DrawingState, MeshDrawingPluginSettings},
+};
+
+/// Handle delete input.
+///
+/// Dispatch `EditModeEvent::PolygonalMeshDelete` for the active mesh.
+pub fn handle_delete_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    settings: Res<MeshDrawingPluginSettings>,
+    drawing_state: Res<DrawingState>,
+    mut edit_mode_event: EventWriter<EditModeEvent>,
+) {
+    if !settings.is_edit_mode_delete_enabled
+        || !keyboard_input.just_pressed(settings.input_binds.edit_mode_delete_key)
+    {
+        return;
+    }
+    if let DrawingMode::EditMode(EditModeState {
+        active_mesh: Some(_),
+        ..
+    }) = drawing_state.mode
+    {
+        edit_mode_event.send(EditModeEvent::PolygonalMeshDelete);
+    }
+}
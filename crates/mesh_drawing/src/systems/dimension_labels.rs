@@ -0,0 +1,154 @@
+use bevy::{prelude::*, utils::HashMap};
+use mesh_geometry_utils::data_structures::MeshPolygon;
+
+use crate::{
+    components::{Canvas, Cleanup, DimensionLabel, MeshDrawingCamera, PolygonalMesh},
+    resources::{
+        drawing::EditModeState, DrawingMode, DrawingState, MeshDrawingPluginSettings,
+        MeshDrawingTheme,
+    },
+};
+
+/// Handle dimension labels toggle input.
+pub fn handle_dimension_labels_toggle(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<MeshDrawingPluginSettings>,
+) {
+    if settings.is_dimension_labels_enabled
+        && keyboard_input.just_pressed(settings.input_binds.dimension_labels_toggle_key)
+    {
+        settings.dimension_labels.is_visible = !settings.dimension_labels.is_visible;
+    }
+}
+
+/// Update the on-screen dimension labels.
+///
+/// Labels the length of each edge at its midpoint and the area of the polygon at
+/// its centroid, for the polygon being created or the active mesh being edited.
+/// Labels are spawned/despawned to match the edges of the polygon.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_dimension_labels(
+    mut commands: Commands,
+    settings: Res<MeshDrawingPluginSettings>,
+    theme: Res<MeshDrawingTheme>,
+    drawing_state: Res<DrawingState>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MeshDrawingCamera>>,
+    query_canvas: Query<&GlobalTransform, With<Canvas>>,
+    query_meshes: Query<(&PolygonalMesh, &GlobalTransform)>,
+    mut query_labels: Query<
+        (Entity, &DimensionLabel, &mut Text, &mut Style, &Node),
+        Without<Cleanup>,
+    >,
+) {
+    let polygon_with_transform = match &drawing_state.mode {
+        DrawingMode::EditMode(EditModeState {
+            active_mesh: Some(active_mesh),
+            ..
+        }) => query_meshes
+            .get(*active_mesh)
+            .ok()
+            .map(|(polygonal_mesh, transform)| (&polygonal_mesh.mesh_polygon, transform)),
+        DrawingMode::EditMode(_) => None,
+        DrawingMode::CreateMode(create_mode_state) => query_canvas
+            .get_single()
+            .ok()
+            .map(|transform| (&create_mode_state.mesh_polygon, transform)),
+    };
+    let labels = match (polygon_with_transform, query_camera.get_single()) {
+        (Some((mesh_polygon, transform)), Ok(_))
+            if settings.is_dimension_labels_enabled && settings.dimension_labels.is_visible =>
+        {
+            collect_dimension_labels(mesh_polygon, transform, &settings)
+        }
+        _ => HashMap::new(),
+    };
+    let mut labels_to_spawn = labels.clone();
+    // update existing labels, remove the stale ones...
+    for (entity, label, mut text, mut style, node) in query_labels.iter_mut() {
+        let Some((value, position)) = labels.get(label) else {
+            commands.entity(entity).insert(Cleanup::Recursive);
+            continue;
+        };
+        labels_to_spawn.remove(label);
+        if let Some(section) = text.sections.first_mut() {
+            if section.value != *value {
+                section.value.clone_from(value);
+            }
+            if section.style.color != theme.dimension_label_color {
+                section.style.color = theme.dimension_label_color;
+            }
+        }
+        let Ok((camera, camera_transform)) = query_camera.get_single() else {
+            continue;
+        };
+        // anchor the center of the label at the position on screen
+        if let Some(screen_position) = camera.world_to_viewport(camera_transform, *position) {
+            let top_left = screen_position - node.size() / 2.;
+            style.display = Display::Flex;
+            style.left = Val::Px(top_left.x);
+            style.top = Val::Px(top_left.y);
+        } else {
+            style.display = Display::None;
+        }
+    }
+    // spawn the new labels, positioned on the next update...
+    for (label, (value, _)) in labels_to_spawn {
+        commands.spawn((
+            TextBundle::from_section(
+                value,
+                TextStyle {
+                    font_size: settings.dimension_labels.font_size,
+                    color: theme.dimension_label_color,
+                    ..default()
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                display: Display::None,
+                ..default()
+            }),
+            label,
+        ));
+    }
+}
+
+/// Collect the text and world position of the labels of the polygon.
+fn collect_dimension_labels(
+    mesh_polygon: &MeshPolygon,
+    transform: &GlobalTransform,
+    settings: &MeshDrawingPluginSettings,
+) -> HashMap<DimensionLabel, (String, Vec3)> {
+    let to_world = |point: Vec2| transform.transform_point(Vec3::new(point.x, 0., point.y));
+    let mut labels = HashMap::new();
+    for (edge, length) in mesh_polygon.edge_lengths() {
+        let (Some(from_vert), Some(to_vert)) = (
+            mesh_polygon.vertices.get(edge.from),
+            mesh_polygon.vertices.get(edge.to),
+        ) else {
+            continue;
+        };
+        let midpoint = match mesh_polygon.edge_curve(&edge) {
+            Some(curve) => curve.point_at(*from_vert, *to_vert, 0.5),
+            None => (*from_vert + *to_vert) / 2.,
+        };
+        labels.insert(
+            DimensionLabel::EdgeLength(edge),
+            (
+                settings.dimension_labels.format_length(length),
+                to_world(midpoint),
+            ),
+        );
+    }
+    if mesh_polygon.vertices.len() > 2 {
+        if let Some(centroid) = mesh_polygon.centroid() {
+            labels.insert(
+                DimensionLabel::Area,
+                (
+                    settings.dimension_labels.format_area(mesh_polygon.area()),
+                    to_world(centroid),
+                ),
+            );
+        }
+    }
+    labels
+}
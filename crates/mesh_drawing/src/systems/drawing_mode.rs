@@ -2,12 +2,13 @@ use bevy::prelude::*;
 
 use crate::{
     events::{create_mode::CreateModeEvent, edit_mode::EditModeEvent},
-    resources::MeshDrawingPluginSettings,
+    resources::{DrawingMode, DrawingState, MeshDrawingPluginSettings},
 };
 
 pub fn handle_drawing_mode_transition(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     settings: Res<MeshDrawingPluginSettings>,
+    drawing_state: Res<DrawingState>,
     mut edit_mode_event: EventWriter<EditModeEvent>,
     mut create_mode_event: EventWriter<CreateModeEvent>,
 ) {
@@ -18,5 +19,9 @@ pub fn handle_drawing_mode_transition(
     } else if keyboard_input.just_pressed(input_binds.create_mode_switch_key) {
         info!("Change to create mode...");
         edit_mode_event.send(EditModeEvent::CreateModeSwitch);
+    } else if keyboard_input.just_pressed(input_binds.create_mode_cycle_tool_key)
+        && matches!(drawing_state.mode, DrawingMode::CreateMode(_))
+    {
+        create_mode_event.send(CreateModeEvent::ToolCycle);
     }
 }
@@ -1,18 +1,40 @@
 use bevy::{math::Vec3Swizzles, prelude::*};
 use bevy_mod_picking::prelude::Pickable;
-use mesh_geometry_utils::data_structures::Edge;
+use mesh_geometry_utils::{
+    data_structures::{Edge, EdgeCurve, MeshPolygon, VertexId},
+    operations::{
+        array::{circular_array, linear_array},
+        constraints::{solve_constraints, Constraint},
+        corner::{chamfer_vertex, fillet_vertex},
+        merge::merge_polygons,
+        split::{nearest_boundary_point, split_by_polyline},
+    },
+};
 
 use crate::{
     components::{
-        Cleanup, EdgeIndicator, GrabTransformable, PolygonalMesh, PolygonalMeshIndicators,
-        VertexIndicator,
+        Canvas, Cleanup, EdgeIndicator, GrabTransformable, PolygonalMesh, PolygonalMeshConstraint,
+        PolygonalMeshConstraints, PolygonalMeshIndicators, SymmetricPolygonalMesh,
+        TangentHandleIndicator, VertexIndicator,
+    },
+    events::{
+        constraint::PolygonalMeshConstraintConflict,
+        edit_mode::{EditModeEvent, InsertVertexData},
+        lifecycle::{
+            DrawingModeChanged, GeometryChangeKind, LifecycleEventWriters, PolygonalMeshCreated,
+            PolygonalMeshDeleted, PolygonalMeshGeometryChanged, PolygonalMeshSelectionChanged,
+        },
     },
-    events::edit_mode::{EditModeEvent, InsertVertexData},
     resources::{
         drawing::{CreateModeState, EditModeState},
-        DrawingMode, DrawingState, MeshDrawingPluginSettings,
+        DrawingMode, DrawingState, IndicatorAssets, IndicatorMode, MeshDrawingPluginSettings,
+        MeshDrawingTheme,
+    },
+    utils::indicators::{
+        despawn_polygonal_mesh_indicators, respawn_polygonal_mesh_indicators, spawn_edge_indicator,
+        spawn_polygonal_mesh_indicators, spawn_tangent_handle_indicators, spawn_vertex_indicators,
     },
-    utils::indicators::{spawn_edge_indicator, spawn_vertex_indicators, EDGE_INDICATOR_WIDTH},
+    utils::polygonal_mesh::{despawn_polygonal_mesh, spawn_polygonal_mesh},
 };
 
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
@@ -20,7 +42,7 @@ pub fn handle_edit_mode_events(
     mut events: EventReader<EditModeEvent>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    indicator_assets: Res<IndicatorAssets>,
     mut drawing_state: ResMut<DrawingState>,
     settings: Res<MeshDrawingPluginSettings>,
     query_vertex_indicators: Query<(Entity, &VertexIndicator)>,
@@ -33,8 +55,13 @@ pub fn handle_edit_mode_events(
         &PolygonalMesh,
         (With<Pickable>, Without<PolygonalMeshIndicators>),
     >,
+    query_canvas: Query<Entity, With<Canvas>>,
+    query_parents: Query<&Parent>,
+    mut lifecycle_events: LifecycleEventWriters,
 ) {
+    let mut active_mesh = active_mesh_of(&drawing_state.mode);
     for event in events.read() {
+        send_selection_changed(&drawing_state.mode, &mut active_mesh, &mut lifecycle_events);
         let DrawingMode::EditMode(edit_mode_state) = &mut drawing_state.mode else {
             return;
         };
@@ -51,45 +78,22 @@ pub fn handle_edit_mode_events(
                 if let Ok(PolygonalMesh { mesh_polygon, .. }) =
                     query_mesh_without_indicators.get(*entity)
                 {
-                    let mut indicators = PolygonalMeshIndicators {
-                        edges: vec![],
-                        vertices: vec![],
-                    };
-                    for (id, vertex) in mesh_polygon.vertices.enumerate() {
-                        let entity = spawn_vertex_indicators(
-                            *vertex,
+                    let indicators = match settings.indicator_mode {
+                        IndicatorMode::Entities => spawn_polygonal_mesh_indicators(
+                            mesh_polygon,
                             &mut commands,
-                            &mut meshes,
-                            &mut materials,
-                        );
-                        commands.entity(entity).insert(VertexIndicator(id));
-                        indicators.vertices.push(entity)
-                    }
-                    // spawn edge indicators
-                    for Edge { from, to } in mesh_polygon.edges.iter().cloned() {
-                        if let (Some(from_vert), Some(to_vert)) = (
-                            mesh_polygon.vertices.get(from),
-                            mesh_polygon.vertices.get(to),
-                        ) {
-                            let entity = spawn_edge_indicator(
-                                *from_vert,
-                                *to_vert,
-                                &mut commands,
-                                &mut meshes,
-                                &mut materials,
-                            );
-                            commands
-                                .entity(entity)
-                                .insert(EdgeIndicator(Edge { from, to }));
-                            indicators.edges.push(entity);
-                        }
-                    }
-                    // Push the vertex/edge indicators as children of selected mesh
+                            &indicator_assets,
+                        ),
+                        // drawn with gizmos, only the picked ones get (proxy) entities
+                        IndicatorMode::Gizmos => PolygonalMeshIndicators::default(),
+                    };
+                    // Push the vertex/edge/handle indicators as children of selected mesh
                     // and insert the component for future ref
                     commands
                         .entity(*entity)
                         .push_children(&indicators.vertices)
                         .push_children(&indicators.edges)
+                        .push_children(&indicators.tangent_handles)
                         .insert(indicators);
                     // Set active mesh
                     edit_mode_state.active_mesh = Some(*entity);
@@ -98,6 +102,7 @@ pub fn handle_edit_mode_events(
             EditModeEvent::VertexIndicatorJustPressed(entity) => {
                 // Set active entity
                 edit_mode_state.active_vertex_indicator = Some(*entity);
+                edit_mode_state.selected_vertex_indicator = Some(*entity);
                 // mark as gizmo transformable
                 commands.entity(*entity).insert(GrabTransformable);
                 // Deactivate rest of indicators
@@ -120,6 +125,20 @@ pub fn handle_edit_mode_events(
                 }
                 // Unset active entity
                 edit_mode_state.active_vertex_indicator = None;
+                // drag finished
+                if let Some(drag_change) = edit_mode_state.drag_change.take() {
+                    lifecycle_events.geometry_changed.send(drag_change);
+                }
+            }
+            EditModeEvent::VertexPositionSet(entity, position) => {
+                // Move the indicator and activate it, vertex is updated with the indicator
+                // ref: `handle_active_indicator`
+                edit_mode_state.active_vertex_indicator = Some(*entity);
+                commands
+                    .entity(*entity)
+                    .insert(Transform::from_translation(Vec3::new(
+                        position.x, 0., position.y,
+                    )));
             }
             EditModeEvent::Reset => {
                 let query_mesh_w_indicators_for_cleanup = query_mesh_indicators_set.p0();
@@ -138,6 +157,9 @@ pub fn handle_edit_mode_events(
                 );
                 // switch to create mode.
                 drawing_state.mode = DrawingMode::CreateMode(CreateModeState::default());
+                lifecycle_events.mode_changed.send(DrawingModeChanged {
+                    mode: drawing_state.mode.clone(),
+                });
             }
             EditModeEvent::VertexInsert(InsertVertexData { edge, translation }) => {
                 let Some(active_mesh) = edit_mode_state.active_mesh else {
@@ -156,6 +178,17 @@ pub fn handle_edit_mode_events(
                 else {
                     continue;
                 };
+                lifecycle_events
+                    .geometry_changed
+                    .send(PolygonalMeshGeometryChanged {
+                        entity: active_mesh,
+                        kind: GeometryChangeKind::VertexInserted,
+                        vertices: vec![vertex_id],
+                    });
+                if settings.indicator_mode == IndicatorMode::Gizmos {
+                    // no indicator entities to update
+                    continue;
+                }
                 // cleanup existing edge indicator
                 for (entity, indicator) in query_edge_indicators.iter() {
                     if indicator.0 == edge.clone() {
@@ -166,12 +199,8 @@ pub fn handle_edit_mode_events(
                     }
                 }
                 // Draw new vertex indicator
-                let entity = spawn_vertex_indicators(
-                    translation.xz(),
-                    &mut commands,
-                    &mut meshes,
-                    &mut materials,
-                );
+                let entity =
+                    spawn_vertex_indicators(translation.xz(), &mut commands, &indicator_assets);
                 // Mark as indicator
                 commands.entity(entity).insert(VertexIndicator(vertex_id));
                 // push vertex indicator as child of active mesh
@@ -193,8 +222,7 @@ pub fn handle_edit_mode_events(
                             *from_vert,
                             *to_vert,
                             &mut commands,
-                            &mut meshes,
-                            &mut materials,
+                            &indicator_assets,
                         );
                         commands
                             .entity(entity)
@@ -205,20 +233,14 @@ pub fn handle_edit_mode_events(
                         polygonal_mesh_indicators.edges.push(entity);
                     }
                 }
-                // regenerate mesh and assign it to existing...
-                let Some(new_mesh) = polygonal_mesh
-                    .mesh_polygon
-                    .extrude_to_bevy_mesh(settings.extrude_size)
-                else {
-                    error!("Could not extrude mesh!");
-                    return;
-                };
-                if let Some(mesh_handle) = polygonal_mesh.mesh_handle.clone() {
-                    if let Some(mesh) = meshes.get_mut(&mesh_handle) {
-                        info!("Generating new mesh...");
-                        mesh.clone_from(&new_mesh);
-                    }
-                }
+                // refresh tangent handles as the curves might have changed
+                respawn_tangent_handle_indicators(
+                    &mut commands,
+                    &indicator_assets,
+                    active_mesh,
+                    &polygonal_mesh.mesh_polygon,
+                    &mut polygonal_mesh_indicators,
+                );
             }
             EditModeEvent::VertexRemove(entity) => {
                 // get vertex id
@@ -257,6 +279,17 @@ pub fn handle_edit_mode_events(
                 // remove from indicators list
                 polygonal_mesh_indicators.vertices.retain(|e| e != entity);
                 commands.entity(*entity).insert(Cleanup::Recursive);
+                lifecycle_events
+                    .geometry_changed
+                    .send(PolygonalMeshGeometryChanged {
+                        entity: active_mesh,
+                        kind: GeometryChangeKind::VertexRemoved,
+                        vertices: vec![*vertex_id],
+                    });
+                if settings.indicator_mode == IndicatorMode::Gizmos {
+                    // no indicator entities to update
+                    continue;
+                }
                 // Draw the added edges...
                 for Edge { from, to } in added_edges {
                     if let (Some(from_vert), Some(to_vert)) = (
@@ -267,8 +300,7 @@ pub fn handle_edit_mode_events(
                             *from_vert,
                             *to_vert,
                             &mut commands,
-                            &mut meshes,
-                            &mut materials,
+                            &indicator_assets,
                         );
                         commands
                             .entity(entity)
@@ -279,23 +311,428 @@ pub fn handle_edit_mode_events(
                         polygonal_mesh_indicators.edges.push(entity);
                     }
                 }
-                // regenerate mesh and assign it to existing...
-                let Some(new_mesh) = polygonal_mesh
-                    .mesh_polygon
-                    .extrude_to_bevy_mesh(settings.extrude_size)
+                // refresh tangent handles as the curves might have changed
+                respawn_tangent_handle_indicators(
+                    &mut commands,
+                    &indicator_assets,
+                    active_mesh,
+                    &polygonal_mesh.mesh_polygon,
+                    &mut polygonal_mesh_indicators,
+                );
+            }
+            EditModeEvent::EdgeCurveToggle(edge) => {
+                let Some(active_mesh) = edit_mode_state.active_mesh else {
+                    continue;
+                };
+                let mut query_mesh_with_indicators = query_mesh_indicators_set.p1();
+                let Ok((mut polygonal_mesh, mut polygonal_mesh_indicators)) =
+                    query_mesh_with_indicators.get_mut(active_mesh)
+                else {
+                    continue;
+                };
+                let mesh_polygon = &mut polygonal_mesh.mesh_polygon;
+                let (Some(from_vert), Some(to_vert)) = (
+                    mesh_polygon.vertices.get(edge.from).cloned(),
+                    mesh_polygon.vertices.get(edge.to).cloned(),
+                ) else {
+                    continue;
+                };
+                // curved -> straight, straight -> curved
+                let curve = match mesh_polygon.edge_curve(edge) {
+                    Some(_) => None,
+                    None => Some(EdgeCurve::bend(from_vert, to_vert)),
+                };
+                if !mesh_polygon.set_edge_curve(edge.clone(), curve) {
+                    continue;
+                }
+                lifecycle_events
+                    .geometry_changed
+                    .send(PolygonalMeshGeometryChanged {
+                        entity: active_mesh,
+                        kind: GeometryChangeKind::EdgeCurveChanged,
+                        vertices: vec![edge.from, edge.to],
+                    });
+                if settings.indicator_mode == IndicatorMode::Gizmos {
+                    // handles are drawn with gizmos
+                    continue;
+                }
+                respawn_tangent_handle_indicators(
+                    &mut commands,
+                    &indicator_assets,
+                    active_mesh,
+                    &polygonal_mesh.mesh_polygon,
+                    &mut polygonal_mesh_indicators,
+                );
+            }
+            EditModeEvent::KnifeToggle => {
+                if edit_mode_state.knife_path.take().is_none()
+                    && edit_mode_state.active_mesh.is_some()
+                {
+                    info!("Knife activated...");
+                    edit_mode_state.knife_path = Some(vec![]);
+                }
+            }
+            EditModeEvent::KnifePointAdd(translation) => {
+                let (Some(active_mesh), Some(knife_path)) = (
+                    edit_mode_state.active_mesh,
+                    edit_mode_state.knife_path.as_mut(),
+                ) else {
+                    continue;
+                };
+                let query_mesh_with_indicators = query_mesh_indicators_set.p1();
+                let Ok((polygonal_mesh, _)) = query_mesh_with_indicators.get(active_mesh) else {
+                    continue;
+                };
+                let point = translation.xz();
+                let is_on_boundary = nearest_boundary_point(&polygonal_mesh.mesh_polygon, point)
+                    .is_some_and(|(_, _, distance)| distance <= settings.knife_snap_distance);
+                if knife_path.is_empty() && !is_on_boundary {
+                    warn!("Knife cut needs to start on the boundary of the mesh!");
+                    continue;
+                }
+                knife_path.push(point);
+                if knife_path.len() < 2 || !is_on_boundary {
+                    continue;
+                }
+                // cut reached the boundary again, split the mesh
+                let Some(pieces) = split_by_polyline(
+                    &polygonal_mesh.mesh_polygon,
+                    knife_path,
+                    settings.knife_snap_distance,
+                ) else {
+                    warn!("Knife cut does not split the mesh, discarded!");
+                    knife_path.clear();
+                    continue;
+                };
+                let Ok(canvas_entity) = query_canvas.get_single() else {
+                    continue;
+                };
+                let solid = polygonal_mesh.solid;
+                for mesh_polygon in [pieces.0, pieces.1] {
+                    let polygonal_mesh = PolygonalMesh {
+                        mesh_polygon,
+                        mesh_handle: None,
+                        solid,
+                    };
+                    match spawn_polygonal_mesh(
+                        polygonal_mesh,
+                        settings.extrude_size,
+                        &mut meshes,
+                        &mut commands,
+                        canvas_entity,
+                    ) {
+                        Ok(entity) => {
+                            lifecycle_events
+                                .created
+                                .send(PolygonalMeshCreated { entity });
+                        }
+                        Err(error) => error!("error: {:?}", error),
+                    }
+                }
+                // replace the original mesh with the pieces
+                let query_mesh_w_indicators_for_cleanup = query_mesh_indicators_set.p0();
+                cleanup_edit_mode_entities_and_reset(
+                    &mut commands,
+                    edit_mode_state,
+                    &query_mesh_w_indicators_for_cleanup,
+                );
+                despawn_polygonal_mesh(active_mesh, &mut commands);
+                lifecycle_events.deleted.send(PolygonalMeshDeleted {
+                    entity: active_mesh,
+                });
+            }
+            EditModeEvent::VertexFillet(entity) | EditModeEvent::VertexChamfer(entity) => {
+                let Some(active_mesh) = edit_mode_state.active_mesh else {
+                    continue;
+                };
+                let Ok((_, VertexIndicator(vertex_id))) = query_vertex_indicators.get(*entity)
+                else {
+                    continue;
+                };
+                let mut query_mesh_with_indicators = query_mesh_indicators_set.p1();
+                let Ok((mut polygonal_mesh, mut polygonal_mesh_indicators)) =
+                    query_mesh_with_indicators.get_mut(active_mesh)
+                else {
+                    continue;
+                };
+                let mesh_polygon = &mut polygonal_mesh.mesh_polygon;
+                let result = match event {
+                    EditModeEvent::VertexFillet(_) => fillet_vertex(
+                        mesh_polygon,
+                        *vertex_id,
+                        settings.fillet_radius,
+                        settings.fillet_segments,
+                    ),
+                    _ => chamfer_vertex(mesh_polygon, *vertex_id, settings.chamfer_distance),
+                };
+                let Some(result) = result else {
+                    warn!("Corner can't be modified, adjacent edges need to be straight!");
+                    continue;
+                };
+                let mut vertices = vec![*vertex_id];
+                vertices.extend(result.vertex_ids.iter().cloned());
+                lifecycle_events
+                    .geometry_changed
+                    .send(PolygonalMeshGeometryChanged {
+                        entity: active_mesh,
+                        kind: GeometryChangeKind::CornerModified,
+                        vertices,
+                    });
+                if result.is_clamped {
+                    warn!(
+                        "Corner size clamped to {:.3} to fit the adjacent edges!",
+                        result.size
+                    );
+                }
+                // vertices changed, refresh all indicators
+                match settings.indicator_mode {
+                    IndicatorMode::Entities => respawn_polygonal_mesh_indicators(
+                        &mut commands,
+                        &indicator_assets,
+                        active_mesh,
+                        &polygonal_mesh.mesh_polygon,
+                        &mut polygonal_mesh_indicators,
+                    ),
+                    IndicatorMode::Gizmos => despawn_polygonal_mesh_indicators(
+                        &mut commands,
+                        &mut polygonal_mesh_indicators,
+                    ),
+                }
+                edit_mode_state.active_vertex_indicator = None;
+                edit_mode_state.selected_vertex_indicator = None;
+            }
+            EditModeEvent::PolygonalMeshDelete => {
+                let Some(active_mesh) = edit_mode_state.active_mesh else {
+                    continue;
+                };
+                let query_mesh_with_indicators = query_mesh_indicators_set.p1();
+                if !query_mesh_with_indicators.contains(active_mesh) {
+                    continue;
+                }
+                // cleanup indicators and deactivate...
+                let query_mesh_w_indicators_for_cleanup = query_mesh_indicators_set.p0();
+                cleanup_edit_mode_entities_and_reset(
+                    &mut commands,
+                    edit_mode_state,
+                    &query_mesh_w_indicators_for_cleanup,
+                );
+                // cleanup the mesh along with its children, its assets are freed with the handles
+                despawn_polygonal_mesh(active_mesh, &mut commands);
+                lifecycle_events.deleted.send(PolygonalMeshDeleted {
+                    entity: active_mesh,
+                });
+            }
+            EditModeEvent::PolygonalMeshDuplicate
+            | EditModeEvent::PolygonalMeshMirror
+            | EditModeEvent::PolygonalMeshLinearArray
+            | EditModeEvent::PolygonalMeshCircularArray => {
+                let Some(active_mesh) = edit_mode_state.active_mesh else {
+                    continue;
+                };
+                // copies share the canvas of the active mesh
+                let Ok(canvas_entity) = query_parents
+                    .get(active_mesh)
+                    .map(|parent| parent.get())
+                    .or_else(|_| query_canvas.get_single())
                 else {
-                    error!("Could not extrude mesh!");
-                    return;
+                    continue;
+                };
+                let query_mesh_with_indicators = query_mesh_indicators_set.p1();
+                let Ok((
+                    PolygonalMesh {
+                        mesh_polygon,
+                        solid,
+                        ..
+                    },
+                    _,
+                )) = query_mesh_with_indicators.get(active_mesh)
+                else {
+                    continue;
+                };
+                let array = settings.array;
+                let copies = match event {
+                    EditModeEvent::PolygonalMeshDuplicate => {
+                        vec![mesh_polygon.translated(array.duplicate_offset)]
+                    }
+                    EditModeEvent::PolygonalMeshMirror => {
+                        vec![mesh_polygon.mirrored(array.mirror_origin, array.mirror_direction)]
+                    }
+                    EditModeEvent::PolygonalMeshLinearArray => {
+                        linear_array(mesh_polygon, array.count as usize, array.linear_spacing)
+                    }
+                    _ => circular_array(
+                        mesh_polygon,
+                        array.circular_center,
+                        array.count as usize,
+                        array.circular_angle,
+                    ),
+                };
+                let solid = *solid;
+                for mesh_polygon in copies {
+                    let polygonal_mesh = PolygonalMesh {
+                        mesh_polygon,
+                        mesh_handle: None,
+                        solid,
+                    };
+                    match spawn_polygonal_mesh(
+                        polygonal_mesh,
+                        settings.extrude_size,
+                        &mut meshes,
+                        &mut commands,
+                        canvas_entity,
+                    ) {
+                        Ok(entity) => {
+                            lifecycle_events
+                                .created
+                                .send(PolygonalMeshCreated { entity });
+                        }
+                        Err(error) => error!("error: {:?}", error),
+                    }
+                }
+            }
+            EditModeEvent::PolygonalMeshMultiSelect(entity) => {
+                let multi_selection = &mut edit_mode_state.multi_selection;
+                // the active mesh is part of the selection
+                if let Some(active_mesh) = edit_mode_state.active_mesh {
+                    if !multi_selection.contains(&active_mesh) {
+                        multi_selection.push(active_mesh);
+                    }
+                }
+                if let Some(idx) = multi_selection.iter().position(|e| e == entity) {
+                    multi_selection.remove(idx);
+                } else {
+                    multi_selection.push(*entity);
+                }
+            }
+            EditModeEvent::MergeSelected => {
+                let Ok(canvas_entity) = query_canvas.get_single() else {
+                    continue;
+                };
+                // collect the selected meshes (active one has indicators)
+                let mut selected = vec![];
+                for entity in edit_mode_state.multi_selection.iter() {
+                    let query_mesh_with_indicators = query_mesh_indicators_set.p1();
+                    let polygonal_mesh = match query_mesh_without_indicators.get(*entity) {
+                        Ok(polygonal_mesh) => polygonal_mesh,
+                        Err(_) => match query_mesh_with_indicators.get(*entity) {
+                            Ok((polygonal_mesh, _)) => polygonal_mesh,
+                            Err(_) => continue,
+                        },
+                    };
+                    selected.push((
+                        *entity,
+                        polygonal_mesh.mesh_polygon.clone(),
+                        polygonal_mesh.solid,
+                    ));
+                }
+                if selected.len() < 2 {
+                    continue;
+                }
+                // merge one adjacent mesh at a time
+                let (_, mut merged, solid) = selected[0].clone();
+                let mut remaining = selected[1..].iter().collect::<Vec<_>>();
+                while !remaining.is_empty() {
+                    let Some((idx, result)) =
+                        remaining
+                            .iter()
+                            .enumerate()
+                            .find_map(|(idx, (_, mesh_polygon, ..))| {
+                                merge_polygons(&merged, mesh_polygon, settings.merge_tolerance)
+                                    .map(|result| (idx, result))
+                            })
+                    else {
+                        break;
+                    };
+                    merged = result;
+                    remaining.swap_remove(idx);
+                }
+                if !remaining.is_empty() {
+                    warn!("Selected meshes are not all adjacent, can't merge!");
+                    continue;
+                }
+                let polygonal_mesh = PolygonalMesh {
+                    mesh_polygon: merged,
+                    mesh_handle: None,
+                    solid,
                 };
-                if let Some(mesh_handle) = polygonal_mesh.mesh_handle.clone() {
-                    if let Some(mesh) = meshes.get_mut(&mesh_handle) {
-                        debug!("Generating new mesh...");
-                        mesh.clone_from(&new_mesh);
+                match spawn_polygonal_mesh(
+                    polygonal_mesh,
+                    settings.extrude_size,
+                    &mut meshes,
+                    &mut commands,
+                    canvas_entity,
+                ) {
+                    Ok(entity) => {
+                        lifecycle_events
+                            .created
+                            .send(PolygonalMeshCreated { entity });
                     }
+                    Err(error) => {
+                        error!("error: {:?}", error);
+                        continue;
+                    }
+                }
+                // replace the originals with the merged mesh
+                let query_mesh_w_indicators_for_cleanup = query_mesh_indicators_set.p0();
+                cleanup_edit_mode_entities_and_reset(
+                    &mut commands,
+                    edit_mode_state,
+                    &query_mesh_w_indicators_for_cleanup,
+                );
+                for (entity, ..) in selected {
+                    despawn_polygonal_mesh(entity, &mut commands);
+                    lifecycle_events
+                        .deleted
+                        .send(PolygonalMeshDeleted { entity });
                 }
             }
         }
     }
+    send_selection_changed(&drawing_state.mode, &mut active_mesh, &mut lifecycle_events);
+}
+
+/// Active mesh of the drawing mode (`None` outside edit mode).
+fn active_mesh_of(mode: &DrawingMode) -> Option<Entity> {
+    match mode {
+        DrawingMode::EditMode(edit_mode_state) => edit_mode_state.active_mesh,
+        DrawingMode::CreateMode(_) => None,
+    }
+}
+
+/// Send [`PolygonalMeshSelectionChanged`] if the active mesh differs from the last known one.
+fn send_selection_changed(
+    mode: &DrawingMode,
+    active_mesh: &mut Option<Entity>,
+    lifecycle_events: &mut LifecycleEventWriters,
+) {
+    let selected = active_mesh_of(mode);
+    if selected != *active_mesh {
+        lifecycle_events
+            .selection_changed
+            .send(PolygonalMeshSelectionChanged {
+                selected,
+                previous: *active_mesh,
+            });
+        *active_mesh = selected;
+    }
+}
+
+/// Replace the tangent handle indicators of the mesh with fresh ones for its current curves.
+fn respawn_tangent_handle_indicators(
+    commands: &mut Commands,
+    indicator_assets: &IndicatorAssets,
+    mesh_entity: Entity,
+    mesh_polygon: &MeshPolygon,
+    indicators: &mut PolygonalMeshIndicators,
+) {
+    for entity in indicators.tangent_handles.drain(..) {
+        commands.entity(entity).insert(Cleanup::Recursive);
+    }
+    indicators.tangent_handles =
+        spawn_tangent_handle_indicators(mesh_polygon, commands, indicator_assets);
+    commands
+        .entity(mesh_entity)
+        .push_children(&indicators.tangent_handles);
 }
 
 /// Mark all temporarily created edit mode entities for cleanup and reset state.
@@ -304,8 +741,14 @@ fn cleanup_edit_mode_entities_and_reset(
     edit_mode_state: &mut EditModeState,
     query_mesh_w_indicators_for_cleanup: &Query<(Entity, &PolygonalMeshIndicators), With<Pickable>>,
 ) {
-    for (entity, PolygonalMeshIndicators { vertices, edges }) in
-        query_mesh_w_indicators_for_cleanup.iter()
+    for (
+        entity,
+        PolygonalMeshIndicators {
+            vertices,
+            edges,
+            tangent_handles,
+        },
+    ) in query_mesh_w_indicators_for_cleanup.iter()
     {
         // cleanup the indicators comp on the mesh entity.
         commands.entity(entity).remove::<PolygonalMeshIndicators>();
@@ -316,82 +759,294 @@ fn cleanup_edit_mode_entities_and_reset(
         for entity in edges {
             commands.entity(*entity).insert(Cleanup::Recursive);
         }
+        for entity in tangent_handles {
+            commands.entity(*entity).insert(Cleanup::Recursive);
+        }
     }
-    // Deactivate mesh and indicator
+    // Deactivate mesh, indicator and tools
     edit_mode_state.active_mesh = None;
     edit_mode_state.active_vertex_indicator = None;
+    edit_mode_state.selected_vertex_indicator = None;
+    edit_mode_state.knife_path = None;
+    edit_mode_state.multi_selection.clear();
+    edit_mode_state.drag_change = None;
+    edit_mode_state.hovered_gizmo_indicator = None;
 }
 
 /// Handle active vertex indicator.
+///
+/// Also handles the active tangent handle indicator of curved edges.
+///
+/// Vertices of welded symmetric meshes move along with their symmetric counterparts.
+///
+/// Constraints of the mesh (if any) are solved with the moved vertex locked.
+///
+/// Changes of drags are collected and sent when the drag finishes, typed
+/// positions (not grabbed) are sent right away.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn handle_active_indicator(
-    query_moved_indicators: Query<(&Transform, &VertexIndicator), Changed<Transform>>,
+    query_moved_indicators: Query<
+        (&Transform, &VertexIndicator, Has<GrabTransformable>),
+        Changed<Transform>,
+    >,
+    query_moved_tangent_handles: Query<
+        (&Transform, &TangentHandleIndicator),
+        (Changed<Transform>, Without<VertexIndicator>),
+    >,
     mut query_with_indicators: Query<
-        &mut PolygonalMesh,
+        (
+            &mut PolygonalMesh,
+            Option<&mut PolygonalMeshConstraints>,
+            Option<&SymmetricPolygonalMesh>,
+            &GlobalTransform,
+        ),
         (With<Pickable>, Without<VertexIndicator>),
     >,
-    mut query_edge_indicators: Query<(&mut Transform, &EdgeIndicator), Without<VertexIndicator>>,
     settings: Res<MeshDrawingPluginSettings>,
-    drawing_state: Res<DrawingState>,
-    mut meshes: ResMut<Assets<Mesh>>,
+    mut drawing_state: ResMut<DrawingState>,
+    mut conflict_events: EventWriter<PolygonalMeshConstraintConflict>,
+    mut geometry_changed_events: EventWriter<PolygonalMeshGeometryChanged>,
 ) {
     let (active_mesh, active_vertex_indicator) = if let DrawingMode::EditMode(EditModeState {
         active_mesh: Some(active_mesh),
         active_vertex_indicator: Some(active_vertex_indicator),
+        ..
     }) = drawing_state.mode
     {
         (active_mesh, active_vertex_indicator)
     } else {
         return;
     };
-    let Ok((transform, VertexIndicator(vertex_id))) =
+    if let Ok((transform, TangentHandleIndicator { edge, index })) =
+        query_moved_tangent_handles.get(active_vertex_indicator)
+    {
+        let Ok((mut polygonal_mesh, ..)) = query_with_indicators.get_mut(active_mesh) else {
+            return;
+        };
+        let mesh_polygon = &mut polygonal_mesh.mesh_polygon;
+        if let (Some(curve), Some(from_vert), Some(to_vert)) = (
+            mesh_polygon.curves.get(edge).cloned(),
+            mesh_polygon.vertices.get(edge.from).cloned(),
+            mesh_polygon.vertices.get(edge.to).cloned(),
+        ) {
+            // Manipulate curve handle, since y is vertical we use z...
+            let curve = curve.with_handle(from_vert, to_vert, *index, transform.translation.xz());
+            mesh_polygon.curves.insert(edge.clone(), curve);
+            record_drag_change(
+                &mut drawing_state,
+                active_mesh,
+                GeometryChangeKind::EdgeCurveChanged,
+                &[edge.from, edge.to],
+            );
+        }
+        return;
+    }
+    let Ok((transform, VertexIndicator(vertex_id), is_grabbed)) =
         query_moved_indicators.get(active_vertex_indicator)
     else {
         return;
     };
-    let Ok(mut polygonal_mesh) = query_with_indicators.get_mut(active_mesh) else {
+    // resolve constraints (positions of the coincident vertices of other meshes)...
+    let constraints = resolve_polygonal_mesh_constraints(active_mesh, &query_with_indicators);
+    let Ok((mut polygonal_mesh, mesh_constraints, symmetric_mesh, _)) =
+        query_with_indicators.get_mut(active_mesh)
+    else {
         return;
     };
-    if let Some(vertex) = polygonal_mesh.mesh_polygon.vertices.get_mut(*vertex_id) {
-        // Manipulate vertex in path 2d...
-        vertex.x = transform.translation.x;
-        // since y is vertical we use z...
-        vertex.y = transform.translation.z;
-        // regenerate mesh and assign it to existing...
-        let Some(new_mesh) = polygonal_mesh
-            .mesh_polygon
-            .extrude_to_bevy_mesh(settings.extrude_size)
-        else {
-            error!("Could not extrude mesh!");
-            return;
-        };
-        if let Some(mesh_handle) = polygonal_mesh.mesh_handle.clone() {
-            if let Some(mesh) = meshes.get_mut(&mesh_handle) {
-                debug!("Generating new mesh...");
-                mesh.clone_from(&new_mesh);
+    // Manipulate vertex in path 2d, since y is vertical we use z...
+    let mut moved_vertices = match symmetric_mesh {
+        Some(SymmetricPolygonalMesh {
+            symmetry,
+            weld_tolerance,
+        }) => symmetry.move_vertex(
+            &mut polygonal_mesh.mesh_polygon,
+            *vertex_id,
+            transform.translation.xz(),
+            *weld_tolerance,
+        ),
+        None => {
+            if polygonal_mesh
+                .mesh_polygon
+                .move_vertex(*vertex_id, transform.translation.xz())
+            {
+                vec![*vertex_id]
+            } else {
+                vec![]
             }
         }
+    };
+    if moved_vertices.is_empty() {
+        return;
     }
-    // move edge indicators accordingly
-    for (mut transform, EdgeIndicator(Edge { from, to })) in query_edge_indicators.iter_mut() {
-        if from == vertex_id || to == vertex_id {
-            if let (Some(from_vert), Some(to_vert)) = (
-                polygonal_mesh.mesh_polygon.vertices.get(*from).cloned(),
-                polygonal_mesh.mesh_polygon.vertices.get(*to).cloned(),
-            ) {
-                // calc for new transform and set it...
-                let mid_pt = (to_vert + from_vert) / 2.;
-                let edge_vec = to_vert - from_vert;
-                let edge_rot_angle_y = edge_vec.angle_between(Vec2::X);
-                *transform = Transform {
-                    translation: Vec3::new(mid_pt.x, 0., mid_pt.y),
-                    rotation: Quat::from_rotation_y(edge_rot_angle_y),
-                    scale: Vec3::new(
-                        edge_vec.length(),
-                        EDGE_INDICATOR_WIDTH,
-                        EDGE_INDICATOR_WIDTH,
-                    ),
-                };
+    if let (Some(mut mesh_constraints), Some(constraints)) = (mesh_constraints, constraints) {
+        // keep the moved vertex (and its symmetric counterparts) locked
+        let locked = moved_vertices.clone();
+        let result = solve_constraints(
+            &mut polygonal_mesh.mesh_polygon,
+            &constraints,
+            &locked,
+            &settings.constraint_solver,
+        );
+        moved_vertices.extend(result.moved_vertices);
+        if result.conflicts != mesh_constraints.conflicts {
+            if !result.conflicts.is_empty() {
+                warn!("Constraints conflict: {:?}", result.conflicts);
+                conflict_events.send(PolygonalMeshConstraintConflict {
+                    entity: active_mesh,
+                    constraints: result
+                        .conflicts
+                        .iter()
+                        .filter_map(|index| mesh_constraints.constraints.get(*index).cloned())
+                        .collect(),
+                });
+            }
+            mesh_constraints.conflicts = result.conflicts;
+        }
+    }
+    if is_grabbed {
+        record_drag_change(
+            &mut drawing_state,
+            active_mesh,
+            GeometryChangeKind::VertexMoved,
+            &moved_vertices,
+        );
+    } else {
+        // typed position applied, nothing left to drag
+        geometry_changed_events.send(PolygonalMeshGeometryChanged {
+            entity: active_mesh,
+            kind: GeometryChangeKind::VertexMoved,
+            vertices: moved_vertices,
+        });
+        if let DrawingMode::EditMode(edit_mode_state) = &mut drawing_state.mode {
+            edit_mode_state.active_vertex_indicator = None;
+        }
+    }
+}
+
+/// Record the vertices changed by the current drag.
+///
+/// Sent as a single [`PolygonalMeshGeometryChanged`] when the drag finishes.
+fn record_drag_change(
+    drawing_state: &mut DrawingState,
+    entity: Entity,
+    kind: GeometryChangeKind,
+    vertices: &[VertexId],
+) {
+    let DrawingMode::EditMode(edit_mode_state) = &mut drawing_state.mode else {
+        return;
+    };
+    let drag_change =
+        edit_mode_state
+            .drag_change
+            .get_or_insert_with(|| PolygonalMeshGeometryChanged {
+                entity,
+                kind,
+                vertices: vec![],
+            });
+    if drag_change.entity != entity || drag_change.kind != kind {
+        *drag_change = PolygonalMeshGeometryChanged {
+            entity,
+            kind,
+            vertices: vec![],
+        };
+    }
+    for vertex_id in vertices {
+        if !drag_change.vertices.contains(vertex_id) {
+            drag_change.vertices.push(*vertex_id);
+        }
+    }
+}
+
+/// Resolve the constraints of the polygonal mesh into polygon constraints.
+///
+/// Coincident vertices are fixed at the (mesh local) position of their target vertex.
+/// Returns `None` if the mesh has no constraints.
+#[allow(clippy::type_complexity)]
+fn resolve_polygonal_mesh_constraints(
+    entity: Entity,
+    query_meshes: &Query<
+        (
+            &mut PolygonalMesh,
+            Option<&mut PolygonalMeshConstraints>,
+            Option<&SymmetricPolygonalMesh>,
+            &GlobalTransform,
+        ),
+        (With<Pickable>, Without<VertexIndicator>),
+    >,
+) -> Option<Vec<Constraint>> {
+    let (_, Some(mesh_constraints), _, global_transform) = query_meshes.get(entity).ok()? else {
+        return None;
+    };
+    let inverse_transform = global_transform.affine().inverse();
+    let constraints = mesh_constraints
+        .constraints
+        .iter()
+        .filter_map(|constraint| match constraint {
+            PolygonalMeshConstraint::Polygon(constraint) => Some(constraint.clone()),
+            PolygonalMeshConstraint::Coincident {
+                vertex,
+                target,
+                target_vertex,
+            } => {
+                let (target_mesh, _, _, target_transform) = query_meshes.get(*target).ok()?;
+                let target_vert = target_mesh.mesh_polygon.vertices.get(*target_vertex)?;
+                let position = inverse_transform.transform_point3(
+                    target_transform.transform_point(Vec3::new(target_vert.x, 0., target_vert.y)),
+                );
+                Some(Constraint::FixedPosition {
+                    vertex: *vertex,
+                    position: position.xz(),
+                })
+            }
+        })
+        .collect();
+    Some(constraints)
+}
+
+/// Draw guides for the curved edges of the active mesh.
+///
+/// Draws the flattened curves along with lines connecting the bezier
+/// control points to their vertices.
+pub fn draw_edge_curve_guides(
+    mut gizmos: Gizmos,
+    theme: Res<MeshDrawingTheme>,
+    drawing_state: Res<DrawingState>,
+    query_meshes: Query<(&PolygonalMesh, &GlobalTransform)>,
+) {
+    let DrawingMode::EditMode(EditModeState {
+        active_mesh: Some(active_mesh),
+        ..
+    }) = drawing_state.mode
+    else {
+        return;
+    };
+    let Ok((PolygonalMesh { mesh_polygon, .. }, global_transform)) = query_meshes.get(active_mesh)
+    else {
+        return;
+    };
+    let to_world = |point: Vec2| global_transform.transform_point(Vec3::new(point.x, 0., point.y));
+    let color = theme.edge_curve_guide_color;
+    for (edge, curve) in mesh_polygon.curves.0.iter() {
+        let (Some(from_vert), Some(to_vert)) = (
+            mesh_polygon.vertices.get(edge.from).cloned(),
+            mesh_polygon.vertices.get(edge.to).cloned(),
+        ) else {
+            continue;
+        };
+        let mut points = vec![from_vert];
+        points.extend(curve.flatten(from_vert, to_vert, mesh_polygon.flatten_tolerance));
+        points.push(to_vert);
+        gizmos.linestrip(points.into_iter().map(to_world), color);
+        match *curve {
+            EdgeCurve::QuadraticBezier(ctrl) => {
+                gizmos.line(to_world(from_vert), to_world(ctrl), color);
+                gizmos.line(to_world(ctrl), to_world(to_vert), color);
+            }
+            EdgeCurve::CubicBezier(ctrl1, ctrl2) => {
+                gizmos.line(to_world(from_vert), to_world(ctrl1), color);
+                gizmos.line(to_world(ctrl2), to_world(to_vert), color);
             }
+            EdgeCurve::Arc { .. } => {}
         }
     }
 }
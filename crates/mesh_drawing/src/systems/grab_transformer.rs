@@ -1,21 +1,31 @@
-use bevy::prelude::*;
-use bevy_mod_raycast::prelude::RaycastSource;
+use bevy::{math::Vec3Swizzles, prelude::*};
 
 use crate::{
-    components::{Canvas, GrabTransformable},
+    components::{
+        Canvas, GrabTransformable, PolygonalMesh, SymmetricPolygonalMesh, VertexIndicator,
+    },
     resources::{drawing::EditModeState, DrawingMode, DrawingState},
     utils::canvas_correction::get_canvas_corrected_translation,
 };
 
-use super::raycast::{get_first_intersection_data_for_source, MeshDrawingRaycastSet};
+use super::raycast::CanvasRaycast;
 
+/// Move the grabbed indicator to the cursor on the canvas.
+///
+/// Vertices on the mirror axis (at the radial center) of welded symmetric meshes stay on it.
+#[allow(clippy::type_complexity)]
 pub fn handle_vertex_indicator_grab(
     drawing_state: Res<DrawingState>,
-    mut query_indicators: Query<&mut Transform, (With<GrabTransformable>, Without<Canvas>)>,
-    query_intersections: Query<&RaycastSource<MeshDrawingRaycastSet>>,
-    query_canvas: Query<&Transform, With<Canvas>>,
+    mut query_indicators: Query<
+        (&mut Transform, Option<&VertexIndicator>),
+        (With<GrabTransformable>, Without<Canvas>),
+    >,
+    canvas_raycast: CanvasRaycast,
+    query_canvas: Query<&GlobalTransform, With<Canvas>>,
+    query_symmetric_meshes: Query<(&PolygonalMesh, &SymmetricPolygonalMesh)>,
 ) {
     let DrawingMode::EditMode(EditModeState {
+        active_mesh,
         active_vertex_indicator: Some(active_vertex_indicator),
         ..
     }) = drawing_state.mode
@@ -25,14 +35,29 @@ pub fn handle_vertex_indicator_grab(
     let Ok(canvas_transform) = query_canvas.get_single() else {
         return;
     };
-    let Ok(mut transform) = query_indicators.get_mut(active_vertex_indicator) else {
+    let Ok((mut transform, vertex_indicator)) = query_indicators.get_mut(active_vertex_indicator)
+    else {
         return;
     };
 
-    let Some((_, intersection)) = get_first_intersection_data_for_source(&query_intersections)
-    else {
+    let Some(cursor_position) = canvas_raycast.cursor_position() else {
         return;
     };
-    let position = get_canvas_corrected_translation(intersection.position(), canvas_transform);
+    let mut position = get_canvas_corrected_translation(cursor_position, canvas_transform);
+    // keep vertices on the symmetry axis/center on it...
+    if let (Some(VertexIndicator(vertex_id)), Some(Ok((polygonal_mesh, symmetric_mesh)))) = (
+        vertex_indicator,
+        active_mesh.map(|active_mesh| query_symmetric_meshes.get(active_mesh)),
+    ) {
+        if let Some(vertex) = polygonal_mesh.mesh_polygon.vertices.get(*vertex_id) {
+            if symmetric_mesh
+                .symmetry
+                .is_fixed_point(*vertex, symmetric_mesh.weld_tolerance)
+            {
+                let fixed_point = symmetric_mesh.symmetry.fixed_point_nearest(position.xz());
+                position = Vec3::new(fixed_point.x, position.y, fixed_point.y);
+            }
+        }
+    }
     transform.translation = position;
 }
@@ -0,0 +1,695 @@
+use bevy::{prelude::*, utils::HashSet, window::PrimaryWindow};
+use bevy_mod_picking::focus::PickingInteraction;
+use mesh_geometry_utils::{
+    data_structures::{Edge, MeshPolygon, VertexId},
+    operations::constraints::Constraint,
+};
+
+use crate::{
+    components::{
+        Canvas, EdgeIndicator, MeshDrawingCamera, PolygonalMesh, PolygonalMeshConstraint,
+        PolygonalMeshConstraints, PolygonalMeshIndicators, TangentHandleIndicator, VertexIndicator,
+    },
+    events::edit_mode::{EditModeEvent, InsertVertexData},
+    resources::{
+        drawing::{CreateModeState, EditModeState, GizmoIndicator},
+        DrawingMode, DrawingState, IndicatorAssets, IndicatorState, MeshDrawingPluginSettings,
+        MeshDrawingTheme,
+    },
+    utils::{
+        canvas_correction::get_canvas_corrected_translation,
+        indicators::{despawn_polygonal_mesh_indicators, spawn_gizmo_indicator_proxy},
+    },
+};
+
+use super::raycast::CanvasRaycast;
+
+/// Update the states of the indicators and swap their (shared) materials to match.
+///
+/// - Active: grabbed (active) vertex indicator, or pressed indicator.
+/// - Selected: selected vertex indicator.
+/// - Hovered: indicator under the pointer.
+/// - Invalid: vertex/edge indicator of a conflicting constraint of the active mesh.
+/// - Idle: rest of the indicators.
+///
+/// Indicators with custom scenes have no material, only their state is updated.
+#[allow(clippy::type_complexity)]
+pub fn update_indicator_states(
+    indicator_assets: Res<IndicatorAssets>,
+    drawing_state: Res<DrawingState>,
+    query_constraints: Query<&PolygonalMeshConstraints>,
+    mut query_indicators: Query<(
+        Entity,
+        &mut IndicatorState,
+        Option<&mut Handle<StandardMaterial>>,
+        Option<&PickingInteraction>,
+        Option<&VertexIndicator>,
+        Option<&EdgeIndicator>,
+    )>,
+) {
+    let (active_indicator, selected_indicator, (invalid_vertices, invalid_edges)) =
+        match &drawing_state.mode {
+            DrawingMode::EditMode(EditModeState {
+                active_mesh,
+                active_vertex_indicator,
+                selected_vertex_indicator,
+                ..
+            }) => (
+                *active_vertex_indicator,
+                *selected_vertex_indicator,
+                active_mesh
+                    .and_then(|active_mesh| query_constraints.get(active_mesh).ok())
+                    .map(conflicting_vertices_and_edges)
+                    .unwrap_or_default(),
+            ),
+            DrawingMode::CreateMode(_) => (None, None, Default::default()),
+        };
+    for (entity, mut state, material, interaction, vertex_indicator, edge_indicator) in
+        query_indicators.iter_mut()
+    {
+        let new_state = if active_indicator == Some(entity)
+            || interaction == Some(&PickingInteraction::Pressed)
+        {
+            IndicatorState::Active
+        } else if selected_indicator == Some(entity) {
+            IndicatorState::Selected
+        } else if interaction == Some(&PickingInteraction::Hovered) {
+            IndicatorState::Hovered
+        } else if vertex_indicator.is_some_and(|VertexIndicator(id)| invalid_vertices.contains(id))
+            || edge_indicator.is_some_and(|EdgeIndicator(edge)| invalid_edges.contains(edge))
+        {
+            IndicatorState::Invalid
+        } else {
+            IndicatorState::Idle
+        };
+        if *state != new_state {
+            *state = new_state;
+        }
+        let Some(mut material) = material else {
+            continue;
+        };
+        let style_assets = if vertex_indicator.is_some() {
+            &indicator_assets.vertex
+        } else if edge_indicator.is_some() {
+            &indicator_assets.edge
+        } else {
+            &indicator_assets.tangent_handle
+        };
+        // re-applied every frame, picking highlights might have swapped it
+        let state_material = style_assets.material(new_state);
+        if *material != *state_material {
+            *material = state_material.clone();
+        }
+    }
+}
+
+/// Apply the changes of the theme to the shared indicator meshes/materials.
+pub fn apply_theme_changes(
+    theme: Res<MeshDrawingTheme>,
+    mut indicator_assets: ResMut<IndicatorAssets>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // assets are created from the initial theme
+    if !theme.is_changed() || theme.is_added() {
+        return;
+    }
+    indicator_assets.apply_theme(&theme, &mut meshes, &mut materials);
+}
+
+/// Scale the indicators to keep a constant size on screen.
+///
+/// Ref: [`MeshDrawingTheme::indicator_pixels_per_unit`].
+#[allow(clippy::type_complexity)]
+pub fn scale_indicators_to_screen_size(
+    theme: Res<MeshDrawingTheme>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MeshDrawingCamera>>,
+    mut query_indicators: Query<
+        (&mut Transform, &GlobalTransform, Has<EdgeIndicator>),
+        With<IndicatorState>,
+    >,
+) {
+    let Ok((camera, camera_transform)) = query_camera.get_single() else {
+        return;
+    };
+    for (mut transform, global_transform, is_edge) in query_indicators.iter_mut() {
+        let scale = match theme.indicator_pixels_per_unit {
+            Some(pixels_per_unit) => {
+                // pixels per world unit at the indicator (previous frame position)
+                let Some(screen_pixels_per_unit) = screen_pixels_per_unit(
+                    camera,
+                    camera_transform,
+                    global_transform.translation(),
+                ) else {
+                    continue;
+                };
+                // scale of the parents (mesh/canvas)
+                let parent_scale = global_transform.compute_transform().scale.y / transform.scale.y;
+                if !parent_scale.is_normal() {
+                    continue;
+                }
+                pixels_per_unit / screen_pixels_per_unit / parent_scale
+            }
+            None => 1.,
+        };
+        let new_scale = if is_edge {
+            // length is set by the edge, only scale the width
+            Vec3::new(transform.scale.x, scale, scale)
+        } else {
+            Vec3::splat(scale)
+        };
+        if transform.scale != new_scale {
+            transform.scale = new_scale;
+        }
+    }
+}
+
+/// Draw the indicators of the active mesh (edit mode) or of the polygon being
+/// created (create mode) with gizmos, colored like the indicator entities.
+///
+/// Ref: [`IndicatorMode::Gizmos`](crate::resources::IndicatorMode::Gizmos).
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn draw_gizmo_indicators(
+    mut gizmos: Gizmos,
+    theme: Res<MeshDrawingTheme>,
+    drawing_state: Res<DrawingState>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MeshDrawingCamera>>,
+    query_canvas: Query<&GlobalTransform, With<Canvas>>,
+    query_meshes: Query<(
+        &PolygonalMesh,
+        &GlobalTransform,
+        Option<&PolygonalMeshConstraints>,
+    )>,
+    query_vertex_indicators: Query<&VertexIndicator>,
+    query_tangent_handle_indicators: Query<&TangentHandleIndicator>,
+) {
+    let Ok((camera, camera_transform)) = query_camera.get_single() else {
+        return;
+    };
+    let (mesh_polygon, global_transform, active, selected, hovered, invalid) =
+        match &drawing_state.mode {
+            DrawingMode::EditMode(EditModeState {
+                active_mesh: Some(active_mesh),
+                active_vertex_indicator,
+                selected_vertex_indicator,
+                hovered_gizmo_indicator,
+                ..
+            }) => {
+                let Ok((PolygonalMesh { mesh_polygon, .. }, global_transform, constraints)) =
+                    query_meshes.get(*active_mesh)
+                else {
+                    return;
+                };
+                let gizmo_indicator = |indicator: Option<Entity>| {
+                    indicator.and_then(|indicator| {
+                        gizmo_indicator_of(
+                            indicator,
+                            &query_vertex_indicators,
+                            &query_tangent_handle_indicators,
+                        )
+                    })
+                };
+                (
+                    mesh_polygon,
+                    global_transform,
+                    gizmo_indicator(*active_vertex_indicator),
+                    gizmo_indicator(*selected_vertex_indicator),
+                    hovered_gizmo_indicator.clone(),
+                    constraints
+                        .map(conflicting_vertices_and_edges)
+                        .unwrap_or_default(),
+                )
+            }
+            DrawingMode::EditMode(_) => return,
+            DrawingMode::CreateMode(CreateModeState { mesh_polygon, .. }) => {
+                let Ok(global_transform) = query_canvas.get_single() else {
+                    return;
+                };
+                (
+                    mesh_polygon,
+                    global_transform,
+                    None,
+                    None,
+                    None,
+                    Default::default(),
+                )
+            }
+        };
+    let (invalid_vertices, invalid_edges) = invalid;
+    // same priorities as `update_indicator_states`
+    let state_of = |indicator: GizmoIndicator, is_invalid: bool| {
+        if active.as_ref() == Some(&indicator) {
+            IndicatorState::Active
+        } else if selected.as_ref() == Some(&indicator) {
+            IndicatorState::Selected
+        } else if hovered.as_ref() == Some(&indicator) {
+            IndicatorState::Hovered
+        } else if is_invalid {
+            IndicatorState::Invalid
+        } else {
+            IndicatorState::Idle
+        }
+    };
+    let to_world = |point: Vec2| global_transform.transform_point(Vec3::new(point.x, 0., point.y));
+    let normal = global_transform.up();
+    let scale = global_transform.compute_transform().scale.x;
+    let radius = |size: f32, position: Vec3| match theme.indicator_pixels_per_unit {
+        Some(pixels_per_unit) => screen_pixels_per_unit(camera, camera_transform, position)
+            .map(|screen_pixels_per_unit| size * pixels_per_unit / screen_pixels_per_unit),
+        None => Some(size * scale),
+    };
+
+    for edge in mesh_polygon.edges.iter() {
+        let (Some(from_vert), Some(to_vert)) = (
+            mesh_polygon.vertices.get(edge.from),
+            mesh_polygon.vertices.get(edge.to),
+        ) else {
+            continue;
+        };
+        let state = state_of(
+            GizmoIndicator::Edge(edge.clone()),
+            invalid_edges.contains(edge),
+        );
+        gizmos.line(
+            to_world(*from_vert),
+            to_world(*to_vert),
+            theme.edge_indicator.colors.color(state),
+        );
+    }
+    for (edge, curve) in mesh_polygon.curves.0.iter() {
+        let (Some(from_vert), Some(to_vert)) = (
+            mesh_polygon.vertices.get(edge.from),
+            mesh_polygon.vertices.get(edge.to),
+        ) else {
+            continue;
+        };
+        for (index, handle) in curve.handles(*from_vert, *to_vert).into_iter().enumerate() {
+            let position = to_world(handle);
+            let Some(radius) = radius(theme.tangent_handle_indicator.size, position) else {
+                continue;
+            };
+            let indicator = GizmoIndicator::TangentHandle {
+                edge: edge.clone(),
+                index,
+            };
+            let color = theme
+                .tangent_handle_indicator
+                .colors
+                .color(state_of(indicator, false));
+            gizmos.circle(position, normal, radius, color);
+        }
+    }
+    for (id, vertex) in mesh_polygon.vertices.enumerate() {
+        let position = to_world(*vertex);
+        let Some(radius) = radius(theme.vertex_indicator.size, position) else {
+            continue;
+        };
+        let state = state_of(GizmoIndicator::Vertex(id), invalid_vertices.contains(&id));
+        gizmos.circle(
+            position,
+            normal,
+            radius,
+            theme.vertex_indicator.colors.color(state),
+        );
+    }
+}
+
+/// Pick the gizmo indicators of the active mesh under the cursor.
+///
+/// Updates the hovered indicator. When pressed, vertices and tangent handles
+/// get an (invisible) indicator entity used for grabbing like the indicator
+/// entities, edges are toggled/split like the edge indicators.
+///
+/// Ref: [`IndicatorMode::Gizmos`](crate::resources::IndicatorMode::Gizmos).
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn handle_gizmo_indicator_picking(
+    mut commands: Commands,
+    plugin_settings: Res<MeshDrawingPluginSettings>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_btn_input: Res<ButtonInput<MouseButton>>,
+    mut drawing_state: ResMut<DrawingState>,
+    mut edit_mode_event: EventWriter<EditModeEvent>,
+    query_window: Query<&Window, With<PrimaryWindow>>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MeshDrawingCamera>>,
+    query_canvas: Query<&GlobalTransform, With<Canvas>>,
+    canvas_raycast: CanvasRaycast,
+    mut query_meshes: Query<(
+        &PolygonalMesh,
+        &GlobalTransform,
+        &mut PolygonalMeshIndicators,
+    )>,
+    query_vertex_indicators: Query<&VertexIndicator>,
+    query_tangent_handle_indicators: Query<&TangentHandleIndicator>,
+) {
+    let DrawingMode::EditMode(edit_mode_state) = &mut drawing_state.mode else {
+        return;
+    };
+    if edit_mode_state.active_vertex_indicator.is_some() {
+        // grabbing, keep the grabbed indicator hovered
+        if mouse_btn_input.just_released(MouseButton::Left) {
+            edit_mode_event.send(EditModeEvent::VertexIndicatorJustReleased);
+        }
+        return;
+    }
+    let cursor_position = query_window
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position());
+    let is_knife_active = edit_mode_state.knife_path.is_some();
+    let (Some(active_mesh), Some(cursor_position), Ok((camera, camera_transform)), false) = (
+        edit_mode_state.active_mesh,
+        cursor_position,
+        query_camera.get_single(),
+        is_knife_active,
+    ) else {
+        // nothing to pick, or clicks are used by the knife tool
+        if edit_mode_state.hovered_gizmo_indicator.is_some() {
+            edit_mode_state.hovered_gizmo_indicator = None;
+        }
+        return;
+    };
+    let Ok((PolygonalMesh { mesh_polygon, .. }, global_transform, mut indicators)) =
+        query_meshes.get_mut(active_mesh)
+    else {
+        return;
+    };
+    let hovered = pick_gizmo_indicator(
+        mesh_polygon,
+        |point| {
+            let position = global_transform.transform_point(Vec3::new(point.x, 0., point.y));
+            camera.world_to_viewport(camera_transform, position)
+        },
+        cursor_position,
+        plugin_settings.gizmo_indicator_pick_radius,
+    );
+    if edit_mode_state.hovered_gizmo_indicator != hovered {
+        edit_mode_state.hovered_gizmo_indicator.clone_from(&hovered);
+    }
+    if !mouse_btn_input.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let input_binds = &plugin_settings.input_binds;
+    match hovered {
+        Some(GizmoIndicator::Edge(edge)) => {
+            if plugin_settings.is_edit_mode_edge_curve_enabled
+                && keyboard_input.pressed(input_binds.edit_mode_toggle_edge_curve_key)
+            {
+                edit_mode_event.send(EditModeEvent::EdgeCurveToggle(edge));
+            } else if plugin_settings.is_edit_mode_insert_vertex_enabled
+                && keyboard_input.pressed(input_binds.edit_mode_insert_vertex_key)
+            {
+                let (Ok(canvas_transform), Some(cursor_position)) =
+                    (query_canvas.get_single(), canvas_raycast.cursor_position())
+                else {
+                    return;
+                };
+                let intersection_pos =
+                    get_canvas_corrected_translation(cursor_position, canvas_transform);
+                edit_mode_event.send(EditModeEvent::VertexInsert(InsertVertexData {
+                    edge,
+                    translation: intersection_pos,
+                }));
+            }
+        }
+        Some(indicator) => {
+            let is_vertex = matches!(indicator, GizmoIndicator::Vertex(_));
+            let Some(entity) = get_or_spawn_gizmo_indicator_proxy(
+                &mut commands,
+                active_mesh,
+                mesh_polygon,
+                &mut indicators,
+                &indicator,
+                &query_vertex_indicators,
+                &query_tangent_handle_indicators,
+            ) else {
+                return;
+            };
+            if is_vertex
+                && plugin_settings.is_edit_mode_remove_vertex_enabled
+                && keyboard_input.pressed(input_binds.edit_mode_remove_vertex_key)
+            {
+                edit_mode_event.send(EditModeEvent::VertexRemove(entity));
+            } else {
+                edit_mode_event.send(EditModeEvent::VertexIndicatorJustPressed(entity));
+            }
+        }
+        None => {}
+    }
+}
+
+/// Vertices and edges of the conflicting constraints.
+fn conflicting_vertices_and_edges(
+    mesh_constraints: &PolygonalMeshConstraints,
+) -> (HashSet<VertexId>, HashSet<Edge>) {
+    let mut vertices = HashSet::new();
+    let mut edges = HashSet::new();
+    for index in mesh_constraints.conflicts.iter() {
+        match mesh_constraints.constraints.get(*index) {
+            Some(PolygonalMeshConstraint::Polygon(Constraint::FixedPosition {
+                vertex, ..
+            }))
+            | Some(PolygonalMeshConstraint::Coincident { vertex, .. }) => {
+                vertices.insert(*vertex);
+            }
+            Some(PolygonalMeshConstraint::Polygon(constraint)) => {
+                edges.extend(constraint.edges().into_iter().cloned());
+            }
+            None => {}
+        }
+    }
+    (vertices, edges)
+}
+
+/// Gizmo indicator of the vertex/tangent handle indicator entity.
+fn gizmo_indicator_of(
+    entity: Entity,
+    query_vertex_indicators: &Query<&VertexIndicator>,
+    query_tangent_handle_indicators: &Query<&TangentHandleIndicator>,
+) -> Option<GizmoIndicator> {
+    if let Ok(VertexIndicator(id)) = query_vertex_indicators.get(entity) {
+        return Some(GizmoIndicator::Vertex(*id));
+    }
+    query_tangent_handle_indicators.get(entity).ok().map(
+        |TangentHandleIndicator { edge, index }| GizmoIndicator::TangentHandle {
+            edge: edge.clone(),
+            index: *index,
+        },
+    )
+}
+
+/// Indicator entity of the picked vertex/tangent handle, spawned as a child of
+/// the mesh (replacing the previous one) if missing.
+fn get_or_spawn_gizmo_indicator_proxy(
+    commands: &mut Commands,
+    mesh_entity: Entity,
+    mesh_polygon: &MeshPolygon,
+    indicators: &mut PolygonalMeshIndicators,
+    indicator: &GizmoIndicator,
+    query_vertex_indicators: &Query<&VertexIndicator>,
+    query_tangent_handle_indicators: &Query<&TangentHandleIndicator>,
+) -> Option<Entity> {
+    let existing = indicators
+        .vertices
+        .iter()
+        .chain(indicators.tangent_handles.iter())
+        .copied()
+        .find(|entity| {
+            gizmo_indicator_of(
+                *entity,
+                query_vertex_indicators,
+                query_tangent_handle_indicators,
+            )
+            .as_ref()
+                == Some(indicator)
+        });
+    if existing.is_some() {
+        return existing;
+    }
+    let position = match indicator {
+        GizmoIndicator::Vertex(id) => *mesh_polygon.vertices.get(*id)?,
+        GizmoIndicator::TangentHandle { edge, index } => {
+            let curve = mesh_polygon.curves.get(edge)?;
+            let from_vert = *mesh_polygon.vertices.get(edge.from)?;
+            let to_vert = *mesh_polygon.vertices.get(edge.to)?;
+            *curve.handles(from_vert, to_vert).get(*index)?
+        }
+        GizmoIndicator::Edge(_) => return None,
+    };
+    despawn_polygonal_mesh_indicators(commands, indicators);
+    let entity = spawn_gizmo_indicator_proxy(position, commands);
+    match indicator {
+        GizmoIndicator::Vertex(id) => {
+            commands.entity(entity).insert(VertexIndicator(*id));
+            indicators.vertices.push(entity);
+        }
+        GizmoIndicator::TangentHandle { edge, index } => {
+            commands.entity(entity).insert(TangentHandleIndicator {
+                edge: edge.clone(),
+                index: *index,
+            });
+            indicators.tangent_handles.push(entity);
+        }
+        GizmoIndicator::Edge(_) => {}
+    }
+    commands.entity(mesh_entity).add_child(entity);
+    Some(entity)
+}
+
+/// Indicator of the polygon nearest to the cursor (viewport position) within
+/// the radius (pixels), vertices and tangent handles take precedence over edges.
+fn pick_gizmo_indicator(
+    mesh_polygon: &MeshPolygon,
+    to_viewport: impl Fn(Vec2) -> Option<Vec2>,
+    cursor_position: Vec2,
+    radius: f32,
+) -> Option<GizmoIndicator> {
+    let mut nearest = None;
+    let mut nearest_distance = radius;
+    for (id, vertex) in mesh_polygon.vertices.enumerate() {
+        let Some(position) = to_viewport(*vertex) else {
+            continue;
+        };
+        let distance = position.distance(cursor_position);
+        if distance < nearest_distance {
+            nearest = Some(GizmoIndicator::Vertex(id));
+            nearest_distance = distance;
+        }
+    }
+    for (edge, curve) in mesh_polygon.curves.0.iter() {
+        let (Some(from_vert), Some(to_vert)) = (
+            mesh_polygon.vertices.get(edge.from),
+            mesh_polygon.vertices.get(edge.to),
+        ) else {
+            continue;
+        };
+        for (index, handle) in curve.handles(*from_vert, *to_vert).into_iter().enumerate() {
+            let Some(position) = to_viewport(handle) else {
+                continue;
+            };
+            let distance = position.distance(cursor_position);
+            if distance < nearest_distance {
+                nearest = Some(GizmoIndicator::TangentHandle {
+                    edge: edge.clone(),
+                    index,
+                });
+                nearest_distance = distance;
+            }
+        }
+    }
+    if nearest.is_some() {
+        return nearest;
+    }
+    for edge in mesh_polygon.edges.iter() {
+        let (Some(from), Some(to)) = (
+            mesh_polygon
+                .vertices
+                .get(edge.from)
+                .and_then(|vertex| to_viewport(*vertex)),
+            mesh_polygon
+                .vertices
+                .get(edge.to)
+                .and_then(|vertex| to_viewport(*vertex)),
+        ) else {
+            continue;
+        };
+        let distance = distance_to_segment(cursor_position, from, to);
+        if distance < nearest_distance {
+            nearest = Some(GizmoIndicator::Edge(edge.clone()));
+            nearest_distance = distance;
+        }
+    }
+    nearest
+}
+
+/// Distance from the point to the segment.
+fn distance_to_segment(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+    if length_squared <= f32::EPSILON {
+        return point.distance(start);
+    }
+    let t = ((point - start).dot(segment) / length_squared).clamp(0., 1.);
+    point.distance(start + segment * t)
+}
+
+/// Viewport pixels per world unit at the position (perpendicular to the view).
+fn screen_pixels_per_unit(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    position: Vec3,
+) -> Option<f32> {
+    let start = camera.world_to_viewport(camera_transform, position)?;
+    let end = camera.world_to_viewport(camera_transform, position + *camera_transform.right())?;
+    let screen_pixels_per_unit = start.distance(end);
+    (screen_pixels_per_unit > f32::EPSILON).then_some(screen_pixels_per_unit)
+}
+
+#[test]
+fn test_pick_gizmo_indicator() {
+    // unit square, 100px per unit on screen
+    let polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(1., 0.),
+        Vec2::new(1., 1.),
+        Vec2::new(0., 1.),
+    ]);
+    let ids = polygon.vertices.ids();
+    let to_viewport = |point: Vec2| Some(point * 100.);
+    let pick =
+        |cursor: Vec2, radius: f32| pick_gizmo_indicator(&polygon, to_viewport, cursor, radius);
+
+    // within the pick radius of the vertex
+    assert_eq!(
+        pick(Vec2::new(105., 0.), 10.),
+        Some(GizmoIndicator::Vertex(ids[1]))
+    );
+    // edge (30px away) only within a larger radius
+    assert_eq!(pick(Vec2::new(50., 30.), 10.), None);
+    assert_eq!(
+        pick(Vec2::new(50., 30.), 40.),
+        Some(GizmoIndicator::Edge(Edge::new(ids[0], ids[1])))
+    );
+    // vertex wins over the (nearer) edge
+    assert_eq!(
+        pick(Vec2::new(8., 3.), 10.),
+        Some(GizmoIndicator::Vertex(ids[0]))
+    );
+}
+
+#[test]
+fn test_pick_gizmo_indicator_off_screen() {
+    let polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(1., 0.),
+        Vec2::new(1., 1.),
+        Vec2::new(0., 1.),
+    ]);
+    let ids = polygon.vertices.ids();
+    // vertices with `x > 0.5` are off screen (behind the camera, etc)
+    let to_viewport = |point: Vec2| (point.x <= 0.5).then_some(point * 100.);
+    // off screen vertex and its edges can't be picked
+    assert_eq!(
+        pick_gizmo_indicator(&polygon, to_viewport, Vec2::new(100., 0.), 10.),
+        None
+    );
+    assert_eq!(
+        pick_gizmo_indicator(&polygon, to_viewport, Vec2::new(50., 5.), 10.),
+        None
+    );
+    // rest is still picked
+    assert_eq!(
+        pick_gizmo_indicator(&polygon, to_viewport, Vec2::new(2., 50.), 10.),
+        Some(GizmoIndicator::Edge(Edge::new(ids[3], ids[0])))
+    );
+}
+
+#[test]
+fn test_distance_to_segment() {
+    let (start, end) = (Vec2::new(0., 0.), Vec2::new(10., 0.));
+    assert!((distance_to_segment(Vec2::new(5., 3.), start, end) - 3.).abs() < 1e-5);
+    // beyond the ends, distance to the nearest end
+    assert!((distance_to_segment(Vec2::new(-3., 4.), start, end) - 5.).abs() < 1e-5);
+    assert!((distance_to_segment(Vec2::new(13., 4.), start, end) - 5.).abs() < 1e-5);
+    // zero length segment
+    assert!((distance_to_segment(Vec2::new(3., 4.), start, start) - 5.).abs() < 1e-5);
+}
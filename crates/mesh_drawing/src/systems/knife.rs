@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::Canvas,
+    events::edit_mode::EditModeEvent,
+    resources::{
+        drawing::EditModeState, DrawingMode, DrawingState, MeshDrawingPluginSettings,
+        MeshDrawingTheme,
+    },
+    utils::canvas_correction::{canvas_to_world, get_canvas_corrected_translation},
+};
+
+use super::raycast::CanvasRaycast;
+
+/// Handle knife tool inputs.
+///
+/// Dispatch `EditModeEvent` to toggle the knife and add the clicked cut points.
+pub fn handle_knife_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_btn_input: Res<ButtonInput<MouseButton>>,
+    settings: Res<MeshDrawingPluginSettings>,
+    drawing_state: Res<DrawingState>,
+    canvas_raycast: CanvasRaycast,
+    query_canvas: Query<&GlobalTransform, With<Canvas>>,
+    mut edit_mode_event: EventWriter<EditModeEvent>,
+) {
+    if !settings.is_edit_mode_knife_enabled {
+        return;
+    }
+    let DrawingMode::EditMode(EditModeState {
+        active_mesh,
+        knife_path,
+        ..
+    }) = &drawing_state.mode
+    else {
+        return;
+    };
+    if keyboard_input.just_pressed(settings.input_binds.edit_mode_knife_key) {
+        if active_mesh.is_some() || knife_path.is_some() {
+            edit_mode_event.send(EditModeEvent::KnifeToggle);
+        }
+        return;
+    }
+    if knife_path.is_none() {
+        return;
+    }
+    if mouse_btn_input.just_pressed(MouseButton::Left) {
+        let Ok(canvas_transform) = query_canvas.get_single() else {
+            return;
+        };
+        let Some(cursor_position) = canvas_raycast.cursor_position() else {
+            return;
+        };
+        let translation = get_canvas_corrected_translation(cursor_position, canvas_transform);
+        edit_mode_event.send(EditModeEvent::KnifePointAdd(translation));
+    } else if mouse_btn_input.just_pressed(MouseButton::Right) {
+        // cancel the knife
+        edit_mode_event.send(EditModeEvent::KnifeToggle);
+    }
+}
+
+/// Draw preview of the knife cut path, up to the cursor.
+pub fn draw_knife_preview(
+    mut gizmos: Gizmos,
+    theme: Res<MeshDrawingTheme>,
+    drawing_state: Res<DrawingState>,
+    canvas_raycast: CanvasRaycast,
+    query_canvas: Query<&GlobalTransform, With<Canvas>>,
+) {
+    let DrawingMode::EditMode(EditModeState {
+        knife_path: Some(knife_path),
+        ..
+    }) = &drawing_state.mode
+    else {
+        return;
+    };
+    if knife_path.is_empty() {
+        return;
+    }
+    let Ok(canvas_transform) = query_canvas.get_single() else {
+        return;
+    };
+    let mut points = knife_path
+        .iter()
+        .map(|point| canvas_to_world(*point, canvas_transform))
+        .collect::<Vec<_>>();
+    if let Some(cursor_position) = canvas_raycast.cursor_position() {
+        points.push(cursor_position);
+    }
+    gizmos.linestrip(points, theme.knife_preview_color);
+}
@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::PolygonalMesh,
+    events::edit_mode::EditModeEvent,
+    resources::{
+        drawing::EditModeState, DrawingMode, DrawingState, MeshDrawingPluginSettings,
+        MeshDrawingTheme,
+    },
+};
+
+/// Handle merge input.
+///
+/// Dispatch `EditModeEvent::MergeSelected` when 2 or more meshes are multi selected.
+pub fn handle_merge_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    settings: Res<MeshDrawingPluginSettings>,
+    drawing_state: Res<DrawingState>,
+    mut edit_mode_event: EventWriter<EditModeEvent>,
+) {
+    if !settings.is_edit_mode_merge_enabled
+        || !keyboard_input.just_pressed(settings.input_binds.edit_mode_merge_key)
+    {
+        return;
+    }
+    let DrawingMode::EditMode(EditModeState {
+        multi_selection, ..
+    }) = &drawing_state.mode
+    else {
+        return;
+    };
+    if multi_selection.len() > 1 {
+        edit_mode_event.send(EditModeEvent::MergeSelected);
+    }
+}
+
+/// Draw outlines of the multi selected meshes.
+pub fn draw_multi_selection_outlines(
+    mut gizmos: Gizmos,
+    theme: Res<MeshDrawingTheme>,
+    drawing_state: Res<DrawingState>,
+    query_meshes: Query<(&PolygonalMesh, &GlobalTransform)>,
+) {
+    let DrawingMode::EditMode(EditModeState {
+        multi_selection, ..
+    }) = &drawing_state.mode
+    else {
+        return;
+    };
+    for entity in multi_selection.iter() {
+        let Ok((PolygonalMesh { mesh_polygon, .. }, global_transform)) = query_meshes.get(*entity)
+        else {
+            continue;
+        };
+        let mut points = mesh_polygon.outline();
+        points.extend(points.first().cloned());
+        gizmos.linestrip(
+            points
+                .into_iter()
+                .map(|point| global_transform.transform_point(Vec3::new(point.x, 0., point.y))),
+            theme.multi_selection_color,
+        );
+    }
+}
@@ -1,9 +1,20 @@
+pub mod array;
 pub mod cleanup;
+pub mod constraints;
+pub mod coordinate_input;
+pub mod corner;
 pub mod create_mode;
 pub mod debug;
+pub mod delete;
+pub mod dimension_labels;
 pub mod drawing_mode;
 pub mod edit_mode;
 pub mod grab_transformer;
+pub mod indicators;
+pub mod knife;
+pub mod merge;
 pub mod picker;
+pub mod polygonal_mesh;
 pub mod raycast;
 pub mod state;
+pub mod symmetry;
@@ -1,20 +1,25 @@
 use bevy::prelude::*;
-use bevy_mod_picking::prelude::{Click, On, Pickable, Pointer};
-use bevy_mod_raycast::prelude::RaycastSource;
+use bevy_mod_picking::{
+    focus::PickingInteraction,
+    prelude::{Click, On, Pickable, Pointer},
+};
 
 use crate::{
-    components::{Canvas, EdgeIndicator, PolygonalMesh, PolygonalMeshIndicators, VertexIndicator},
+    components::{
+        Canvas, EdgeIndicator, PolygonalMesh, PolygonalMeshIndicators, TangentHandleIndicator,
+        VertexIndicator,
+    },
     events::{
         edit_mode::{EditModeEvent, InsertVertexData},
         picker::PickerClickEvent,
     },
-    resources::MeshDrawingPluginSettings,
-    systems::raycast::get_first_intersection_data_for_source,
+    resources::{
+        drawing::EditModeState, DrawingMode, DrawingState, IndicatorMode, MeshDrawingPluginSettings,
+    },
+    systems::raycast::CanvasRaycast,
     utils::canvas_correction::get_canvas_corrected_translation,
 };
 
-use super::raycast::MeshDrawingRaycastSet;
-
 /// Configure relevant entities to fire click event
 pub fn add_picker_click_event_to_pickable(
     mut commands: Commands,
@@ -48,8 +53,9 @@ pub fn handle_picker_events(
     mut events: EventReader<PickerClickEvent>,
     mut edit_mode_event: EventWriter<EditModeEvent>,
     plugin_settings: Res<MeshDrawingPluginSettings>,
-    query_canvas: Query<&Transform, With<Canvas>>,
-    query_intersections: Query<&RaycastSource<MeshDrawingRaycastSet>>,
+    drawing_state: Res<DrawingState>,
+    query_canvas: Query<&GlobalTransform, With<Canvas>>,
+    canvas_raycast: CanvasRaycast,
     query_mesh_with_indicators: Query<&PolygonalMeshIndicators, With<Pickable>>,
     query_mesh_without_indicators: Query<
         &PolygonalMesh,
@@ -57,11 +63,29 @@ pub fn handle_picker_events(
     >,
     query_vertex_indicators: Query<&VertexIndicator>,
     query_edge_indicators: Query<&EdgeIndicator>,
+    query_tangent_handle_indicators: Query<(), With<TangentHandleIndicator>>,
 ) {
     let Ok(canvas_transform) = query_canvas.get_single() else {
         return;
     };
+    let is_knife_active = matches!(
+        drawing_state.mode,
+        DrawingMode::EditMode(EditModeState {
+            knife_path: Some(_),
+            ..
+        })
+    );
+    let is_gizmo_indicator_hovered =
+        is_gizmo_indicator_hovered(&plugin_settings, &drawing_state.mode);
     for event in events.read() {
+        if is_knife_active {
+            // clicks are used by the knife tool
+            continue;
+        }
+        if is_gizmo_indicator_hovered {
+            // clicks are used by the gizmo indicators (under the pointer)
+            continue;
+        }
         if event.target != event.listener() {
             // skip propagated events...
             continue;
@@ -71,6 +95,13 @@ pub fn handle_picker_events(
         if query_canvas.contains(entity) {
             // if canvas is clicked cleanup every thing
             edit_mode_event.send(EditModeEvent::Reset);
+        } else if plugin_settings.is_edit_mode_merge_enabled
+            && keyboard_input.pressed(plugin_settings.input_binds.edit_mode_multi_select_key)
+            && (query_mesh_with_indicators.contains(entity)
+                || query_mesh_without_indicators.contains(entity))
+        {
+            // if any mesh is clicked with the multi select key.
+            edit_mode_event.send(EditModeEvent::PolygonalMeshMultiSelect(entity));
         } else if query_mesh_with_indicators.contains(entity) {
             // if current mesh (with active indicators) is clicked.
             // Do nothing for now
@@ -89,24 +120,84 @@ pub fn handle_picker_events(
                 continue;
             };
             // if edge indicator is clicked
-            if plugin_settings.is_edit_mode_insert_vertex_enabled
+            if plugin_settings.is_edit_mode_edge_curve_enabled
+                && keyboard_input
+                    .pressed(plugin_settings.input_binds.edit_mode_toggle_edge_curve_key)
+            {
+                edit_mode_event.send(EditModeEvent::EdgeCurveToggle(edge.clone()));
+            } else if plugin_settings.is_edit_mode_insert_vertex_enabled
                 && keyboard_input.pressed(plugin_settings.input_binds.edit_mode_insert_vertex_key)
             {
-                let Some((_, intersection)) =
-                    get_first_intersection_data_for_source(&query_intersections)
-                else {
+                let Some(cursor_position) = canvas_raycast.cursor_position() else {
                     continue;
                 };
                 let intersection_pos =
-                    get_canvas_corrected_translation(intersection.position(), canvas_transform);
+                    get_canvas_corrected_translation(cursor_position, canvas_transform);
                 edit_mode_event.send(EditModeEvent::VertexInsert(InsertVertexData {
                     edge: edge.clone(),
                     translation: intersection_pos,
                 }));
             }
+        } else if query_tangent_handle_indicators.contains(entity) {
+            // if tangent handle indicator is clicked.
+            // Do nothing, handles are only dragged.
         } else {
             // For any other reset everything
             edit_mode_event.send(EditModeEvent::Reset);
         }
     }
 }
+
+/// Handle the clicks on the (not pickable) canvas of [`CanvasRaycastMode::Plane`](crate::resources::CanvasRaycastMode::Plane).
+///
+/// Dispatch `EditModeEvent::Reset` when pressed and released on the canvas,
+/// away from any pickable entity (mesh, indicator).
+pub fn handle_canvas_plane_clicks(
+    mouse_btn_input: Res<ButtonInput<MouseButton>>,
+    mut is_pressed_on_canvas: Local<bool>,
+    mut edit_mode_event: EventWriter<EditModeEvent>,
+    plugin_settings: Res<MeshDrawingPluginSettings>,
+    drawing_state: Res<DrawingState>,
+    canvas_raycast: CanvasRaycast,
+    query_interactions: Query<&PickingInteraction>,
+) {
+    if !mouse_btn_input.just_pressed(MouseButton::Left)
+        && !mouse_btn_input.just_released(MouseButton::Left)
+    {
+        return;
+    }
+    let is_knife_active = matches!(
+        drawing_state.mode,
+        DrawingMode::EditMode(EditModeState {
+            knife_path: Some(_),
+            ..
+        })
+    );
+    let is_on_canvas = !is_knife_active
+        && !is_gizmo_indicator_hovered(&plugin_settings, &drawing_state.mode)
+        && query_interactions
+            .iter()
+            .all(|interaction| *interaction == PickingInteraction::None)
+        && canvas_raycast.cursor_position().is_some();
+    if mouse_btn_input.just_pressed(MouseButton::Left) {
+        *is_pressed_on_canvas = is_on_canvas;
+    } else if std::mem::take(&mut *is_pressed_on_canvas) && is_on_canvas {
+        // if canvas is clicked cleanup every thing
+        edit_mode_event.send(EditModeEvent::Reset);
+    }
+}
+
+/// If a gizmo indicator is under the pointer, its clicks aren't for the entities below.
+fn is_gizmo_indicator_hovered(
+    plugin_settings: &MeshDrawingPluginSettings,
+    mode: &DrawingMode,
+) -> bool {
+    plugin_settings.indicator_mode == IndicatorMode::Gizmos
+        && matches!(
+            mode,
+            DrawingMode::EditMode(EditModeState {
+                hovered_gizmo_indicator: Some(_),
+                ..
+            })
+        )
+}
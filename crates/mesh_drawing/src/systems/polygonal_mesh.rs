@@ -0,0 +1,372 @@
+use bevy::{
+    prelude::*,
+    tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task},
+};
+use bevy_mod_picking::prelude::{Highlight, HighlightKind};
+use mesh_geometry_utils::data_structures::MeshPolygon;
+
+use crate::{
+    components::{
+        EdgeIndicator, PolygonalMesh, PolygonalMeshGenerationPending, PolygonalMeshGenerationTask,
+        PolygonalMeshIndicators, TangentHandleIndicator, VertexIndicator,
+    },
+    resources::{
+        drawing::EditModeState, DrawingMode, DrawingState, IndicatorAssets, IndicatorMode,
+        MeshDrawingPluginSettings, MeshDrawingTheme,
+    },
+    utils::{
+        indicators::{
+            despawn_polygonal_mesh_indicators, edge_indicator_transform,
+            respawn_polygonal_mesh_indicators,
+        },
+        polygonal_mesh::{
+            assign_polygonal_mesh, regenerate_polygonal_mesh, update_polygonal_mesh_in_place,
+        },
+    },
+};
+
+/// Regenerate the meshes of the changed polygonal meshes (edited by the plugin or
+/// mutated directly) and resync their indicators (if any).
+///
+/// Runs once a frame, so a mesh is regenerated at most once no matter how many
+/// edits it had. Indicators are moved to their vertices/edges/handles, or
+/// respawned if the vertices/edges/curves were added or removed.
+///
+/// Large polygons are generated in the background if
+/// [`MeshDrawingPluginSettings::is_async_mesh_generation_enabled`], unless
+/// their mesh can be updated in place.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn regenerate_changed_polygonal_meshes(
+    mut commands: Commands,
+    settings: Res<MeshDrawingPluginSettings>,
+    mut drawing_state: ResMut<DrawingState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    indicator_assets: Res<IndicatorAssets>,
+    mut query_meshes: Query<
+        (
+            Entity,
+            &mut PolygonalMesh,
+            Option<&mut PolygonalMeshIndicators>,
+            Option<&Handle<Mesh>>,
+            Has<PolygonalMeshGenerationTask>,
+        ),
+        Changed<PolygonalMesh>,
+    >,
+    mut query_vertex_indicators: Query<(&mut Transform, &VertexIndicator)>,
+    mut query_edge_indicators: Query<(&mut Transform, &EdgeIndicator), Without<VertexIndicator>>,
+    mut query_tangent_handles: Query<
+        (&mut Transform, &TangentHandleIndicator),
+        (Without<VertexIndicator>, Without<EdgeIndicator>),
+    >,
+) {
+    for (entity, mut polygonal_mesh, indicators, mesh_handle, has_task) in query_meshes.iter_mut() {
+        // freshly spawned meshes are already generated...
+        if !(polygonal_mesh.is_added() && polygonal_mesh.mesh_handle.is_some()) {
+            // bypass to not trigger the change detection again
+            let polygonal_mesh = polygonal_mesh.bypass_change_detection();
+            let is_async = settings.is_async_mesh_generation_enabled
+                && polygonal_mesh.mesh_polygon.vertices.len()
+                    >= settings.async_mesh_generation_min_vertices;
+            if is_async
+                && !update_polygonal_mesh_in_place(
+                    polygonal_mesh,
+                    settings.extrude_size,
+                    &mut meshes,
+                )
+            {
+                // previous mesh is kept until the task finishes, replaces (drops) superseded task
+                let task = spawn_mesh_generation_task(polygonal_mesh, settings.extrude_size);
+                commands.entity(entity).insert((
+                    PolygonalMeshGenerationTask(task),
+                    PolygonalMeshGenerationPending,
+                ));
+            } else {
+                if !is_async {
+                    regenerate_polygonal_mesh(polygonal_mesh, settings.extrude_size, &mut meshes);
+                }
+                if has_task {
+                    // mesh is up to date, result of the task would be stale
+                    commands
+                        .entity(entity)
+                        .remove::<(PolygonalMeshGenerationTask, PolygonalMeshGenerationPending)>();
+                }
+                sync_mesh_handle(&mut commands, entity, polygonal_mesh, mesh_handle);
+            }
+        }
+        let Some(mut indicators) = indicators else {
+            continue;
+        };
+        let mesh_polygon = &polygonal_mesh.mesh_polygon;
+        let is_in_sync = match settings.indicator_mode {
+            IndicatorMode::Entities => are_indicators_in_sync(
+                mesh_polygon,
+                &indicators,
+                &query_vertex_indicators,
+                &query_edge_indicators,
+                &query_tangent_handles,
+            ),
+            IndicatorMode::Gizmos => are_gizmo_indicator_proxies_in_sync(
+                mesh_polygon,
+                &indicators,
+                &query_vertex_indicators,
+                &query_tangent_handles,
+            ),
+        };
+        if !is_in_sync {
+            match settings.indicator_mode {
+                IndicatorMode::Entities => respawn_polygonal_mesh_indicators(
+                    &mut commands,
+                    &indicator_assets,
+                    entity,
+                    mesh_polygon,
+                    &mut indicators,
+                ),
+                // drawn with gizmos, picked vertex/handle is gone
+                IndicatorMode::Gizmos => {
+                    despawn_polygonal_mesh_indicators(&mut commands, &mut indicators)
+                }
+            }
+            if let DrawingMode::EditMode(edit_mode_state) = &mut drawing_state.mode {
+                if edit_mode_state.active_mesh == Some(entity) {
+                    // old indicators are gone
+                    edit_mode_state.active_vertex_indicator = None;
+                    edit_mode_state.selected_vertex_indicator = None;
+                    edit_mode_state.drag_change = None;
+                }
+            }
+            continue;
+        }
+        // skip the active (grabbed) indicator, it drives the mesh
+        let active_vertex_indicator = match drawing_state.mode {
+            DrawingMode::EditMode(EditModeState {
+                active_vertex_indicator,
+                ..
+            }) => active_vertex_indicator,
+            DrawingMode::CreateMode(_) => None,
+        };
+        for indicator in indicators.vertices.iter() {
+            if Some(*indicator) == active_vertex_indicator {
+                continue;
+            }
+            let Ok((mut transform, VertexIndicator(vertex_id))) =
+                query_vertex_indicators.get_mut(*indicator)
+            else {
+                continue;
+            };
+            let Some(vertex) = mesh_polygon.vertices.get(*vertex_id) else {
+                continue;
+            };
+            let translation = Vec3::new(vertex.x, 0., vertex.y);
+            if transform.translation != translation {
+                transform.translation = translation;
+            }
+        }
+        for indicator in indicators.edges.iter() {
+            let Ok((mut transform, EdgeIndicator(edge))) =
+                query_edge_indicators.get_mut(*indicator)
+            else {
+                continue;
+            };
+            let (Some(from_vert), Some(to_vert)) = (
+                mesh_polygon.vertices.get(edge.from),
+                mesh_polygon.vertices.get(edge.to),
+            ) else {
+                continue;
+            };
+            let mut edge_transform = edge_indicator_transform(*from_vert, *to_vert);
+            // keep the width, might be scaled to the screen
+            edge_transform.scale.y = transform.scale.y;
+            edge_transform.scale.z = transform.scale.z;
+            if *transform != edge_transform {
+                *transform = edge_transform;
+            }
+        }
+        for indicator in indicators.tangent_handles.iter() {
+            if Some(*indicator) == active_vertex_indicator {
+                continue;
+            }
+            let Ok((mut transform, TangentHandleIndicator { edge, index })) =
+                query_tangent_handles.get_mut(*indicator)
+            else {
+                continue;
+            };
+            let (Some(curve), Some(from_vert), Some(to_vert)) = (
+                mesh_polygon.curves.get(edge),
+                mesh_polygon.vertices.get(edge.from),
+                mesh_polygon.vertices.get(edge.to),
+            ) else {
+                continue;
+            };
+            let Some(handle) = curve.handles(*from_vert, *to_vert).get(*index).cloned() else {
+                continue;
+            };
+            let translation = Vec3::new(handle.x, 0., handle.y);
+            if transform.translation != translation {
+                transform.translation = translation;
+            }
+        }
+    }
+}
+
+/// Add the material of the theme (and picking highlights) to the new polygonal meshes.
+///
+/// Meshes spawned with their own material keep it.
+pub fn insert_polygonal_mesh_materials(
+    mut commands: Commands,
+    theme: Res<MeshDrawingTheme>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    query_meshes: Query<Entity, (Added<PolygonalMesh>, Without<Handle<StandardMaterial>>)>,
+) {
+    for entity in query_meshes.iter() {
+        let mesh_material = materials.add(theme.mesh_material.clone());
+        let highlight_mat_kind = HighlightKind::<StandardMaterial>::Fixed(mesh_material.clone());
+        commands.entity(entity).insert((
+            mesh_material,
+            Highlight::<StandardMaterial> {
+                hovered: Some(highlight_mat_kind.clone()),
+                pressed: Some(highlight_mat_kind.clone()),
+                selected: Some(highlight_mat_kind.clone()),
+            },
+        ));
+    }
+}
+
+/// Apply the meshes generated in the background by their finished tasks.
+pub fn apply_mesh_generation_tasks(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut query_tasks: Query<(
+        Entity,
+        &mut PolygonalMesh,
+        &mut PolygonalMeshGenerationTask,
+        Option<&Handle<Mesh>>,
+    )>,
+) {
+    for (entity, mut polygonal_mesh, mut task, mesh_handle) in query_tasks.iter_mut() {
+        let Some(new_mesh) = block_on(future::poll_once(&mut task.0)) else {
+            continue;
+        };
+        commands
+            .entity(entity)
+            .remove::<(PolygonalMeshGenerationTask, PolygonalMeshGenerationPending)>();
+        let Some(new_mesh) = new_mesh else {
+            error!("Could not extrude mesh!");
+            continue;
+        };
+        // bypass to not trigger the change detection (regeneration) again
+        let polygonal_mesh = polygonal_mesh.bypass_change_detection();
+        assign_polygonal_mesh(polygonal_mesh, new_mesh, &mut meshes);
+        sync_mesh_handle(&mut commands, entity, polygonal_mesh, mesh_handle);
+    }
+}
+
+/// Spawn a task generating the mesh of (a snapshot of) the polygonal mesh.
+fn spawn_mesh_generation_task(
+    polygonal_mesh: &PolygonalMesh,
+    extrude_size: f32,
+) -> Task<Option<Mesh>> {
+    let mut snapshot = PolygonalMesh {
+        mesh_polygon: polygonal_mesh.mesh_polygon.clone(),
+        mesh_handle: None,
+        solid: polygonal_mesh.solid,
+    };
+    AsyncComputeTaskPool::get().spawn(async move { snapshot.generate_mesh(extrude_size) })
+}
+
+/// Insert the (new) mesh handle of the polygonal mesh into its entity.
+fn sync_mesh_handle(
+    commands: &mut Commands,
+    entity: Entity,
+    polygonal_mesh: &PolygonalMesh,
+    mesh_handle: Option<&Handle<Mesh>>,
+) {
+    if let Some(new_mesh_handle) = polygonal_mesh.mesh_handle.as_ref() {
+        if mesh_handle != Some(new_mesh_handle) {
+            commands.entity(entity).insert(new_mesh_handle.clone());
+        }
+    }
+}
+
+/// Check if the (proxy) indicators of the picked [`IndicatorMode::Gizmos`] vertices
+/// and curve handles still match the polygon.
+#[allow(clippy::type_complexity)]
+fn are_gizmo_indicator_proxies_in_sync(
+    mesh_polygon: &MeshPolygon,
+    indicators: &PolygonalMeshIndicators,
+    query_vertex_indicators: &Query<(&mut Transform, &VertexIndicator)>,
+    query_tangent_handles: &Query<
+        (&mut Transform, &TangentHandleIndicator),
+        (Without<VertexIndicator>, Without<EdgeIndicator>),
+    >,
+) -> bool {
+    let are_vertices_in_sync = indicators.vertices.iter().all(|indicator| {
+        query_vertex_indicators
+            .get(*indicator)
+            .is_ok_and(|(_, VertexIndicator(vertex_id))| {
+                mesh_polygon.vertices.get(*vertex_id).is_some()
+            })
+    });
+    let are_handles_in_sync = indicators.tangent_handles.iter().all(|indicator| {
+        query_tangent_handles.get(*indicator).is_ok_and(
+            |(_, TangentHandleIndicator { edge, index })| {
+                let (Some(curve), Some(from_vert), Some(to_vert)) = (
+                    mesh_polygon.curves.get(edge),
+                    mesh_polygon.vertices.get(edge.from),
+                    mesh_polygon.vertices.get(edge.to),
+                ) else {
+                    return false;
+                };
+                *index < curve.handles(*from_vert, *to_vert).len()
+            },
+        )
+    });
+    indicators.edges.is_empty() && are_vertices_in_sync && are_handles_in_sync
+}
+
+/// Check if the indicators match the vertices, edges and curve handles of the polygon.
+#[allow(clippy::type_complexity)]
+fn are_indicators_in_sync(
+    mesh_polygon: &MeshPolygon,
+    indicators: &PolygonalMeshIndicators,
+    query_vertex_indicators: &Query<(&mut Transform, &VertexIndicator)>,
+    query_edge_indicators: &Query<(&mut Transform, &EdgeIndicator), Without<VertexIndicator>>,
+    query_tangent_handles: &Query<
+        (&mut Transform, &TangentHandleIndicator),
+        (Without<VertexIndicator>, Without<EdgeIndicator>),
+    >,
+) -> bool {
+    let handle_count: usize = mesh_polygon
+        .curves
+        .0
+        .iter()
+        .filter_map(|(edge, curve)| {
+            let from_vert = mesh_polygon.vertices.get(edge.from)?;
+            let to_vert = mesh_polygon.vertices.get(edge.to)?;
+            Some(curve.handles(*from_vert, *to_vert).len())
+        })
+        .sum();
+    if indicators.vertices.len() != mesh_polygon.vertices.len()
+        || indicators.edges.len() != mesh_polygon.edges.len()
+        || indicators.tangent_handles.len() != handle_count
+    {
+        return false;
+    }
+    let are_vertices_in_sync = indicators.vertices.iter().all(|indicator| {
+        query_vertex_indicators
+            .get(*indicator)
+            .is_ok_and(|(_, VertexIndicator(vertex_id))| {
+                mesh_polygon.vertices.get(*vertex_id).is_some()
+            })
+    });
+    let are_edges_in_sync = indicators.edges.iter().all(|indicator| {
+        query_edge_indicators
+            .get(*indicator)
+            .is_ok_and(|(_, EdgeIndicator(edge))| mesh_polygon.edges.contains(edge))
+    });
+    let are_handles_in_sync = indicators.tangent_handles.iter().all(|indicator| {
+        query_tangent_handles.get(*indicator).is_ok_and(
+            |(_, TangentHandleIndicator { edge, .. })| mesh_polygon.curves.contains_key(edge),
+        )
+    });
+    are_vertices_in_sync && are_edges_in_sync && are_handles_in_sync
+}
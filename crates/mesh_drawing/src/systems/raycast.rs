@@ -1,12 +1,15 @@
-use bevy::{prelude::*, reflect::TypePath};
+use bevy::{ecs::system::SystemParam, prelude::*, reflect::TypePath, window::PrimaryWindow};
 use bevy_mod_picking::prelude::{Highlight, HighlightKind, PickableBundle};
 use bevy_mod_raycast::prelude::{IntersectionData, RaycastMesh, RaycastMethod, RaycastSource};
 
 use crate::{
-    components::{Canvas, MeshDrawingCamera, VertexIndicator},
+    components::{Canvas, MeshDrawingCamera, TangentHandleIndicator, VertexIndicator},
     events::{create_mode::CreateModeEvent, edit_mode::EditModeEvent},
     prelude::MeshDrawingPluginSettings,
-    utils::canvas_correction::get_canvas_corrected_translation,
+    resources::{drawing::EditModeState, CanvasRaycastMode, DrawingMode, DrawingState},
+    utils::canvas_correction::{
+        get_canvas_corrected_translation, get_canvas_uncorrected_translation,
+    },
 };
 
 /// Unit Struct use to mark the main mesh drawing
@@ -39,11 +42,24 @@ pub fn update_raycast_with_cursor(
     }
 }
 
+/// Make the added canvas pickable and raycast its mesh.
+///
+/// Canvases of [`CanvasRaycastMode::Plane`] (with or without a mesh) are left
+/// as is, their plane is intersected instead and clicks are handled by
+/// `handle_canvas_plane_clicks`.
 pub fn enable_raycast_on_canvas_add(
     mut commands: Commands,
-    query: Query<(Entity, &Handle<StandardMaterial>), Added<Canvas>>,
+    settings: Res<MeshDrawingPluginSettings>,
+    query: Query<(Entity, Option<&Handle<StandardMaterial>>), Added<Canvas>>,
 ) {
+    if settings.canvas_raycast_mode != CanvasRaycastMode::Mesh {
+        return;
+    }
     for (entity, material) in query.iter() {
+        let Some(material) = material else {
+            warn!("Canvas {:?} has no mesh material to raycast", entity);
+            continue;
+        };
         let highlight_mat_kind = HighlightKind::<StandardMaterial>::Fixed(material.clone());
         commands.entity(entity).insert((
             PickableBundle::default(),
@@ -71,9 +87,10 @@ pub fn disable_raycast_on_canvas_remove(
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub fn enable_raycast_on_vertex_indicators_add(
     mut commands: Commands,
-    query: Query<Entity, Added<VertexIndicator>>,
+    query: Query<Entity, Or<(Added<VertexIndicator>, Added<TangentHandleIndicator>)>>,
 ) {
     for entity in query.iter() {
         commands
@@ -111,19 +128,41 @@ pub fn disable_raycast_on_camera_remove(
 /// Dispatch `CreateModeEvent` on user interactions along with intersections data.
 #[allow(clippy::too_many_arguments)]
 pub fn handle_raycast_intersections(
-    query_intersections: Query<&RaycastSource<MeshDrawingRaycastSet>>,
+    canvas_raycast: CanvasRaycast,
     settings: Res<MeshDrawingPluginSettings>,
     mut create_mode_event: EventWriter<CreateModeEvent>,
     mouse_btn_input: Res<ButtonInput<MouseButton>>,
+    drawing_state: Res<DrawingState>,
 ) {
     let MeshDrawingPluginSettings { input_binds, .. } = *settings;
-    if mouse_btn_input.just_pressed(input_binds.create_mode_add_vertex_btn) {
+    if settings.create_mode_tool.is_shape() {
+        let is_dragging_shape = matches!(
+            &drawing_state.mode,
+            DrawingMode::CreateMode(create_mode_state) if create_mode_state.shape_draft.is_some()
+        );
+        let cursor_position = canvas_raycast.cursor_position();
+        if mouse_btn_input.just_pressed(input_binds.create_mode_add_vertex_btn) {
+            // Start/complete shape...
+            let Some(cursor_position) = cursor_position else {
+                return;
+            };
+            create_mode_event.send(CreateModeEvent::ShapePointerDown(cursor_position));
+        } else if mouse_btn_input.just_released(input_binds.create_mode_add_vertex_btn) {
+            if is_dragging_shape {
+                create_mode_event.send(CreateModeEvent::ShapePointerUp);
+            }
+        } else if mouse_btn_input.just_pressed(input_binds.create_mode_close_and_extrude_mesh_btn) {
+            create_mode_event.send(CreateModeEvent::PolygonCloseAndIntoMeshExtrude);
+        } else if is_dragging_shape {
+            if let Some(cursor_position) = cursor_position {
+                create_mode_event.send(CreateModeEvent::ShapePointerMove(cursor_position));
+            }
+        }
+    } else if mouse_btn_input.just_pressed(input_binds.create_mode_add_vertex_btn) {
         // Add new vertex...
-        let Some((_, intersection)) = get_first_intersection_data_for_source(&query_intersections)
-        else {
+        let Some(intersection_point) = canvas_raycast.cursor_position() else {
             return;
         };
-        let intersection_point = intersection.position();
         info!("intersection_point: {:?}", intersection_point);
         create_mode_event.send(CreateModeEvent::VertexAdd(intersection_point));
     } else if mouse_btn_input.just_pressed(input_binds.create_mode_close_and_extrude_mesh_btn) {
@@ -157,6 +196,66 @@ pub fn get_multi_intersection_data_for_source<T: TypePath>(
     intersections
 }
 
+/// Cursor position on the canvas, located as set by [`CanvasRaycastMode`].
+#[derive(SystemParam)]
+pub struct CanvasRaycast<'w, 's> {
+    settings: Res<'w, MeshDrawingPluginSettings>,
+    query_intersections: Query<'w, 's, &'static RaycastSource<MeshDrawingRaycastSet>>,
+    query_window: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    query_camera:
+        Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<MeshDrawingCamera>>,
+    query_canvas: Query<'w, 's, &'static GlobalTransform, With<Canvas>>,
+}
+
+impl<'w, 's> CanvasRaycast<'w, 's> {
+    /// World position of the cursor on the canvas, `None` if the cursor misses it.
+    pub fn cursor_position(&self) -> Option<Vec3> {
+        match self.settings.canvas_raycast_mode {
+            CanvasRaycastMode::Mesh => {
+                get_first_intersection_data_for_source(&self.query_intersections)
+                    .map(|(_, intersection)| intersection.position())
+            }
+            CanvasRaycastMode::Plane { bounds } => {
+                let cursor_position = self.query_window.get_single().ok()?.cursor_position()?;
+                let (camera, camera_transform) = self.query_camera.get_single().ok()?;
+                let ray = camera.viewport_to_world(camera_transform, cursor_position)?;
+                let canvas_transform = self.query_canvas.get_single().ok()?;
+                intersect_canvas_plane(ray, canvas_transform, bounds)
+            }
+        }
+    }
+}
+
+/// Intersection (world position) of the ray with the canvas plane (canvas local `y = 0`).
+///
+/// Only hits within the bounds (canvas local `x`, `z`) if any. Intersected in
+/// canvas local space, so scaled and nested canvases are handled.
+pub fn intersect_canvas_plane(
+    ray: Ray3d,
+    canvas_transform: &GlobalTransform,
+    bounds: Option<Rect>,
+) -> Option<Vec3> {
+    let inverse = canvas_transform.affine().inverse();
+    let origin = inverse.transform_point3(ray.origin);
+    let direction = inverse.transform_vector3(*ray.direction);
+    if direction.y.abs() <= f32::EPSILON {
+        // parallel to the canvas
+        return None;
+    }
+    let distance = -origin.y / direction.y;
+    if distance < 0. {
+        return None;
+    }
+    let local_position = origin + direction * distance;
+    if bounds.is_some_and(|bounds| !bounds.contains(local_position.xz())) {
+        return None;
+    }
+    Some(get_canvas_uncorrected_translation(
+        local_position,
+        canvas_transform,
+    ))
+}
+
 /// Handle raycast intersections for vertex grabbing.
 ///
 /// Dispatch `EditModeEvent` on user interactions along with intersections data.
@@ -169,8 +268,17 @@ pub fn handle_vertex_grabbing_raycast_intersections(
         (Entity, &Transform),
         (With<RaycastMesh<VertexGrabbingRaycastSet>>, Without<Canvas>),
     >,
-    query_canvas: Query<&Transform, With<Canvas>>,
+    query_canvas: Query<&GlobalTransform, With<Canvas>>,
+    drawing_state: Res<DrawingState>,
 ) {
+    if let DrawingMode::EditMode(EditModeState {
+        knife_path: Some(_),
+        ..
+    }) = drawing_state.mode
+    {
+        // vertices can't be grabbed while using the knife
+        return;
+    }
     let Ok(canvas_transform) = query_canvas.get_single() else {
         return;
     };
@@ -201,3 +309,71 @@ pub fn handle_vertex_grabbing_raycast_intersections(
         edit_mode_event.send(EditModeEvent::VertexIndicatorJustReleased);
     }
 }
+
+#[test]
+fn test_intersect_canvas_plane() {
+    let canvas_transform = GlobalTransform::from(Transform::from_xyz(1., 2., 3.));
+    let ray = Ray3d::new(Vec3::new(2., 7., 1.), Vec3::NEG_Y);
+    let position = intersect_canvas_plane(ray, &canvas_transform, None).unwrap();
+    assert!(position.abs_diff_eq(Vec3::new(2., 2., 1.), 1e-5));
+    // hit in canvas local space
+    let local_position = get_canvas_corrected_translation(position, &canvas_transform);
+    assert!(local_position.abs_diff_eq(Vec3::new(1., 0., -2.), 1e-5));
+    // oblique ray
+    let ray = Ray3d::new(Vec3::new(1., 4., 3.), Vec3::new(1., -1., 0.));
+    let position = intersect_canvas_plane(ray, &canvas_transform, None).unwrap();
+    assert!(position.abs_diff_eq(Vec3::new(3., 2., 3.), 1e-5));
+}
+
+#[test]
+fn test_intersect_canvas_plane_misses() {
+    let canvas_transform = GlobalTransform::from(Transform::from_xyz(0., 1., 0.));
+    // parallel to the canvas
+    let ray = Ray3d::new(Vec3::new(0., 2., 0.), Vec3::X);
+    assert_eq!(intersect_canvas_plane(ray, &canvas_transform, None), None);
+    let ray = Ray3d::new(Vec3::new(0., 1., 0.), Vec3::new(1., 0., 1.));
+    assert_eq!(intersect_canvas_plane(ray, &canvas_transform, None), None);
+    // canvas behind the ray origin
+    let ray = Ray3d::new(Vec3::new(0., 2., 0.), Vec3::Y);
+    assert_eq!(intersect_canvas_plane(ray, &canvas_transform, None), None);
+    let ray = Ray3d::new(Vec3::new(0., -2., 0.), Vec3::NEG_Y);
+    assert_eq!(intersect_canvas_plane(ray, &canvas_transform, None), None);
+}
+
+#[test]
+fn test_intersect_canvas_plane_bounds() {
+    let canvas_transform = GlobalTransform::IDENTITY;
+    let bounds = Some(Rect::new(-1., -2., 1., 2.));
+    let ray = Ray3d::new(Vec3::new(0.5, 3., -1.5), Vec3::NEG_Y);
+    let position = intersect_canvas_plane(ray, &canvas_transform, bounds).unwrap();
+    assert!(position.abs_diff_eq(Vec3::new(0.5, 0., -1.5), 1e-5));
+    // outside the bounds (local `x`, `z`)
+    for origin in [Vec3::new(1.5, 3., 0.), Vec3::new(0., 3., 2.5)] {
+        let ray = Ray3d::new(origin, Vec3::NEG_Y);
+        assert_eq!(
+            intersect_canvas_plane(ray, &canvas_transform, bounds),
+            None,
+            "{}",
+            origin
+        );
+    }
+}
+
+#[test]
+fn test_intersect_scaled_rotated_canvas_plane() {
+    // canvas standing upright (local `y` along world `z`), scaled along its `x`
+    let canvas_transform = GlobalTransform::from(
+        Transform::from_xyz(0., 0., -5.)
+            .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2))
+            .with_scale(Vec3::new(4., 1., 1.)),
+    );
+    let bounds = Some(Rect::new(-1., -1., 1., 1.));
+    let ray = Ray3d::new(Vec3::new(2., 0.5, 10.), Vec3::NEG_Z);
+    let position = intersect_canvas_plane(ray, &canvas_transform, bounds).unwrap();
+    assert!(position.abs_diff_eq(Vec3::new(2., 0.5, -5.), 1e-4));
+    // bounds are in (scaled) canvas local units
+    let local_position = get_canvas_corrected_translation(position, &canvas_transform);
+    assert!(local_position.abs_diff_eq(Vec3::new(0.5, 0., -0.5), 1e-4));
+    let ray = Ray3d::new(Vec3::new(5., 0.5, 10.), Vec3::NEG_Z);
+    assert_eq!(intersect_canvas_plane(ray, &canvas_transform, bounds), None);
+}
@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+use mesh_geometry_utils::operations::symmetry::Symmetry;
+
+use crate::{
+    components::{Canvas, PolygonalMesh, SymmetryLink},
+    resources::{
+        drawing::EditModeState, DrawingMode, DrawingState, MeshDrawingPluginSettings,
+        MeshDrawingTheme,
+    },
+    utils::canvas_correction::canvas_to_world,
+};
+
+/// Half length of the mirror axis/length of the radial sector guides.
+const SYMMETRY_GUIDE_EXTENT: f32 = 50.;
+
+/// Handle symmetry toggle input.
+pub fn handle_symmetry_toggle(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<MeshDrawingPluginSettings>,
+) {
+    if settings.is_symmetry_enabled
+        && keyboard_input.just_pressed(settings.input_binds.symmetry_toggle_key)
+    {
+        settings.symmetry.is_active = !settings.symmetry.is_active;
+        info!("Symmetry active: {}", settings.symmetry.is_active);
+    }
+}
+
+/// Draw the active symmetry guides on the canvas.
+///
+/// Draws the mirror axis (radial sector boundaries) and in create mode, the
+/// images of the polygon being created.
+pub fn draw_symmetry_guides(
+    mut gizmos: Gizmos,
+    settings: Res<MeshDrawingPluginSettings>,
+    theme: Res<MeshDrawingTheme>,
+    drawing_state: Res<DrawingState>,
+    query_canvas: Query<&GlobalTransform, With<Canvas>>,
+) {
+    let Some(symmetry_settings) = settings.active_symmetry() else {
+        return;
+    };
+    let Ok(canvas_transform) = query_canvas.get_single() else {
+        return;
+    };
+    let to_world = |point: Vec2| canvas_to_world(point, canvas_transform);
+    let symmetry = symmetry_settings.symmetry;
+    match symmetry {
+        Symmetry::Mirror { origin, direction } => {
+            let direction = direction.normalize_or_zero() * SYMMETRY_GUIDE_EXTENT;
+            gizmos.line(
+                to_world(origin - direction),
+                to_world(origin + direction),
+                theme.symmetry_guide_color,
+            );
+        }
+        Symmetry::Radial { center, .. } => {
+            // sector boundaries, starting along the canvas `x` axis
+            for index in 0..symmetry.image_count() {
+                let end = symmetry.apply(center + Vec2::X * SYMMETRY_GUIDE_EXTENT, index);
+                gizmos.line(to_world(center), to_world(end), theme.symmetry_guide_color);
+            }
+        }
+    }
+    let DrawingMode::CreateMode(create_mode_state) = &drawing_state.mode else {
+        return;
+    };
+    let path = create_mode_state.mesh_polygon.vertices.get_all_owned();
+    if path.len() < 2 {
+        return;
+    }
+    for index in 1..symmetry.image_count() {
+        gizmos.linestrip(
+            path.iter()
+                .map(|point| to_world(symmetry.apply(*point, index))),
+            theme.symmetry_preview_color,
+        );
+    }
+}
+
+/// Propagate the edits of the active mesh to its linked symmetric copies.
+pub fn sync_symmetry_links(
+    drawing_state: Res<DrawingState>,
+    mut query_links: Query<(Entity, &mut PolygonalMesh, &SymmetryLink)>,
+) {
+    let DrawingMode::EditMode(EditModeState {
+        active_mesh: Some(active_mesh),
+        ..
+    }) = drawing_state.mode
+    else {
+        return;
+    };
+    let Ok((_, polygonal_mesh, active_link)) = query_links.get_mut(active_mesh) else {
+        return;
+    };
+    if !polygonal_mesh.is_changed() {
+        return;
+    }
+    // polygon of the identity image...
+    let source_polygon = active_link
+        .symmetry
+        .apply_inverse_to_polygon(&polygonal_mesh.mesh_polygon, active_link.index);
+    let source = active_link.source;
+    for (entity, mut polygonal_mesh, link) in query_links.iter_mut() {
+        if entity == active_mesh || link.source != source {
+            continue;
+        }
+        polygonal_mesh.mesh_polygon = link.symmetry.apply_to_polygon(&source_polygon, link.index);
+    }
+}
@@ -3,9 +3,89 @@ use bevy::prelude::*;
 /// Get corrected value of translation.
 ///
 /// Used to convert translation from global to the local wrt canvas as most
-/// entities are children of the canvas.
-pub fn get_canvas_corrected_translation(translation: Vec3, canvas_transform: &Transform) -> Vec3 {
-    let inverted_canvas_rot = canvas_transform.rotation.inverse();
-    let adjusted_translation = translation - canvas_transform.translation;
-    inverted_canvas_rot * adjusted_translation
+/// entities are children of the canvas. Uses the inverse of the canvas
+/// [`GlobalTransform`], so scaled canvases and canvases nested under other
+/// entities are handled.
+pub fn get_canvas_corrected_translation(
+    translation: Vec3,
+    canvas_transform: &GlobalTransform,
+) -> Vec3 {
+    canvas_transform
+        .affine()
+        .inverse()
+        .transform_point3(translation)
+}
+
+/// Get global value of translation local wrt canvas.
+///
+/// Inverse of [`get_canvas_corrected_translation`].
+pub fn get_canvas_uncorrected_translation(
+    translation: Vec3,
+    canvas_transform: &GlobalTransform,
+) -> Vec3 {
+    canvas_transform.transform_point(translation)
+}
+
+/// Convert the world position to a canvas point (canvas local `x`, `z`), like
+/// the vertices of the polygons drawn on the canvas.
+///
+/// Positions off the canvas plane are projected onto it along the canvas local `y`.
+pub fn world_to_canvas(translation: Vec3, canvas_transform: &GlobalTransform) -> Vec2 {
+    get_canvas_corrected_translation(translation, canvas_transform).xz()
+}
+
+/// Convert the canvas point (canvas local `x`, `z`) to a world position on the canvas.
+///
+/// Inverse of [`world_to_canvas`].
+pub fn canvas_to_world(point: Vec2, canvas_transform: &GlobalTransform) -> Vec3 {
+    get_canvas_uncorrected_translation(Vec3::new(point.x, 0., point.y), canvas_transform)
+}
+
+#[cfg(test)]
+fn scaled_rotated_nested_canvas_transform() -> (Transform, Transform, GlobalTransform) {
+    let parent = Transform::from_xyz(3., 1., -2.)
+        .with_rotation(Quat::from_rotation_y(0.6))
+        .with_scale(Vec3::splat(2.));
+    let canvas = Transform::from_xyz(-1., 0.5, 4.)
+        .with_rotation(Quat::from_rotation_x(0.3) * Quat::from_rotation_y(-1.1))
+        .with_scale(Vec3::new(0.5, 1., 3.));
+    (parent, canvas, GlobalTransform::from(parent) * canvas)
+}
+
+#[test]
+fn test_canvas_to_world_round_trip() {
+    let (parent, canvas, canvas_transform) = scaled_rotated_nested_canvas_transform();
+    for point in [Vec2::ZERO, Vec2::new(1.5, -2.), Vec2::new(-3., 0.25)] {
+        let position = canvas_to_world(point, &canvas_transform);
+        // canvas point is transformed by the canvas then its parent
+        let expected =
+            parent.transform_point(canvas.transform_point(Vec3::new(point.x, 0., point.y)));
+        assert!(
+            position.abs_diff_eq(expected, 1e-4),
+            "{} != {}",
+            position,
+            expected
+        );
+        let round_trip = world_to_canvas(position, &canvas_transform);
+        assert!(
+            round_trip.abs_diff_eq(point, 1e-4),
+            "{} != {}",
+            round_trip,
+            point
+        );
+    }
+}
+
+#[test]
+fn test_world_to_canvas_projects_along_canvas_y() {
+    let (_, _, canvas_transform) = scaled_rotated_nested_canvas_transform();
+    let point = Vec2::new(2., -1.);
+    let canvas_y = canvas_transform.affine().transform_vector3(Vec3::Y);
+    let position = canvas_to_world(point, &canvas_transform) + canvas_y * 1.7;
+    assert!(world_to_canvas(position, &canvas_transform).abs_diff_eq(point, 1e-4));
+    // corrected translation keeps the offset off the canvas plane
+    let translation = get_canvas_corrected_translation(position, &canvas_transform);
+    assert!(translation.abs_diff_eq(Vec3::new(2., 1.7, -1.), 1e-4));
+    let uncorrected = get_canvas_uncorrected_translation(translation, &canvas_transform);
+    assert!(uncorrected.abs_diff_eq(position, 1e-4));
 }
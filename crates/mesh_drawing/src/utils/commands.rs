@@ -0,0 +1,207 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use mesh_geometry_utils::data_structures::{Edge, MeshPolygon, VertexId};
+
+use crate::{
+    components::{PolygonalMesh, PolygonalMeshSolid},
+    events::lifecycle::{GeometryChangeKind, PolygonalMeshCreated, PolygonalMeshGeometryChanged},
+    resources::MeshDrawingPluginSettings,
+    utils::polygonal_mesh::{insert_polygonal_mesh, insert_polygonal_mesh_components},
+};
+
+/// Options of the polygonal meshes spawned with [`PolygonalMeshCommandsExt::spawn_polygonal_mesh`].
+#[derive(Debug, Clone, Copy)]
+pub struct PolygonalMeshOptions {
+    /// Kind of solid generated from the polygon, `None` uses [`MeshDrawingPluginSettings::solid`].
+    pub solid: Option<PolygonalMeshSolid>,
+    /// Reverse counter clockwise polygons, same as the polygons closed in create mode.
+    pub is_winding_corrected: bool,
+}
+
+impl Default for PolygonalMeshOptions {
+    fn default() -> Self {
+        Self {
+            solid: None,
+            is_winding_corrected: true,
+        }
+    }
+}
+
+/// [`Commands`] extension to spawn and edit polygonal meshes from code.
+///
+/// Meshes and indicators (if the mesh is being edited) are kept in sync and
+/// the lifecycle events are sent, same as when drawing with the plugin.
+pub trait PolygonalMeshCommandsExt {
+    /// Spawn the polygon as a pickable polygonal mesh entity, child of the canvas.
+    ///
+    /// Returns the entity, despawned if the polygon is degenerate (less than 3 vertices).
+    /// Meshes of large polygons are generated in the background if
+    /// [`MeshDrawingPluginSettings::is_async_mesh_generation_enabled`].
+    fn spawn_polygonal_mesh(
+        &mut self,
+        mesh_polygon: MeshPolygon,
+        canvas_entity: Entity,
+        options: PolygonalMeshOptions,
+    ) -> Entity;
+
+    /// Move the vertex of the polygonal mesh to the (mesh local `x`, `z`) position.
+    fn move_polygonal_mesh_vertex(&mut self, entity: Entity, vertex_id: VertexId, position: Vec2);
+
+    /// Insert a new vertex on the edge of the polygonal mesh at the (mesh local `x`, `z`) position.
+    ///
+    /// Id of the new vertex is sent with [`PolygonalMeshGeometryChanged`].
+    fn insert_polygonal_mesh_vertex(&mut self, entity: Entity, edge: Edge, position: Vec2);
+
+    /// Remove the vertex of the polygonal mesh, polygons keep at least 3 vertices.
+    fn remove_polygonal_mesh_vertex(&mut self, entity: Entity, vertex_id: VertexId);
+}
+
+impl PolygonalMeshCommandsExt for Commands<'_, '_> {
+    fn spawn_polygonal_mesh(
+        &mut self,
+        mesh_polygon: MeshPolygon,
+        canvas_entity: Entity,
+        options: PolygonalMeshOptions,
+    ) -> Entity {
+        let entity = self.spawn_empty().id();
+        self.add(move |world: &mut World| {
+            world.run_system_once_with(
+                (entity, mesh_polygon, canvas_entity, options),
+                spawn_polygonal_mesh_command,
+            );
+        });
+        entity
+    }
+
+    fn move_polygonal_mesh_vertex(&mut self, entity: Entity, vertex_id: VertexId, position: Vec2) {
+        self.add(move |world: &mut World| {
+            world.run_system_once_with(
+                (entity, VertexEdit::Move(vertex_id, position)),
+                edit_polygonal_mesh_vertex_command,
+            );
+        });
+    }
+
+    fn insert_polygonal_mesh_vertex(&mut self, entity: Entity, edge: Edge, position: Vec2) {
+        self.add(move |world: &mut World| {
+            world.run_system_once_with(
+                (entity, VertexEdit::Insert(edge, position)),
+                edit_polygonal_mesh_vertex_command,
+            );
+        });
+    }
+
+    fn remove_polygonal_mesh_vertex(&mut self, entity: Entity, vertex_id: VertexId) {
+        self.add(move |world: &mut World| {
+            world.run_system_once_with(
+                (entity, VertexEdit::Remove(vertex_id)),
+                edit_polygonal_mesh_vertex_command,
+            );
+        });
+    }
+}
+
+/// Vertex edit applied by the vertex commands.
+#[derive(Debug, Clone)]
+enum VertexEdit {
+    Move(VertexId, Vec2),
+    Insert(Edge, Vec2),
+    Remove(VertexId),
+}
+
+/// Spawn the polygonal mesh into the reserved entity.
+fn spawn_polygonal_mesh_command(
+    In((entity, mut mesh_polygon, canvas_entity, options)): In<(
+        Entity,
+        MeshPolygon,
+        Entity,
+        PolygonalMeshOptions,
+    )>,
+    mut commands: Commands,
+    settings: Res<MeshDrawingPluginSettings>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut created_events: EventWriter<PolygonalMeshCreated>,
+) {
+    if options.is_winding_corrected && mesh_polygon.vertices.is_order_ccw() {
+        // same as create mode, refer `close_polygon_and_extrude_mesh`
+        mesh_polygon.reverse();
+    }
+    let polygonal_mesh = PolygonalMesh {
+        mesh_polygon,
+        mesh_handle: None,
+        solid: options.solid.unwrap_or(settings.solid),
+    };
+    if settings.is_async_mesh_generation_enabled
+        && polygonal_mesh.mesh_polygon.vertices.len()
+            >= settings.async_mesh_generation_min_vertices.max(3)
+    {
+        // large polygon, generated in the background by `regenerate_changed_polygonal_meshes`,
+        // its mesh handle is inserted by `apply_mesh_generation_tasks`
+        insert_polygonal_mesh_components(
+            entity,
+            polygonal_mesh,
+            None,
+            &mut commands,
+            canvas_entity,
+        );
+        created_events.send(PolygonalMeshCreated { entity });
+        return;
+    }
+    match insert_polygonal_mesh(
+        entity,
+        polygonal_mesh,
+        settings.extrude_size,
+        &mut meshes,
+        &mut commands,
+        canvas_entity,
+    ) {
+        Ok(()) => {
+            created_events.send(PolygonalMeshCreated { entity });
+        }
+        Err(error) => {
+            error!("Could not spawn polygonal mesh: {:?}", error);
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Apply the vertex edit to the polygonal mesh.
+///
+/// Mesh and indicators are regenerated by `regenerate_changed_polygonal_meshes`.
+fn edit_polygonal_mesh_vertex_command(
+    In((entity, edit)): In<(Entity, VertexEdit)>,
+    mut query_meshes: Query<&mut PolygonalMesh>,
+    mut geometry_changed_events: EventWriter<PolygonalMeshGeometryChanged>,
+) {
+    let Ok(mut polygonal_mesh) = query_meshes.get_mut(entity) else {
+        warn!("Polygonal mesh {:?} not found!", entity);
+        return;
+    };
+    let mesh_polygon = &mut polygonal_mesh.mesh_polygon;
+    let change = match edit.clone() {
+        VertexEdit::Move(vertex_id, position) => mesh_polygon
+            .move_vertex(vertex_id, position)
+            .then_some((GeometryChangeKind::VertexMoved, vertex_id)),
+        VertexEdit::Insert(edge, position) => mesh_polygon
+            .insert_vertex_on_edge(position, edge)
+            .map(|vertex_id| (GeometryChangeKind::VertexInserted, vertex_id)),
+        VertexEdit::Remove(vertex_id) => {
+            if mesh_polygon.vertices.len() <= 3 {
+                error!("Cannot remove vertices! vertices are less than or equal to 3!");
+                return;
+            }
+            mesh_polygon
+                .remove_vertex(vertex_id)
+                .0
+                .map(|_| (GeometryChangeKind::VertexRemoved, vertex_id))
+        }
+    };
+    let Some((kind, vertex_id)) = change else {
+        warn!("Could not apply {:?} to polygonal mesh {:?}!", edit, entity);
+        return;
+    };
+    geometry_changed_events.send(PolygonalMeshGeometryChanged {
+        entity,
+        kind,
+        vertices: vec![vertex_id],
+    });
+}
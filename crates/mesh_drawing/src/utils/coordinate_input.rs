@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+
+/// Parse typed coordinates into a canvas local (`x`, `z`) position.
+///
+/// Supported formats:
+/// - `x,z`: absolute position.
+/// - `@dx,dz`: relative to `reference`.
+/// - `@length<angle`: relative to `reference`, `angle` in degrees from the
+///   canvas `x` axis towards the `z` axis.
+///
+/// Non-finite values and positions (eg: overflowing `1e40`) are rejected.
+pub fn parse_coordinate_input(input: &str, reference: Option<Vec2>) -> Result<Vec2, String> {
+    let position = parse_position(input.trim(), reference)?;
+    if !position.is_finite() {
+        return Err(format!("position {} is out of range", position));
+    }
+    Ok(position)
+}
+
+/// Parse the position of any of the formats, see [`parse_coordinate_input`].
+fn parse_position(input: &str, reference: Option<Vec2>) -> Result<Vec2, String> {
+    let Some(relative) = input.strip_prefix('@') else {
+        return parse_pair(input, ',');
+    };
+    let Some(reference) = reference else {
+        return Err("relative input needs a previous position".to_string());
+    };
+    if relative.contains('<') {
+        let polar = parse_pair(relative, '<')?;
+        let (length, angle) = (polar.x, polar.y.to_radians());
+        Ok(reference + Vec2::from_angle(angle) * length)
+    } else {
+        Ok(reference + parse_pair(relative, ',')?)
+    }
+}
+
+/// Parse two numbers separated by `separator`.
+fn parse_pair(input: &str, separator: char) -> Result<Vec2, String> {
+    let Some((first, second)) = input.split_once(separator) else {
+        return Err(format!("expected two values separated by '{}'", separator));
+    };
+    let parse = |value: &str| {
+        let value = value.trim();
+        match value.parse::<f32>() {
+            Ok(number) if number.is_finite() => Ok(number),
+            Ok(_) => Err(format!("number {:?} is out of range", value)),
+            Err(error) => Err(format!("invalid number {:?}: {}", value, error)),
+        }
+    };
+    Ok(Vec2::new(parse(first)?, parse(second)?))
+}
+
+#[test]
+fn test_parse_absolute_coordinate_input() {
+    assert_eq!(
+        parse_coordinate_input("1.5, -2", None),
+        Ok(Vec2::new(1.5, -2.))
+    );
+    assert_eq!(
+        parse_coordinate_input(" 3,4 ", Some(Vec2::new(10., 10.))),
+        Ok(Vec2::new(3., 4.))
+    );
+}
+
+#[test]
+fn test_parse_relative_coordinate_input() {
+    let reference = Some(Vec2::new(1., 1.));
+    assert_eq!(
+        parse_coordinate_input("@2,-1", reference),
+        Ok(Vec2::new(3., 0.))
+    );
+    // angle from the `x` axis towards the `z` axis
+    let position = parse_coordinate_input("@2<0", reference).unwrap();
+    assert!(position.abs_diff_eq(Vec2::new(3., 1.), 1e-5));
+    let position = parse_coordinate_input("@2<90", reference).unwrap();
+    assert!(position.abs_diff_eq(Vec2::new(1., 3.), 1e-5));
+    let position = parse_coordinate_input("@ 1 < 180", reference).unwrap();
+    assert!(position.abs_diff_eq(Vec2::new(0., 1.), 1e-5));
+    // relative input needs a reference
+    assert!(parse_coordinate_input("@2,-1", None).is_err());
+    assert!(parse_coordinate_input("@2<90", None).is_err());
+}
+
+#[test]
+fn test_parse_invalid_coordinate_input() {
+    for input in ["", "1", "1;2", "a,2", "1,2,3", "@", "@1<", "@<90"] {
+        assert!(
+            parse_coordinate_input(input, Some(Vec2::ZERO)).is_err(),
+            "{:?}",
+            input
+        );
+    }
+    // non-finite/overflowing values
+    for input in ["1e40,0", "0,-1e40", "inf,0", "NaN,0", "@1e40<0", "@3e38,0"] {
+        assert!(
+            parse_coordinate_input(input, Some(Vec2::new(3e38, 0.))).is_err(),
+            "{:?}",
+            input
+        );
+    }
+}
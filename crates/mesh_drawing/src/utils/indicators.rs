@@ -1,86 +1,191 @@
 use bevy::prelude::*;
-use bevy_mod_picking::prelude::{Highlight, HighlightKind, PickableBundle};
+use bevy_mod_picking::prelude::PickableBundle;
+use mesh_geometry_utils::data_structures::{Edge, MeshPolygon};
 
-/// Spawns a vertex indicator at the given vertex.
-pub fn spawn_vertex_indicators(
-    vertex: Vec2,
+use crate::{
+    components::{
+        Cleanup, EdgeIndicator, PolygonalMeshIndicators, TangentHandleIndicator, VertexIndicator,
+    },
+    resources::{IndicatorAssets, IndicatorState, IndicatorStyleAssets},
+};
+
+/// Spawns an indicator with the mesh and idle material (or custom scene) of the style.
+fn spawn_indicator(
+    transform: Transform,
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
+    style_assets: &IndicatorStyleAssets,
 ) -> Entity {
-    let material = StandardMaterial {
-        unlit: true,
-        base_color: Color::WHITE,
-        ..default()
-    };
-    let material_hdl = materials.add(material);
-    let highlight_mat_kind = HighlightKind::<StandardMaterial>::Fixed(material_hdl.clone());
+    let mut indicator = commands.spawn((
+        style_assets.mesh.clone(),
+        SpatialBundle::from_transform(transform),
+        PickableBundle::default(),
+        IndicatorState::Idle,
+    ));
+    match &style_assets.scene {
+        // mesh without material isn't drawn, but still picked
+        Some(scene) => {
+            indicator.with_children(|parent| {
+                parent.spawn(SceneBundle {
+                    scene: scene.clone(),
+                    ..default()
+                });
+            });
+        }
+        None => {
+            indicator.insert(style_assets.material(IndicatorState::Idle).clone());
+        }
+    }
+    indicator.id()
+}
+
+/// Spawns an invisible (not pickable) indicator, stand-in for the picked
+/// [`IndicatorMode::Gizmos`](crate::resources::IndicatorMode::Gizmos) indicator.
+///
+/// Insert the vertex/tangent handle indicator component and push it as a child of the mesh.
+pub fn spawn_gizmo_indicator_proxy(position: Vec2, commands: &mut Commands) -> Entity {
     commands
-        .spawn(MaterialMeshBundle {
-            mesh: meshes.add(Sphere::new(0.1).mesh()),
-            material: material_hdl.clone(),
-            transform: Transform::from_translation(Vec3::new(vertex.x, 0., vertex.y)),
-            ..default()
-        })
-        .insert((
-            PickableBundle::default(),
-            Highlight::<StandardMaterial> {
-                hovered: Some(highlight_mat_kind.clone()),
-                pressed: Some(highlight_mat_kind.clone()),
-                selected: Some(highlight_mat_kind.clone()),
-            },
+        .spawn(TransformBundle::from_transform(
+            Transform::from_translation(Vec3::new(position.x, 0., position.y)),
         ))
         .id()
 }
 
-/// Width of the edge indicator.
-pub const EDGE_INDICATOR_WIDTH: f32 = 0.1;
+/// Spawns a vertex indicator at the given vertex.
+pub fn spawn_vertex_indicators(
+    vertex: Vec2,
+    commands: &mut Commands,
+    indicator_assets: &IndicatorAssets,
+) -> Entity {
+    spawn_indicator(
+        Transform::from_translation(Vec3::new(vertex.x, 0., vertex.y)),
+        commands,
+        &indicator_assets.vertex,
+    )
+}
 
 /// Spawns a new edge indicator between the `from` and `to` vertex (`VertexId`).
 pub fn spawn_edge_indicator(
     from_vert: Vec2,
     to_vert: Vec2,
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
+    indicator_assets: &IndicatorAssets,
 ) -> Entity {
+    spawn_indicator(
+        edge_indicator_transform(from_vert, to_vert),
+        commands,
+        &indicator_assets.edge,
+    )
+}
+
+/// Transform of the edge indicator between the `from` and `to` vertex (mesh local).
+///
+/// Edge indicator mesh is unit long, only its length is scaled.
+pub fn edge_indicator_transform(from_vert: Vec2, to_vert: Vec2) -> Transform {
     let mid_pt = (to_vert + from_vert) / 2.;
     let edge_vec = to_vert - from_vert;
     let edge_rot_angle_y = edge_vec.angle_between(Vec2::X);
-    // create material for edge
-    let material = materials.add(StandardMaterial {
-        unlit: true,
-        base_color: Color::WHITE,
-        ..default()
-    });
-    let highlight_mat_kind = HighlightKind::<StandardMaterial>::Fixed(material.clone());
-    // Spawn the edge
+    Transform {
+        translation: Vec3::new(mid_pt.x, 0., mid_pt.y),
+        rotation: Quat::from_rotation_y(edge_rot_angle_y),
+        scale: Vec3::new(edge_vec.length(), 1., 1.),
+    }
+}
+
+/// Spawns tangent handle indicators for the handles of all curved edges of the polygon.
+pub fn spawn_tangent_handle_indicators(
+    mesh_polygon: &MeshPolygon,
+    commands: &mut Commands,
+    indicator_assets: &IndicatorAssets,
+) -> Vec<Entity> {
+    let mut entities = vec![];
+    for (edge, curve) in mesh_polygon.curves.0.iter() {
+        let (Some(from_vert), Some(to_vert)) = (
+            mesh_polygon.vertices.get(edge.from),
+            mesh_polygon.vertices.get(edge.to),
+        ) else {
+            continue;
+        };
+        for (index, handle) in curve.handles(*from_vert, *to_vert).into_iter().enumerate() {
+            let entity = spawn_indicator(
+                Transform::from_translation(Vec3::new(handle.x, 0., handle.y)),
+                commands,
+                &indicator_assets.tangent_handle,
+            );
+            commands.entity(entity).insert(TangentHandleIndicator {
+                edge: edge.clone(),
+                index,
+            });
+            entities.push(entity);
+        }
+    }
+    entities
+}
+
+/// Spawns all the vertex/edge/tangent handle indicators of the polygon.
+///
+/// Indicators are not parented, push them as children of the mesh entity.
+pub fn spawn_polygonal_mesh_indicators(
+    mesh_polygon: &MeshPolygon,
+    commands: &mut Commands,
+    indicator_assets: &IndicatorAssets,
+) -> PolygonalMeshIndicators {
+    let mut indicators = PolygonalMeshIndicators {
+        edges: vec![],
+        vertices: vec![],
+        tangent_handles: vec![],
+    };
+    for (id, vertex) in mesh_polygon.vertices.enumerate() {
+        let entity = spawn_vertex_indicators(*vertex, commands, indicator_assets);
+        commands.entity(entity).insert(VertexIndicator(id));
+        indicators.vertices.push(entity)
+    }
+    // spawn edge indicators
+    for Edge { from, to } in mesh_polygon.edges.iter().cloned() {
+        if let (Some(from_vert), Some(to_vert)) = (
+            mesh_polygon.vertices.get(from),
+            mesh_polygon.vertices.get(to),
+        ) {
+            let entity = spawn_edge_indicator(*from_vert, *to_vert, commands, indicator_assets);
+            commands
+                .entity(entity)
+                .insert(EdgeIndicator(Edge { from, to }));
+            indicators.edges.push(entity);
+        }
+    }
+    // spawn tangent handle indicators of curved edges
+    indicators.tangent_handles =
+        spawn_tangent_handle_indicators(mesh_polygon, commands, indicator_assets);
+    indicators
+}
+
+/// Mark all the indicators of the mesh for cleanup and clear the lists.
+pub fn despawn_polygonal_mesh_indicators(
+    commands: &mut Commands,
+    indicators: &mut PolygonalMeshIndicators,
+) {
+    for entity in indicators
+        .vertices
+        .drain(..)
+        .chain(indicators.edges.drain(..))
+        .chain(indicators.tangent_handles.drain(..))
+    {
+        commands.entity(entity).insert(Cleanup::Recursive);
+    }
+}
+
+/// Replace all the indicators of the mesh with fresh ones for its current polygon.
+pub fn respawn_polygonal_mesh_indicators(
+    commands: &mut Commands,
+    indicator_assets: &IndicatorAssets,
+    mesh_entity: Entity,
+    mesh_polygon: &MeshPolygon,
+    indicators: &mut PolygonalMeshIndicators,
+) {
+    despawn_polygonal_mesh_indicators(commands, indicators);
+    *indicators = spawn_polygonal_mesh_indicators(mesh_polygon, commands, indicator_assets);
     commands
-        .spawn(MaterialMeshBundle {
-            mesh: meshes.add(Cuboid::default().mesh()),
-            material: materials.add(StandardMaterial {
-                unlit: true,
-                base_color: Color::WHITE,
-                ..default()
-            }),
-            transform: Transform {
-                translation: Vec3::new(mid_pt.x, 0., mid_pt.y),
-                rotation: Quat::from_rotation_y(edge_rot_angle_y),
-                scale: Vec3::new(
-                    edge_vec.length(),
-                    EDGE_INDICATOR_WIDTH,
-                    EDGE_INDICATOR_WIDTH,
-                ),
-            },
-            ..default()
-        })
-        .insert((
-            PickableBundle::default(),
-            Highlight::<StandardMaterial> {
-                hovered: Some(highlight_mat_kind.clone()),
-                pressed: Some(highlight_mat_kind.clone()),
-                selected: Some(highlight_mat_kind.clone()),
-            },
-        ))
-        .id()
+        .entity(mesh_entity)
+        .push_children(&indicators.vertices)
+        .push_children(&indicators.edges)
+        .push_children(&indicators.tangent_handles);
 }
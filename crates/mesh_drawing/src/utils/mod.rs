@@ -1,2 +1,6 @@
 pub mod canvas_correction;
+pub mod commands;
+pub mod coordinate_input;
 pub mod indicators;
+pub mod polygonal_mesh;
+pub mod spatial_query;
@@ -0,0 +1,161 @@
+use bevy::prelude::*;
+use bevy_mod_picking::prelude::PickableBundle;
+
+use crate::components::{Cleanup, PolygonalMesh};
+
+/// Spawn the [`PolygonalMesh`] as a pickable mesh entity, child of the canvas.
+///
+/// Generates the mesh from its polygon and sets its `mesh_handle`.
+///
+/// Returns the spawned entity.
+pub fn spawn_polygonal_mesh(
+    polygonal_mesh: PolygonalMesh,
+    extrude_size: f32,
+    meshes: &mut Assets<Mesh>,
+    commands: &mut Commands,
+    canvas_entity: Entity,
+) -> Result<Entity, String> {
+    let new_mesh_entity = commands.spawn_empty().id();
+    if let Err(error) = insert_polygonal_mesh(
+        new_mesh_entity,
+        polygonal_mesh,
+        extrude_size,
+        meshes,
+        commands,
+        canvas_entity,
+    ) {
+        commands.entity(new_mesh_entity).despawn();
+        return Err(error);
+    }
+    Ok(new_mesh_entity)
+}
+
+/// Insert the [`PolygonalMesh`] along with its mesh, material and picking
+/// components into the (empty) entity and add it as a child of the canvas.
+///
+/// Generates the mesh from its polygon and sets its `mesh_handle`.
+pub fn insert_polygonal_mesh(
+    entity: Entity,
+    mut polygonal_mesh: PolygonalMesh,
+    extrude_size: f32,
+    meshes: &mut Assets<Mesh>,
+    commands: &mut Commands,
+    canvas_entity: Entity,
+) -> Result<(), String> {
+    // Create mesh from vertices
+    let generated_mesh = polygonal_mesh
+        .generate_mesh(extrude_size)
+        .ok_or_else(|| "Vertices are less than 3!".to_string())?;
+    // create comp for mesh spawning
+    let mesh_handle = meshes.add(generated_mesh);
+    polygonal_mesh.mesh_handle = Some(mesh_handle.clone());
+    insert_polygonal_mesh_components(
+        entity,
+        polygonal_mesh,
+        Some(mesh_handle),
+        commands,
+        canvas_entity,
+    );
+    Ok(())
+}
+
+/// Insert the [`PolygonalMesh`] along with the mesh handle and picking
+/// components into the (empty) entity and add it as a child of the canvas.
+///
+/// Doesn't generate the mesh, use [`insert_polygonal_mesh`] for that. Without a
+/// `mesh_handle` (generated in the background), it's inserted once the mesh is
+/// generated. Material of the [`MeshDrawingTheme`](crate::resources::MeshDrawingTheme)
+/// is added by `insert_polygonal_mesh_materials`.
+pub fn insert_polygonal_mesh_components(
+    entity: Entity,
+    polygonal_mesh: PolygonalMesh,
+    mesh_handle: Option<Handle<Mesh>>,
+    commands: &mut Commands,
+    canvas_entity: Entity,
+) {
+    let mut entity_commands = commands.entity(entity);
+    if let Some(mesh_handle) = mesh_handle {
+        entity_commands.insert(mesh_handle);
+    }
+    entity_commands
+        .insert(SpatialBundle::default())
+        .insert(polygonal_mesh)
+        .insert(PickableBundle::default());
+    // add new mesh as child of canvas
+    commands.entity(canvas_entity).add_child(entity);
+}
+
+/// Mark the [`PolygonalMesh`] entity (along with its children) for cleanup.
+///
+/// Its mesh and material assets are freed once their (strong) handles are dropped,
+/// materials shared with other entities are kept.
+///
+/// Indicators (if any) need to be removed from the edit mode state separately.
+pub fn despawn_polygonal_mesh(entity: Entity, commands: &mut Commands) {
+    commands.entity(entity).insert(Cleanup::Recursive);
+}
+
+/// Regenerate mesh of the polygonal mesh and assign it to existing mesh handle.
+///
+/// Existing meshes are updated in place (eg: moved vertices) if their triangulation
+/// is still valid, else generated again. Creates the mesh handle if it's `None`
+/// (or its mesh was removed).
+pub fn regenerate_polygonal_mesh(
+    polygonal_mesh: &mut PolygonalMesh,
+    extrude_size: f32,
+    meshes: &mut Assets<Mesh>,
+) {
+    if update_polygonal_mesh_in_place(polygonal_mesh, extrude_size, meshes) {
+        return;
+    }
+    let Some(new_mesh) = polygonal_mesh.generate_mesh(extrude_size) else {
+        error!("Could not extrude mesh!");
+        return;
+    };
+    assign_polygonal_mesh(polygonal_mesh, new_mesh, meshes);
+}
+
+/// Update the existing mesh of the polygonal mesh in place, keeping its triangulation.
+///
+/// Returns `false` if the mesh needs to be generated again (or doesn't exist).
+pub fn update_polygonal_mesh_in_place(
+    polygonal_mesh: &mut PolygonalMesh,
+    extrude_size: f32,
+    meshes: &mut Assets<Mesh>,
+) -> bool {
+    let Some(mesh_handle) = polygonal_mesh.mesh_handle.clone() else {
+        return false;
+    };
+    let Some(mesh) = meshes.get_mut(&mesh_handle) else {
+        return false;
+    };
+    let is_updated = polygonal_mesh.update_mesh(mesh, extrude_size);
+    if is_updated {
+        debug!("Updated mesh in place...");
+    }
+    is_updated
+}
+
+/// Assign the generated mesh to the existing mesh handle of the polygonal mesh.
+///
+/// Creates the mesh handle if it's `None` (or its mesh was removed).
+pub fn assign_polygonal_mesh(
+    polygonal_mesh: &mut PolygonalMesh,
+    new_mesh: Mesh,
+    meshes: &mut Assets<Mesh>,
+) {
+    match polygonal_mesh
+        .mesh_handle
+        .as_ref()
+        .and_then(|mesh_handle| meshes.get_mut(mesh_handle))
+    {
+        Some(mesh) => {
+            debug!("Generating new mesh...");
+            *mesh = new_mesh;
+        }
+        None => {
+            debug!("Generating new mesh handle...");
+            polygonal_mesh.mesh_handle = Some(meshes.add(new_mesh));
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+use mesh_geometry_utils::{
+    data_structures::{Edge, MeshPolygon},
+    operations::query::{EdgeHit, PointContainment},
+};
+
+use crate::{
+    components::PolygonalMesh,
+    utils::canvas_correction::{canvas_to_world, world_to_canvas},
+};
+
+/// Nearest boundary point of a [`PolygonalMesh`], in world coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonalMeshBoundaryPoint {
+    /// Entity of the polygonal mesh.
+    pub entity: Entity,
+    /// Edge the point lies on.
+    pub edge: Edge,
+    /// World position of the point.
+    pub point: Vec3,
+    /// Parameter (0..=1) of the point along the edge.
+    pub t: f32,
+    /// World distance from the queried point (projected onto the polygon plane).
+    pub distance: f32,
+}
+
+/// Intersection of a ray/segment with an edge of a [`PolygonalMesh`], in world coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonalMeshEdgeHit {
+    /// Entity of the polygonal mesh.
+    pub entity: Entity,
+    /// Edge that was hit.
+    pub edge: Edge,
+    /// World position of the hit.
+    pub point: Vec3,
+    /// Parameter (0..=1) of the hit along the edge.
+    pub t: f32,
+    /// World distance from the origin of the ray/segment.
+    pub distance: f32,
+    /// Whether the ray/segment enters the polygon at the hit (exits otherwise).
+    pub is_entering: bool,
+}
+
+/// Spatial queries against all the [`PolygonalMesh`]es using world coordinates.
+///
+/// Positions are projected onto the plane of each polygon (the canvas plane) using
+/// [`world_to_canvas`] with the [`GlobalTransform`] of the polygonal mesh, rays and
+/// segments are queried in 2D within that plane. Tolerances are in polygon (canvas) units.
+#[derive(SystemParam)]
+pub struct PolygonalMeshSpatialQuery<'w, 's> {
+    query_polygonal_mesh: Query<'w, 's, (Entity, &'static PolygonalMesh, &'static GlobalTransform)>,
+}
+
+impl<'w, 's> PolygonalMeshSpatialQuery<'w, 's> {
+    /// Classify the world point against the polygonal mesh.
+    pub fn classify_point(
+        &self,
+        entity: Entity,
+        point: Vec3,
+        tolerance: f32,
+    ) -> Option<PointContainment> {
+        let (_, polygonal_mesh, transform) = self.query_polygonal_mesh.get(entity).ok()?;
+        Some(
+            polygonal_mesh
+                .mesh_polygon
+                .classify_point(world_to_canvas(point, transform), tolerance),
+        )
+    }
+
+    /// Get all the polygonal meshes containing the world point (or with it on their boundary).
+    pub fn meshes_containing_point(&self, point: Vec3, tolerance: f32) -> Vec<Entity> {
+        self.query_polygonal_mesh
+            .iter()
+            .filter(|(_, polygonal_mesh, transform)| {
+                polygonal_mesh
+                    .mesh_polygon
+                    .contains_point(world_to_canvas(point, transform), tolerance)
+            })
+            .map(|(entity, ..)| entity)
+            .collect()
+    }
+
+    /// Signed distance from the world point to the boundary of the polygonal mesh, positive inside.
+    pub fn signed_distance(&self, entity: Entity, point: Vec3) -> Option<f32> {
+        let (_, polygonal_mesh, transform) = self.query_polygonal_mesh.get(entity).ok()?;
+        let local_point = world_to_canvas(point, transform);
+        let nearest = polygonal_mesh
+            .mesh_polygon
+            .nearest_boundary_point(local_point)?;
+        let distance = canvas_to_world(local_point, transform)
+            .distance(canvas_to_world(nearest.point, transform));
+        if polygonal_mesh.mesh_polygon.signed_distance(local_point)? >= 0. {
+            Some(distance)
+        } else {
+            Some(-distance)
+        }
+    }
+
+    /// Get the nearest boundary point of the polygonal mesh to the world point.
+    pub fn nearest_boundary_point(
+        &self,
+        entity: Entity,
+        point: Vec3,
+    ) -> Option<PolygonalMeshBoundaryPoint> {
+        let (entity, polygonal_mesh, transform) = self.query_polygonal_mesh.get(entity).ok()?;
+        boundary_point(entity, polygonal_mesh, transform, point)
+    }
+
+    /// Get the nearest boundary point to the world point among all the polygonal meshes.
+    pub fn nearest_boundary_point_of_all(&self, point: Vec3) -> Option<PolygonalMeshBoundaryPoint> {
+        self.query_polygonal_mesh
+            .iter()
+            .filter_map(|(entity, polygonal_mesh, transform)| {
+                boundary_point(entity, polygonal_mesh, transform, point)
+            })
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    }
+
+    /// Intersect the world ray with the edges of all the polygonal meshes.
+    ///
+    /// Hits are sorted by distance from `origin`.
+    pub fn intersect_ray(&self, origin: Vec3, direction: Vec3) -> Vec<PolygonalMeshEdgeHit> {
+        self.edge_hits(origin, |polygon, transform| {
+            let local_direction = transform
+                .affine()
+                .inverse()
+                .transform_vector3(direction)
+                .xz();
+            polygon.intersect_ray(world_to_canvas(origin, transform), local_direction)
+        })
+    }
+
+    /// Get the first hit of the world ray among all the polygonal meshes.
+    pub fn raycast(&self, origin: Vec3, direction: Vec3) -> Option<PolygonalMeshEdgeHit> {
+        self.intersect_ray(origin, direction).into_iter().next()
+    }
+
+    /// Intersect the world segment with the edges of all the polygonal meshes.
+    ///
+    /// Hits are sorted by distance from `start`.
+    pub fn intersect_segment(&self, start: Vec3, end: Vec3) -> Vec<PolygonalMeshEdgeHit> {
+        self.edge_hits(start, |polygon, transform| {
+            polygon.intersect_segment(
+                world_to_canvas(start, transform),
+                world_to_canvas(end, transform),
+            )
+        })
+    }
+
+    /// Collect the hits of all the polygonal meshes in world coordinates.
+    fn edge_hits(
+        &self,
+        origin: Vec3,
+        polygon_hits: impl Fn(&MeshPolygon, &GlobalTransform) -> Vec<EdgeHit>,
+    ) -> Vec<PolygonalMeshEdgeHit> {
+        let mut hits = vec![];
+        for (entity, polygonal_mesh, transform) in self.query_polygonal_mesh.iter() {
+            let projected_origin = canvas_to_world(world_to_canvas(origin, transform), transform);
+            for hit in polygon_hits(&polygonal_mesh.mesh_polygon, transform) {
+                let point = canvas_to_world(hit.point, transform);
+                hits.push(PolygonalMeshEdgeHit {
+                    entity,
+                    edge: hit.edge,
+                    point,
+                    t: hit.t,
+                    distance: projected_origin.distance(point),
+                    is_entering: hit.is_entering,
+                });
+            }
+        }
+        hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        hits
+    }
+}
+
+/// Get the nearest boundary point of the polygonal mesh in world coordinates.
+fn boundary_point(
+    entity: Entity,
+    polygonal_mesh: &PolygonalMesh,
+    transform: &GlobalTransform,
+    point: Vec3,
+) -> Option<PolygonalMeshBoundaryPoint> {
+    let local_point = world_to_canvas(point, transform);
+    let nearest = polygonal_mesh
+        .mesh_polygon
+        .nearest_boundary_point(local_point)?;
+    let world_point = canvas_to_world(nearest.point, transform);
+    Some(PolygonalMeshBoundaryPoint {
+        entity,
+        edge: nearest.edge,
+        point: world_point,
+        t: nearest.t,
+        distance: canvas_to_world(local_point, transform).distance(world_point),
+    })
+}
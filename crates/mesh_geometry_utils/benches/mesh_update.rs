@@ -0,0 +1,59 @@
+//! Compare updating an extruded mesh in place (vertex drag) with extruding it again.
+//!
+//! Run with `cargo bench -p mesh_geometry_utils`.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::Vec2;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use mesh_geometry_utils::data_structures::MeshPolygon;
+
+/// Extrude size of the benchmarked meshes.
+const EXTRUDE_SIZE: f32 = 1.;
+
+/// Star shaped polygon (concave, non trivial triangulation) with `count` vertices.
+fn star_polygon(count: usize) -> MeshPolygon {
+    let points = (0..count)
+        .map(|index| {
+            let radius = if index % 2 == 0 { 10. } else { 7. };
+            Vec2::from_angle(-TAU * index as f32 / count as f32) * radius
+        })
+        .collect::<Vec<_>>();
+    MeshPolygon::from(points)
+}
+
+fn bench_vertex_drag(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vertex_drag");
+    for count in [16, 128, 512] {
+        let mut polygon = star_polygon(count);
+        let vertex_id = polygon.vertices.ids()[0];
+        let start = *polygon.vertices.get(vertex_id).unwrap();
+        let mut mesh = polygon.extrude_to_bevy_mesh(EXTRUDE_SIZE).unwrap();
+        // small drag steps keep the triangulation valid
+        let mut step = 0;
+        let mut drag = move |polygon: &mut MeshPolygon| {
+            step = (step + 1) % 10;
+            polygon.move_vertex(vertex_id, start + Vec2::splat(step as f32 * 0.01));
+        };
+        group.bench_with_input(BenchmarkId::new("extrude", count), &count, |b, _| {
+            b.iter(|| {
+                drag(&mut polygon);
+                mesh = black_box(polygon.extrude_to_bevy_mesh(EXTRUDE_SIZE).unwrap());
+            })
+        });
+        group.bench_with_input(
+            BenchmarkId::new("update_in_place", count),
+            &count,
+            |b, _| {
+                b.iter(|| {
+                    drag(&mut polygon);
+                    assert!(polygon.update_extruded_bevy_mesh(black_box(&mut mesh), EXTRUDE_SIZE));
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_vertex_drag);
+criterion_main!(benches);
@@ -0,0 +1,376 @@
+use bevy::{prelude::Vec2, utils::HashMap};
+use delegate::delegate;
+
+use super::Edge;
+
+/// Max recursion depth used while adaptively flattening bezier curves.
+const MAX_FLATTEN_DEPTH: u32 = 16;
+/// Max segments a single arc can be flattened into.
+const MAX_ARC_SEGMENTS: usize = 1024;
+/// Samples used to find the nearest curve parameter to a point.
+const NEAREST_T_SAMPLES: usize = 64;
+
+/// Curve of an edge.
+///
+/// Describes how an edge travels between its `from` and `to` vertices.
+/// Control points are in the same space as the vertices.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdgeCurve {
+    /// Quadratic bezier curve with a single control point.
+    QuadraticBezier(Vec2),
+    /// Cubic bezier curve with control points near `from` and `to` respectively.
+    CubicBezier(Vec2, Vec2),
+    /// Circular arc.
+    ///
+    /// `bulge` is `tan(θ/4)` where `θ` is the included angle of the arc.
+    /// Arc bulges to the left of `from -> to` when `+ve` and right when `-ve`.
+    Arc { bulge: f32 },
+}
+
+impl EdgeCurve {
+    /// Get the curve traversed in the opposite direction (`to -> from`).
+    pub fn reversed(&self) -> Self {
+        match *self {
+            Self::QuadraticBezier(ctrl) => Self::QuadraticBezier(ctrl),
+            Self::CubicBezier(ctrl1, ctrl2) => Self::CubicBezier(ctrl2, ctrl1),
+            Self::Arc { bulge } => Self::Arc { bulge: -bulge },
+        }
+    }
+
+    /// Get point on the curve at parameter `t` in `[0, 1]`.
+    pub fn point_at(&self, from: Vec2, to: Vec2, t: f32) -> Vec2 {
+        match *self {
+            Self::QuadraticBezier(ctrl) => {
+                let mt = 1. - t;
+                from * mt * mt + ctrl * 2. * mt * t + to * t * t
+            }
+            Self::CubicBezier(ctrl1, ctrl2) => {
+                let mt = 1. - t;
+                from * mt * mt * mt
+                    + ctrl1 * 3. * mt * mt * t
+                    + ctrl2 * 3. * mt * t * t
+                    + to * t * t * t
+            }
+            Self::Arc { bulge } => match ArcGeometry::new(from, to, bulge) {
+                Some(arc) => arc.point_at(t),
+                None => from.lerp(to, t),
+            },
+        }
+    }
+
+    /// Flatten the curve into line segments.
+    ///
+    /// Adaptively subdivides so that the flattened segments deviate from the
+    /// curve by at most `tolerance`.
+    ///
+    /// Returns the intermediate points only, `from` and `to` are excluded.
+    pub fn flatten(&self, from: Vec2, to: Vec2, tolerance: f32) -> Vec<Vec2> {
+        let tolerance = tolerance.max(f32::EPSILON);
+        let mut points = vec![];
+        match *self {
+            Self::QuadraticBezier(ctrl) => {
+                // Elevate to cubic, which is exact for quadratics.
+                let ctrl1 = from + (ctrl - from) * (2. / 3.);
+                let ctrl2 = to + (ctrl - to) * (2. / 3.);
+                flatten_cubic(from, ctrl1, ctrl2, to, tolerance, 0, &mut points);
+            }
+            Self::CubicBezier(ctrl1, ctrl2) => {
+                flatten_cubic(from, ctrl1, ctrl2, to, tolerance, 0, &mut points);
+            }
+            Self::Arc { bulge } => {
+                let Some(arc) = ArcGeometry::new(from, to, bulge) else {
+                    return points;
+                };
+                let segments = arc.segments_for_tolerance(tolerance);
+                for i in 1..segments {
+                    points.push(arc.point_at(i as f32 / segments as f32));
+                }
+                return points;
+            }
+        }
+        // last point pushed is the `to` point.
+        points.pop();
+        points
+    }
+
+    /// Split the curve at parameter `t` into 2 curves (`from -> point`, `point -> to`).
+    pub fn split(&self, from: Vec2, to: Vec2, t: f32) -> (Self, Self) {
+        let t = t.clamp(0., 1.);
+        match *self {
+            Self::QuadraticBezier(ctrl) => {
+                let ctrl_a = from.lerp(ctrl, t);
+                let ctrl_b = ctrl.lerp(to, t);
+                (Self::QuadraticBezier(ctrl_a), Self::QuadraticBezier(ctrl_b))
+            }
+            Self::CubicBezier(ctrl1, ctrl2) => {
+                // De Casteljau's algorithm.
+                let p01 = from.lerp(ctrl1, t);
+                let p12 = ctrl1.lerp(ctrl2, t);
+                let p23 = ctrl2.lerp(to, t);
+                let p012 = p01.lerp(p12, t);
+                let p123 = p12.lerp(p23, t);
+                (Self::CubicBezier(p01, p012), Self::CubicBezier(p123, p23))
+            }
+            Self::Arc { bulge } => {
+                let angle = 4. * bulge.atan();
+                let bulge_a = (angle * t / 4.).tan();
+                let bulge_b = (angle * (1. - t) / 4.).tan();
+                (Self::Arc { bulge: bulge_a }, Self::Arc { bulge: bulge_b })
+            }
+        }
+    }
+
+    /// Find the curve parameter `t` of the point on the curve nearest to `point`.
+    pub fn nearest_t(&self, from: Vec2, to: Vec2, point: Vec2) -> f32 {
+        let mut nearest_t = 0.;
+        let mut nearest_dist = f32::MAX;
+        for i in 0..=NEAREST_T_SAMPLES {
+            let t = i as f32 / NEAREST_T_SAMPLES as f32;
+            let dist = self.point_at(from, to, t).distance_squared(point);
+            if dist < nearest_dist {
+                nearest_t = t;
+                nearest_dist = dist;
+            }
+        }
+        nearest_t
+    }
+
+    /// Get positions of the (draggable) handles of the curve.
+    ///
+    /// - Beziers: the control points.
+    /// - Arc: the mid point of the arc.
+    pub fn handles(&self, from: Vec2, to: Vec2) -> Vec<Vec2> {
+        match *self {
+            Self::QuadraticBezier(ctrl) => vec![ctrl],
+            Self::CubicBezier(ctrl1, ctrl2) => vec![ctrl1, ctrl2],
+            Self::Arc { .. } => vec![self.point_at(from, to, 0.5)],
+        }
+    }
+
+    /// Get the curve with the handle at `index` moved to `position`.
+    ///
+    /// Ref: [`Self::handles`].
+    pub fn with_handle(&self, from: Vec2, to: Vec2, index: usize, position: Vec2) -> Self {
+        match (*self, index) {
+            (Self::QuadraticBezier(_), 0) => Self::QuadraticBezier(position),
+            (Self::CubicBezier(_, ctrl2), 0) => Self::CubicBezier(position, ctrl2),
+            (Self::CubicBezier(ctrl1, _), 1) => Self::CubicBezier(ctrl1, position),
+            (Self::Arc { .. }, 0) => {
+                let chord = to - from;
+                let half_chord_len = chord.length() / 2.;
+                if half_chord_len <= f32::EPSILON {
+                    return *self;
+                }
+                // signed sagitta, +ve on the left of the chord.
+                let sagitta = (position - (from + to) / 2.).dot(chord.normalize().perp());
+                Self::Arc {
+                    bulge: sagitta / half_chord_len,
+                }
+            }
+            _ => *self,
+        }
+    }
+
+    /// Get a curve bending the straight edge `from -> to`.
+    ///
+    /// Cubic bezier with control points at thirds of the edge, pushed to its left.
+    pub fn bend(from: Vec2, to: Vec2) -> Self {
+        let chord = to - from;
+        let offset = chord.perp() * 0.25;
+        Self::CubicBezier(
+            from + chord / 3. + offset,
+            from + chord * (2. / 3.) + offset,
+        )
+    }
+}
+
+/// Geometry of a circular arc from its end points and bulge.
+#[derive(Debug, Clone, Copy)]
+struct ArcGeometry {
+    center: Vec2,
+    radius: f32,
+    start_angle: f32,
+    sweep_angle: f32,
+}
+
+impl ArcGeometry {
+    /// Returns `None` for straight (zero bulge) or degenerate arcs.
+    fn new(from: Vec2, to: Vec2, bulge: f32) -> Option<Self> {
+        let chord = to - from;
+        let half_chord_len = chord.length() / 2.;
+        if bulge.abs() <= f32::EPSILON || half_chord_len <= f32::EPSILON {
+            return None;
+        }
+        let normal = chord.normalize().perp();
+        let sagitta = bulge * half_chord_len;
+        // distance of center from chord mid point along normal.
+        let center_offset = (sagitta * sagitta - half_chord_len * half_chord_len) / (2. * sagitta);
+        let center = (from + to) / 2. + normal * center_offset;
+        let radius = (sagitta - center_offset).abs();
+        let start = from - center;
+        let sweep_angle = -4. * bulge.atan();
+        Some(Self {
+            center,
+            radius,
+            start_angle: start.y.atan2(start.x),
+            sweep_angle,
+        })
+    }
+
+    fn point_at(&self, t: f32) -> Vec2 {
+        let angle = self.start_angle + self.sweep_angle * t;
+        self.center + Vec2::from_angle(angle) * self.radius
+    }
+
+    /// Number of segments needed so that max deviation is within tolerance.
+    fn segments_for_tolerance(&self, tolerance: f32) -> usize {
+        if tolerance >= self.radius {
+            return 1;
+        }
+        let max_step_angle = 2. * (1. - tolerance / self.radius).acos();
+        ((self.sweep_angle.abs() / max_step_angle).ceil() as usize).clamp(1, MAX_ARC_SEGMENTS)
+    }
+}
+
+/// Recursively flatten a cubic bezier, pushes the end point of each flat part.
+fn flatten_cubic(
+    p0: Vec2,
+    p1: Vec2,
+    p2: Vec2,
+    p3: Vec2,
+    tolerance: f32,
+    depth: u32,
+    points: &mut Vec<Vec2>,
+) {
+    let is_flat = distance_to_segment(p1, p0, p3) <= tolerance
+        && distance_to_segment(p2, p0, p3) <= tolerance;
+    if is_flat || depth >= MAX_FLATTEN_DEPTH {
+        points.push(p3);
+        return;
+    }
+    let p01 = p0.lerp(p1, 0.5);
+    let p12 = p1.lerp(p2, 0.5);
+    let p23 = p2.lerp(p3, 0.5);
+    let p012 = p01.lerp(p12, 0.5);
+    let p123 = p12.lerp(p23, 0.5);
+    let mid = p012.lerp(p123, 0.5);
+    flatten_cubic(p0, p01, p012, mid, tolerance, depth + 1, points);
+    flatten_cubic(mid, p123, p23, p3, tolerance, depth + 1, points);
+}
+
+/// Distance of point `p` from the segment `a -> b`.
+fn distance_to_segment(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    if len_sq <= f32::EPSILON {
+        return p.distance(a);
+    }
+    let t = ((p - a).dot(ab) / len_sq).clamp(0., 1.);
+    p.distance(a + ab * t)
+}
+
+/// Edge Curves Data Structure
+///
+/// Stores the curves of the curved edges of a polygon.
+/// Edges without a curve are straight.
+#[derive(Debug, Clone, Default)]
+pub struct EdgeCurves(pub HashMap<Edge, EdgeCurve>);
+
+impl EdgeCurves {
+    /// Create a new empty set of curves.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Get curve of the edge, traversed in the direction of the given edge.
+    ///
+    /// Also finds curves stored against the reversed edge.
+    pub fn get_directed(&self, edge: &Edge) -> Option<EdgeCurve> {
+        if let Some(curve) = self.0.get(edge) {
+            return Some(*curve);
+        }
+        self.0
+            .get(&Edge::new(edge.to, edge.from))
+            .map(|curve| curve.reversed())
+    }
+
+    delegate! {
+        to self.0 {
+            /// Get curve of the edge.
+            pub fn get(&self, edge: &Edge) -> Option<&EdgeCurve>;
+            /// Insert a curve for the edge, returns the previous one (if any).
+            pub fn insert(&mut self, edge: Edge, curve: EdgeCurve) -> Option<EdgeCurve>;
+            /// Remove curve of the edge.
+            pub fn remove(&mut self, edge: &Edge) -> Option<EdgeCurve>;
+            /// Check if edge has a curve.
+            pub fn contains_key(&self, edge: &Edge) -> bool;
+            pub fn len(&self) -> usize;
+            pub fn is_empty(&self) -> bool;
+            pub fn clear(&mut self);
+        }
+    }
+}
+
+#[test]
+fn test_edge_curve_cubic_flatten_within_tolerance() {
+    let from = Vec2::new(0., 0.);
+    let to = Vec2::new(4., 0.);
+    let curve = EdgeCurve::CubicBezier(Vec2::new(1., 2.), Vec2::new(3., 2.));
+    let tolerance = 0.01;
+    let points = curve.flatten(from, to, tolerance);
+    assert!(points.len() > 4);
+    // every flattened point lies on the curve
+    for point in points.iter() {
+        let t = curve.nearest_t(from, to, *point);
+        assert!(curve.point_at(from, to, t).distance(*point) < 0.1);
+    }
+    // a straight curve does not need intermediate points
+    let straight = EdgeCurve::CubicBezier(Vec2::new(1., 0.), Vec2::new(3., 0.));
+    assert!(straight.flatten(from, to, tolerance).is_empty());
+}
+
+#[test]
+fn test_edge_curve_arc() {
+    let from = Vec2::new(-1., 0.);
+    let to = Vec2::new(1., 0.);
+    // half circle (θ = π) bulging left of from -> to, ie. towards +y.
+    let curve = EdgeCurve::Arc { bulge: 1. };
+    let mid = curve.point_at(from, to, 0.5);
+    assert!(mid.distance(Vec2::new(0., 1.)) < 1e-5, "{mid}");
+    assert!(curve.point_at(from, to, 0.).distance(from) < 1e-5);
+    assert!(curve.point_at(from, to, 1.).distance(to) < 1e-5);
+    // all flattened points are on the unit circle
+    let points = curve.flatten(from, to, 0.001);
+    assert!(!points.is_empty());
+    for point in points.iter() {
+        assert!((point.length() - 1.).abs() < 1e-4);
+    }
+    // handle roundtrip
+    let handle = curve.handles(from, to)[0];
+    let updated = curve.with_handle(from, to, 0, handle);
+    assert_eq!(updated, curve);
+    // reversed traverses the same points
+    let reversed = curve.reversed();
+    assert!(reversed.point_at(to, from, 0.5).distance(mid) < 1e-5);
+}
+
+#[test]
+fn test_edge_curve_split() {
+    let from = Vec2::new(0., 0.);
+    let to = Vec2::new(4., 0.);
+    for curve in [
+        EdgeCurve::QuadraticBezier(Vec2::new(2., 3.)),
+        EdgeCurve::CubicBezier(Vec2::new(1., 2.), Vec2::new(3., -2.)),
+        EdgeCurve::Arc { bulge: 0.5 },
+    ] {
+        let t = 0.3;
+        let split_pt = curve.point_at(from, to, t);
+        let (curve_a, curve_b) = curve.split(from, to, t);
+        for s in [0., 0.25, 0.5, 1.] {
+            let on_a = curve_a.point_at(from, split_pt, s);
+            let on_b = curve_b.point_at(split_pt, to, s);
+            assert!(curve.point_at(from, to, t * s).distance(on_a) < 1e-4);
+            assert!(curve.point_at(from, to, t + (1. - t) * s).distance(on_b) < 1e-4);
+        }
+    }
+}
@@ -1,8 +1,14 @@
 use bevy::prelude::Mesh;
 
-use crate::mesh_builder::earcutr::generate_mesh_earcutr;
+use crate::mesh_builder::{
+    earcutr::{generate_mesh_earcutr, update_mesh_earcutr},
+    lathe::{generate_mesh_lathe, LatheOptions},
+};
 
-use super::{vertices::Vertex, Edge, Edges, VertexId, Vertices};
+use super::{vertices::Vertex, Edge, EdgeCurve, EdgeCurves, Edges, VertexId, Vertices};
+
+/// Default max deviation allowed when flattening curved edges.
+pub const DEFAULT_FLATTEN_TOLERANCE: f32 = 0.01;
 
 /// # Mesh Polygon Data Structure
 ///
@@ -13,6 +19,10 @@ pub struct MeshPolygon {
     pub vertices: Vertices,
     /// Edges of the polygon.
     pub edges: Edges,
+    /// Curves of the curved edges of the polygon. Edges without a curve are straight.
+    pub curves: EdgeCurves,
+    /// Max deviation allowed when flattening curved edges before triangulation.
+    pub flatten_tolerance: f32,
 }
 
 impl MeshPolygon {
@@ -21,6 +31,8 @@ impl MeshPolygon {
         Self {
             vertices: Vertices::new(),
             edges: Edges::new(),
+            curves: EdgeCurves::new(),
+            flatten_tolerance: DEFAULT_FLATTEN_TOLERANCE,
         }
     }
 
@@ -28,6 +40,7 @@ impl MeshPolygon {
     pub fn clear_with_reset(&mut self) {
         self.vertices.clear_with_reset();
         self.edges.clear();
+        self.curves.clear();
     }
 
     /// Reverse the order of vertices and edges in polygon
@@ -44,6 +57,16 @@ impl MeshPolygon {
         for edge in updated_edges {
             self.edges.insert(edge);
         }
+        // reverse the curves along with their edges...
+        let updated_curves = self
+            .curves
+            .0
+            .drain()
+            .map(|(Edge { from, to }, curve)| (Edge::new(to, from), curve.reversed()))
+            .collect::<Vec<_>>();
+        for (edge, curve) in updated_curves {
+            self.curves.insert(edge, curve);
+        }
     }
 
     /// Push a new vertex to the end and return its Id.
@@ -76,18 +99,116 @@ impl MeshPolygon {
         false
     }
 
+    /// Get the curve of an edge, traversed in the direction of the given edge.
+    ///
+    /// Returns `None` for straight edges.
+    pub fn edge_curve(&self, edge: &Edge) -> Option<EdgeCurve> {
+        self.curves.get_directed(edge)
+    }
+
+    /// Set or unset (using `None`) the curve of an existing edge.
+    ///
+    /// Returns if the edge exists.
+    pub fn set_edge_curve(&mut self, edge: Edge, curve: Option<EdgeCurve>) -> bool {
+        let reversed_edge = Edge::new(edge.to, edge.from);
+        let stored_edge = if self.edges.contains(&edge) {
+            edge.clone()
+        } else if self.edges.contains(&reversed_edge) {
+            reversed_edge
+        } else {
+            return false;
+        };
+        match curve {
+            Some(curve) if stored_edge == edge => self.curves.insert(stored_edge, curve),
+            Some(curve) => self.curves.insert(stored_edge, curve.reversed()),
+            None => self.curves.remove(&stored_edge),
+        };
+        true
+    }
+
+    /// Move vertex to the given position.
+    ///
+    /// Bezier control points adjacent to the vertex are moved along with it.
+    ///
+    /// Returns if the vertex exists.
+    pub fn move_vertex(&mut self, vertex_id: VertexId, position: Vertex) -> bool {
+        let Some(vertex) = self.vertices.get_mut(vertex_id) else {
+            return false;
+        };
+        let delta = position - *vertex;
+        *vertex = position;
+        for (Edge { from, to }, curve) in self.curves.0.iter_mut() {
+            *curve = match *curve {
+                EdgeCurve::QuadraticBezier(ctrl) if *from == vertex_id || *to == vertex_id => {
+                    EdgeCurve::QuadraticBezier(ctrl + delta / 2.)
+                }
+                EdgeCurve::CubicBezier(ctrl1, ctrl2) if *from == vertex_id => {
+                    EdgeCurve::CubicBezier(ctrl1 + delta, ctrl2)
+                }
+                EdgeCurve::CubicBezier(ctrl1, ctrl2) if *to == vertex_id => {
+                    EdgeCurve::CubicBezier(ctrl1, ctrl2 + delta)
+                }
+                curve => curve,
+            };
+        }
+        true
+    }
+
+    /// Get the outline of the polygon in order, with curved edges flattened.
+    ///
+    /// Ref: [`EdgeCurve::flatten`] and [`Self::flatten_tolerance`].
+    pub fn outline(&self) -> Vec<Vertex> {
+        let ids = self.vertices.ids();
+        let mut outline = Vec::with_capacity(ids.len());
+        for (index, id) in ids.iter().enumerate() {
+            let Some(from_vert) = self.vertices.get(*id) else {
+                continue;
+            };
+            outline.push(*from_vert);
+            let next_id = ids[(index + 1) % ids.len()];
+            let Some(to_vert) = self.vertices.get(next_id) else {
+                continue;
+            };
+            if let Some(curve) = self.edge_curve(&Edge::new(*id, next_id)) {
+                outline.extend(curve.flatten(*from_vert, *to_vert, self.flatten_tolerance));
+            }
+        }
+        outline
+    }
+
     /// Extrude the polygon into a Bevy Mesh.
     ///
     /// Internally tries to close the polygon
     /// before generating the mesh using the mesh builder.
     pub fn extrude_to_bevy_mesh(&mut self, extrude_size: f32) -> Option<Mesh> {
         if self.close() {
-            let mesh = generate_mesh_earcutr(self.vertices.get_all_owned(), extrude_size);
+            let mesh = generate_mesh_earcutr(self.outline(), extrude_size);
             return Some(mesh);
         }
         None
     }
 
+    /// Update the mesh extruded from the polygon in place, keeping its triangulation.
+    ///
+    /// Cheaper than [`extrude_to_bevy_mesh`](MeshPolygon::extrude_to_bevy_mesh)
+    /// for moved vertices. Returns `false` (mesh untouched) if the number of
+    /// outline points changed or the triangulation became invalid (eg: a flipped
+    /// triangle), the mesh needs to be extruded again then.
+    pub fn update_extruded_bevy_mesh(&mut self, mesh: &mut Mesh, extrude_size: f32) -> bool {
+        self.close() && update_mesh_earcutr(mesh, &self.outline(), extrude_size)
+    }
+
+    /// Lathe (revolve) the polygon into a Bevy Mesh.
+    ///
+    /// Uses the polygon as the profile revolved around the axis in [`LatheOptions`].
+    /// Internally tries to close the polygon before generating the mesh.
+    pub fn lathe_to_bevy_mesh(&mut self, options: &LatheOptions) -> Option<Mesh> {
+        if self.close() {
+            return generate_mesh_lathe(self.outline(), options);
+        }
+        None
+    }
+
     /// Remove vertex by Id.
     ///
     /// Removes any edges connecting to it.
@@ -113,7 +234,8 @@ impl MeshPolygon {
             } else if edge.from == vertex_id {
                 new_edge_to = Some(edge.to);
             }
-            // cleanup orphan edges
+            // cleanup orphan edges and their curves
+            self.curves.remove(&edge);
             self.edges.remove(&edge);
         }
         let mut added_edges = vec![];
@@ -126,10 +248,53 @@ impl MeshPolygon {
         (vertex, added_edges)
     }
 
+    /// Replace vertex by a chain of new vertices (in order).
+    ///
+    /// Edges connecting to the vertex (along with their curves) are replaced
+    /// by straight edges connecting its neighbors through the chain.
+    ///
+    /// Returns the ids of the new vertices or empty if not replaced.
+    pub fn replace_vertex(
+        &mut self,
+        vertex_id: VertexId,
+        replacement: Vec<Vertex>,
+    ) -> Vec<VertexId> {
+        let Some(index) = self.vertices.ids().iter().position(|id| *id == vertex_id) else {
+            return vec![];
+        };
+        if replacement.is_empty() {
+            return vec![];
+        }
+        let ids = self.vertices.insert_many(index, replacement);
+        self.vertices.remove(vertex_id);
+        let orphan_edges = self
+            .edges
+            .filter_by_vertex(vertex_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut chain = ids.clone();
+        for edge in orphan_edges {
+            if edge.to == vertex_id {
+                chain.insert(0, edge.from);
+            } else if edge.from == vertex_id {
+                chain.push(edge.to);
+            }
+            // cleanup orphan edges and their curves
+            self.curves.remove(&edge);
+            self.edges.remove(&edge);
+        }
+        for pair in chain.windows(2) {
+            self.edges.insert(Edge::new(pair[0], pair[1]));
+        }
+        ids
+    }
+
     /// Inserts a new vertex on the specified edge.
     ///
     /// Also adds the connecting edges and removed existing edge.
     ///
+    /// Curved edges are split at the point on the curve nearest to the vertex.
+    ///
     /// Returns the inserted vertex id or None if not inserted.
     pub fn insert_vertex_on_edge(&mut self, vertex: Vertex, edge: Edge) -> Option<VertexId> {
         let Edge { from, to } = edge;
@@ -151,6 +316,18 @@ impl MeshPolygon {
             } else {
                 to_idx + 1
             };
+        // split the curve (if any) of the edge
+        let split_curves = match (
+            self.curves.remove(&edge),
+            self.vertices.get(from),
+            self.vertices.get(to),
+        ) {
+            (Some(curve), Some(from_vert), Some(to_vert)) => {
+                let t = curve.nearest_t(*from_vert, *to_vert, vertex);
+                Some(curve.split(*from_vert, *to_vert, t))
+            }
+            _ => None,
+        };
         // insert new vert at from + 1
         let id = self.vertices.insert(insert_idx, vertex);
         // remove edge
@@ -158,6 +335,10 @@ impl MeshPolygon {
         // create new connecting edges
         self.edges.insert(Edge { from, to: id });
         self.edges.insert(Edge { from: id, to });
+        if let Some((curve_a, curve_b)) = split_curves {
+            self.curves.insert(Edge { from, to: id }, curve_a);
+            self.curves.insert(Edge { from: id, to }, curve_b);
+        }
         Some(id)
     }
 }
@@ -493,3 +674,107 @@ fn test_vertex_remove_a_reversed_vertices() {
     // assert correct edges
     assert_eq!(polygon.edges.0, expected_edges);
 }
+
+#[test]
+fn test_curved_edges_outline() {
+    use bevy::prelude::Vec2;
+
+    let mut polygon = MeshPolygon::new();
+    let a = polygon.push_vertex(Vec2::new(0., 0.));
+    let b = polygon.push_vertex(Vec2::new(0., 1.));
+    polygon.push_vertex(Vec2::new(1., 1.));
+    polygon.push_vertex(Vec2::new(1., 0.));
+    assert!(polygon.close());
+    assert_eq!(polygon.outline().len(), 4);
+    // curve the first edge
+    let curve = EdgeCurve::QuadraticBezier(Vec2::new(-1., 0.5));
+    assert!(polygon.set_edge_curve(Edge::new(a, b), Some(curve)));
+    let outline = polygon.outline();
+    assert!(outline.len() > 4);
+    assert_eq!(outline[0], Vec2::new(0., 0.));
+    assert!(outline[1..outline.len() - 3].iter().all(|p| p.x < 0.));
+    // curves follow the edges when reversed
+    polygon.reverse();
+    assert_eq!(polygon.edge_curve(&Edge::new(a, b)), Some(curve));
+    assert_eq!(polygon.edge_curve(&Edge::new(b, a)), Some(curve.reversed()));
+    assert_eq!(polygon.outline().len(), outline.len());
+    // control points follow moved vertices
+    assert!(polygon.move_vertex(b, Vec2::new(0., 2.)));
+    assert_eq!(
+        polygon.edge_curve(&Edge::new(a, b)),
+        Some(EdgeCurve::QuadraticBezier(Vec2::new(-1., 1.)))
+    );
+    // unset the curve
+    assert!(polygon.set_edge_curve(Edge::new(a, b), None));
+    assert_eq!(polygon.outline().len(), 4);
+}
+
+#[test]
+fn test_curved_edge_vertex_insert_and_remove() {
+    use bevy::prelude::Vec2;
+
+    let mut polygon = MeshPolygon::new();
+    let a = polygon.push_vertex(Vec2::new(0., 0.));
+    let b = polygon.push_vertex(Vec2::new(0., 2.));
+    polygon.push_vertex(Vec2::new(2., 2.));
+    polygon.push_vertex(Vec2::new(2., 0.));
+    assert!(polygon.close());
+    let curve = EdgeCurve::CubicBezier(Vec2::new(-1., 0.5), Vec2::new(-1., 1.5));
+    polygon.set_edge_curve(Edge::new(a, b), Some(curve));
+    // insert splits the curve into both new edges
+    let mid = curve.point_at(Vec2::new(0., 0.), Vec2::new(0., 2.), 0.5);
+    let id = polygon.insert_vertex_on_edge(mid, Edge::new(a, b)).unwrap();
+    assert!(polygon.edge_curve(&Edge::new(a, b)).is_none());
+    assert!(polygon.edge_curve(&Edge::new(a, id)).is_some());
+    assert!(polygon.edge_curve(&Edge::new(id, b)).is_some());
+    // removing the vertex straightens the joined edge
+    polygon.remove_vertex(id);
+    assert!(polygon.curves.is_empty());
+}
+
+#[test]
+fn test_update_extruded_bevy_mesh() {
+    use bevy::{
+        prelude::{Vec2, Vec3},
+        render::mesh::VertexAttributeValues,
+    };
+
+    let attribute = |mesh: &Mesh, id| match mesh.attribute(id) {
+        Some(VertexAttributeValues::Float32x3(values)) => values.clone(),
+        _ => panic!("Missing attribute!"),
+    };
+    let mut polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(0., 2.),
+        Vec2::new(1., 3.),
+        Vec2::new(2., 2.),
+        Vec2::new(2., 0.),
+    ]);
+    let ids = polygon.vertices.ids().clone();
+    let mut mesh = polygon.extrude_to_bevy_mesh(1.).unwrap();
+    // moved vertex, updated in place same as the extruded mesh
+    assert!(polygon.move_vertex(ids[2], Vec2::new(1.2, 3.5)));
+    assert!(polygon.update_extruded_bevy_mesh(&mut mesh, 1.));
+    let extruded = polygon.extrude_to_bevy_mesh(1.).unwrap();
+    for id in [Mesh::ATTRIBUTE_POSITION, Mesh::ATTRIBUTE_NORMAL] {
+        let (updated, expected) = (attribute(&mesh, id.clone()), attribute(&extruded, id));
+        assert_eq!(updated.len(), expected.len());
+        for (updated, expected) in updated.iter().zip(expected.iter()) {
+            assert!(Vec3::from(*updated).abs_diff_eq(Vec3::from(*expected), 1e-5));
+        }
+    }
+    // flipped triangle (vertex dragged across the polygon)
+    assert!(polygon.move_vertex(ids[2], Vec2::new(1., -3.)));
+    assert!(!polygon.update_extruded_bevy_mesh(&mut mesh, 1.));
+    // topology changed
+    let mut polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(0., 2.),
+        Vec2::new(2., 2.),
+        Vec2::new(2., 0.),
+    ]);
+    let mut mesh = polygon.extrude_to_bevy_mesh(1.).unwrap();
+    let ids = polygon.vertices.ids().clone();
+    polygon.insert_vertex_on_edge(Vec2::new(1., 2.), Edge::new(ids[1], ids[2]));
+    assert!(!polygon.update_extruded_bevy_mesh(&mut mesh, 1.));
+}
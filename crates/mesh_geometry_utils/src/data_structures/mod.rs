@@ -1,3 +1,5 @@
+/// Module for **EdgeCurves** data structure.
+pub mod curves;
 /// Module for **Edges** data structure.
 pub mod edges;
 /// Module for **MeshPolygon** data structure.
@@ -7,6 +9,7 @@ pub mod ordered_sm;
 /// Module for **Vertices** data structure.
 pub mod vertices;
 
+pub use curves::{EdgeCurve, EdgeCurves};
 pub use edges::{Edge, Edges};
 pub use mesh_polygon::MeshPolygon;
 pub use ordered_sm::OrderedSlotMap;
@@ -2,3 +2,5 @@
 pub mod data_structures;
 /// Mesh building and generation utilities.
 pub mod mesh_builder;
+/// Operations used to create and manipulate mesh polygons.
+pub mod operations;
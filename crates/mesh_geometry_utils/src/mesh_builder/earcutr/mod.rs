@@ -1,4 +1,7 @@
-use bevy::prelude::{Mesh, Vec2, Vec3};
+use bevy::{
+    prelude::{Mesh, Vec2, Vec3},
+    render::mesh::{Indices, VertexAttributeValues},
+};
 use geo::{coord, LineString, Polygon};
 use mesh::MeshBuilder;
 
@@ -54,3 +57,100 @@ pub fn generate_mesh_earcutr(path_2d: Vec<Vec2>, extrude_amount: f32) -> Mesh {
 
     builder.build()
 }
+
+/// Update a mesh generated with [`generate_mesh_earcutr`] in place for the
+/// moved points of the 2D path, keeping its triangulation.
+///
+/// Only the changed positions (and the normals of the affected walls) are rewritten.
+///
+/// Returns `false` (mesh untouched) if the triangulation can't be kept: the path
+/// has a different number of points than the mesh or a floor/ceiling triangle
+/// flips (or degenerates). Regenerate the mesh using [`generate_mesh_earcutr`] then.
+pub fn update_mesh_earcutr(mesh: &mut Mesh, path_2d: &[Vec2], extrude_amount: f32) -> bool {
+    let Some(first) = path_2d.first() else {
+        return false;
+    };
+    // same closed ring as the polygon used to generate the mesh
+    let mut ring = path_2d.to_vec();
+    if path_2d.last() != Some(first) {
+        ring.push(*first);
+    }
+    let ring_len = ring.len();
+    let wall_count = ring_len - 1;
+    // floor + ceiling + 4 corners per wall
+    let cap_len = 2 * ring_len;
+    if mesh.count_vertices() != cap_len + 4 * wall_count {
+        return false;
+    }
+    let (Some(Indices::U32(indices)), Some(VertexAttributeValues::Float32x3(positions))) =
+        (mesh.indices(), mesh.attribute(Mesh::ATTRIBUTE_POSITION))
+    else {
+        return false;
+    };
+    // floor/ceiling triangles need to keep their orientation
+    for triangle in indices.chunks_exact(3) {
+        let triangle = [triangle[0], triangle[1], triangle[2]];
+        if triangle.iter().any(|index| *index as usize >= cap_len) {
+            continue;
+        }
+        let old = triangle.map(|index| {
+            let position = positions[index as usize];
+            Vec2::new(position[0], position[2])
+        });
+        let new = triangle.map(|index| ring[index as usize % ring_len]);
+        let old_area = (old[1] - old[0]).perp_dot(old[2] - old[0]);
+        let new_area = (new[1] - new[0]).perp_dot(new[2] - new[0]);
+        if old_area * new_area <= 0. {
+            return false;
+        }
+    }
+    let y1 = 0.;
+    let y2 = extrude_amount;
+    let mut moved_walls = vec![];
+    if let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+    {
+        let mut set_position = |index: usize, position: Vec3| {
+            let position = position.to_array();
+            if positions[index] != position {
+                positions[index] = position;
+                return true;
+            }
+            false
+        };
+        // Floor & Ceiling
+        for (index, point) in ring.iter().enumerate() {
+            set_position(index, Vec3::new(point.x, y1, point.y));
+            set_position(ring_len + index, Vec3::new(point.x, y2, point.y));
+        }
+        // Walls
+        for (wall, line) in ring.windows(2).enumerate() {
+            let corners = [
+                Vec3::new(line[0].x, y1, line[0].y),
+                Vec3::new(line[1].x, y1, line[1].y),
+                Vec3::new(line[1].x, y2, line[1].y),
+                Vec3::new(line[0].x, y2, line[0].y),
+            ];
+            let offset = cap_len + 4 * wall;
+            let mut is_moved = false;
+            for (index, corner) in corners.into_iter().enumerate() {
+                is_moved |= set_position(offset + index, corner);
+            }
+            if is_moved {
+                moved_walls.push((offset, corners));
+            }
+        }
+    }
+    if let Some(VertexAttributeValues::Float32x3(normals)) =
+        mesh.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+    {
+        for (offset, corners) in moved_walls {
+            // same as the generated walls
+            let bottom_line = corners[1] - corners[0];
+            let up_line = corners[2] - corners[1];
+            let normal = bottom_line.cross(up_line).normalize().to_array();
+            normals[offset..offset + 4].fill(normal);
+        }
+    }
+    true
+}
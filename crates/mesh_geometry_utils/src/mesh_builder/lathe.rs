@@ -0,0 +1,368 @@
+//! Generate/Build Mesh by revolving (lathing) a 2D profile around an axis.
+
+use std::f32::consts::TAU;
+
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::{
+    prelude::{Mesh, Vec2, Vec3},
+    render::{mesh::Indices, render_resource::PrimitiveTopology},
+};
+
+/// Axis of revolution in canvas space.
+///
+/// The axis is a line lying on the canvas passing through `origin` along `direction`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatheAxis {
+    /// Point on the axis.
+    pub origin: Vec2,
+    /// Direction of the axis. Does not need to be normalized.
+    pub direction: Vec2,
+}
+
+impl LatheAxis {
+    /// Create a new axis passing through `origin` along `direction`.
+    pub fn new(origin: Vec2, direction: Vec2) -> Self {
+        Self { origin, direction }
+    }
+}
+
+impl Default for LatheAxis {
+    /// Axis along the canvas `Z` (`Vec2::Y`) direction passing through the origin.
+    fn default() -> Self {
+        Self::new(Vec2::ZERO, Vec2::Y)
+    }
+}
+
+/// Options used to lathe a profile into a solid of revolution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatheOptions {
+    /// Axis of revolution.
+    pub axis: LatheAxis,
+    /// Number of segments used for the full sweep.
+    pub segments: u32,
+    /// Sweep angle in radians. Clamped to `(0, TAU]`.
+    ///
+    /// Partial revolves (less than `TAU`) are capped at both ends.
+    pub sweep_angle: f32,
+    /// Use smooth (averaged) normals instead of flat faceted normals.
+    pub smooth_normals: bool,
+}
+
+impl Default for LatheOptions {
+    fn default() -> Self {
+        Self {
+            axis: LatheAxis::default(),
+            segments: 32,
+            sweep_angle: TAU,
+            smooth_normals: true,
+        }
+    }
+}
+
+/// Generate 3D Mesh by lathing.
+///
+/// Revolves the closed 2D `profile` (in canvas space) around the [`LatheAxis`].
+/// The canvas is the `XZ` plane with `Y` as its normal, at `0` angle the revolved
+/// profile lies on the canvas.
+///
+/// Returns `None` if the profile has less than 3 points or the axis is degenerate.
+pub fn generate_mesh_lathe(profile: Vec<Vec2>, options: &LatheOptions) -> Option<Mesh> {
+    if profile.len() < 3 || options.axis.direction.length_squared() <= f32::EPSILON {
+        return None;
+    }
+    let axis_dir = options.axis.direction.normalize();
+    // Vector perpendicular to the axis on the canvas, used as radial direction.
+    let radial_dir = axis_dir.perp();
+    // Convert profile to (axial, radial) coordinates.
+    let mut profile_ar = profile
+        .iter()
+        .map(|p| {
+            let rel = *p - options.axis.origin;
+            Vec2::new(rel.dot(axis_dir), rel.dot(radial_dir))
+        })
+        .collect::<Vec<_>>();
+    // Keep a consistent winding so generated faces always point outwards.
+    if signed_area(&profile_ar) < 0. {
+        profile_ar.reverse();
+    }
+
+    let sweep_angle = options.sweep_angle.clamp(f32::EPSILON, TAU);
+    let is_full_revolve = sweep_angle >= TAU - f32::EPSILON;
+    let segments = ((options.segments.max(3) as f32) * (sweep_angle / TAU))
+        .ceil()
+        .max(1.) as usize;
+    let axis_dir_3d = Vec3::new(axis_dir.x, 0., axis_dir.y);
+    let radial_dir_3d = Vec3::new(radial_dir.x, 0., radial_dir.y);
+    let origin_3d = Vec3::new(options.axis.origin.x, 0., options.axis.origin.y);
+    // Position of the point in (axial, radial) coordinates revolved by angle.
+    let revolve = |point: Vec2, angle: f32| -> Vec3 {
+        origin_3d
+            + axis_dir_3d * point.x
+            + (radial_dir_3d * angle.cos() + Vec3::Y * angle.sin()) * point.y
+    };
+
+    let mut builder = LatheMeshBuilder::default();
+    let profile_len = profile_ar.len();
+    // Rings of revolved profile, last ring is the first one for full revolves.
+    let ring_count = if is_full_revolve {
+        segments
+    } else {
+        segments + 1
+    };
+    let rings = (0..ring_count)
+        .map(|ring| {
+            let angle = sweep_angle * ring as f32 / segments as f32;
+            profile_ar
+                .iter()
+                .map(|p| revolve(*p, angle))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    if options.smooth_normals {
+        // Shared vertices, normals accumulated from adjacent faces.
+        for ring in rings.iter() {
+            for position in ring.iter() {
+                builder.add_vertex(*position, Vec3::ZERO);
+            }
+        }
+        for segment in 0..segments {
+            let ring_a = segment;
+            let ring_b = (segment + 1) % ring_count;
+            for i in 0..profile_len {
+                let j = (i + 1) % profile_len;
+                let a = (ring_a * profile_len + i) as u32;
+                let b = (ring_a * profile_len + j) as u32;
+                let c = (ring_b * profile_len + j) as u32;
+                let d = (ring_b * profile_len + i) as u32;
+                builder.add_smooth_quad([a, b, c, d]);
+            }
+        }
+        builder.normalize_normals();
+    } else {
+        // Separate vertices per face with flat normals.
+        for segment in 0..segments {
+            let ring_a = &rings[segment];
+            let ring_b = &rings[(segment + 1) % ring_count];
+            for i in 0..profile_len {
+                let j = (i + 1) % profile_len;
+                builder.add_flat_quad([ring_a[i], ring_a[j], ring_b[j], ring_b[i]]);
+            }
+        }
+    }
+
+    if !is_full_revolve {
+        // Cap both ends of the partial revolve.
+        let cap_triangles = triangulate_profile(&profile_ar)?;
+        let start_normal = revolve_tangent(&radial_dir_3d, 0.) * -1.;
+        let end_normal = revolve_tangent(&radial_dir_3d, sweep_angle);
+        builder.add_cap(&rings[0], &cap_triangles, start_normal);
+        builder.add_cap(&rings[ring_count - 1], &cap_triangles, end_normal);
+    }
+
+    Some(builder.build())
+}
+
+/// Tangent direction of the revolution at the given angle.
+fn revolve_tangent(radial_dir_3d: &Vec3, angle: f32) -> Vec3 {
+    (*radial_dir_3d * -angle.sin() + Vec3::Y * angle.cos()).normalize()
+}
+
+/// Signed area of the 2D polygon, `+ve` for **CCW** order.
+fn signed_area(points: &[Vec2]) -> f32 {
+    let mut area = 0.;
+    for (i, p1) in points.iter().enumerate() {
+        let p2 = points[(i + 1) % points.len()];
+        area += p1.perp_dot(p2);
+    }
+    area / 2.
+}
+
+/// Triangulate the profile used for caps, returns triangle indices into the profile.
+fn triangulate_profile(profile: &[Vec2]) -> Option<Vec<usize>> {
+    let flat = profile
+        .iter()
+        .flat_map(|p| [p.x as f64, p.y as f64])
+        .collect::<Vec<_>>();
+    earcutr::earcut(&flat, &[], 2).ok()
+}
+
+/// Mesh builder for lathe geometry.
+#[derive(Debug, Default)]
+struct LatheMeshBuilder {
+    positions: Vec<Vec3>,
+    normals: Vec<Vec3>,
+    indices: Vec<u32>,
+}
+
+impl LatheMeshBuilder {
+    fn add_vertex(&mut self, position: Vec3, normal: Vec3) -> u32 {
+        self.positions.push(position);
+        self.normals.push(normal);
+        (self.positions.len() - 1) as u32
+    }
+
+    /// Adds a quad on shared vertices, accumulating its normal on them.
+    fn add_smooth_quad(&mut self, [a, b, c, d]: [u32; 4]) {
+        let [pa, pb, pc, pd] = [a, b, c, d].map(|i| self.positions[i as usize]);
+        // area weighted face normal, robust against degenerate (on axis) corners.
+        let normal = (pc - pa).cross(pd - pb);
+        if normal.length_squared() <= f32::EPSILON * f32::EPSILON {
+            return;
+        }
+        for i in [a, b, c, d] {
+            self.normals[i as usize] += normal;
+        }
+        self.indices.extend([a, b, c, a, c, d]);
+    }
+
+    /// Adds a quad with its own vertices and flat normal.
+    fn add_flat_quad(&mut self, [pa, pb, pc, pd]: [Vec3; 4]) {
+        let normal = (pc - pa).cross(pd - pb);
+        if normal.length_squared() <= f32::EPSILON * f32::EPSILON {
+            return;
+        }
+        let normal = normal.normalize();
+        let a = self.add_vertex(pa, normal);
+        let b = self.add_vertex(pb, normal);
+        let c = self.add_vertex(pc, normal);
+        let d = self.add_vertex(pd, normal);
+        self.indices.extend([a, b, c, a, c, d]);
+    }
+
+    /// Adds a cap using the triangulated profile, facing along `normal`.
+    fn add_cap(&mut self, ring: &[Vec3], triangles: &[usize], normal: Vec3) {
+        let offset = self.positions.len() as u32;
+        for position in ring.iter() {
+            self.add_vertex(*position, normal);
+        }
+        for triangle in triangles.chunks_exact(3) {
+            let [a, b, c] = [triangle[0], triangle[1], triangle[2]];
+            let face_normal = (ring[b] - ring[a]).cross(ring[c] - ring[a]);
+            let [a, b, c] = [a, b, c].map(|i| offset + i as u32);
+            if face_normal.dot(normal) >= 0. {
+                self.indices.extend([a, b, c]);
+            } else {
+                self.indices.extend([a, c, b]);
+            }
+        }
+    }
+
+    fn normalize_normals(&mut self) {
+        for normal in self.normals.iter_mut() {
+            *normal = normal.normalize_or_zero();
+        }
+    }
+
+    fn build(self) -> Mesh {
+        let num_vertices = self.positions.len();
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        );
+        mesh.insert_indices(Indices::U32(self.indices));
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            self.positions
+                .iter()
+                .map(|p| p.to_array())
+                .collect::<Vec<_>>(),
+        );
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_NORMAL,
+            self.normals
+                .iter()
+                .map(|n| n.to_array())
+                .collect::<Vec<_>>(),
+        );
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 0.0]; num_vertices]);
+        mesh
+    }
+}
+
+#[cfg(test)]
+fn mesh_signed_volume(mesh: &Mesh) -> f32 {
+    use bevy::render::mesh::VertexAttributeValues;
+
+    let Some(VertexAttributeValues::Float32x3(positions)) =
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+    else {
+        unreachable!()
+    };
+    let Some(Indices::U32(indices)) = mesh.indices() else {
+        unreachable!()
+    };
+    let mut volume = 0.;
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [triangle[0], triangle[1], triangle[2]]
+            .map(|i| Vec3::from_array(positions[i as usize]));
+        volume += a.dot(b.cross(c)) / 6.;
+    }
+    volume
+}
+
+#[test]
+fn test_lathe_full_revolve_volume() {
+    use std::f32::consts::PI;
+
+    // Rectangle profile: radius 1..2, height 3 along the axis.
+    let profile = vec![
+        Vec2::new(1., 0.),
+        Vec2::new(2., 0.),
+        Vec2::new(2., 3.),
+        Vec2::new(1., 3.),
+    ];
+    for smooth_normals in [true, false] {
+        let mesh = generate_mesh_lathe(
+            profile.clone(),
+            &LatheOptions {
+                segments: 256,
+                smooth_normals,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let expected = PI * (2. * 2. - 1. * 1.) * 3.;
+        let volume = mesh_signed_volume(&mesh);
+        // +ve volume means faces point outwards.
+        assert!((volume - expected).abs() < expected * 0.01, "{volume}");
+    }
+}
+
+#[test]
+fn test_lathe_partial_revolve_is_capped() {
+    use std::f32::consts::PI;
+
+    // CW profile should also result in outward faces.
+    let profile = vec![
+        Vec2::new(1., 0.),
+        Vec2::new(1., 3.),
+        Vec2::new(2., 3.),
+        Vec2::new(2., 0.),
+    ];
+    let mesh = generate_mesh_lathe(
+        profile,
+        &LatheOptions {
+            segments: 256,
+            sweep_angle: PI,
+            smooth_normals: false,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let expected = PI * (2. * 2. - 1. * 1.) * 3. / 2.;
+    let volume = mesh_signed_volume(&mesh);
+    assert!((volume - expected).abs() < expected * 0.01, "{volume}");
+}
+
+#[test]
+fn test_lathe_degenerate_input() {
+    let profile = vec![Vec2::new(1., 0.), Vec2::new(2., 0.)];
+    assert!(generate_mesh_lathe(profile, &LatheOptions::default()).is_none());
+    let profile = vec![Vec2::new(1., 0.), Vec2::new(2., 0.), Vec2::new(2., 1.)];
+    let options = LatheOptions {
+        axis: LatheAxis::new(Vec2::ZERO, Vec2::ZERO),
+        ..Default::default()
+    };
+    assert!(generate_mesh_lathe(profile, &options).is_none());
+}
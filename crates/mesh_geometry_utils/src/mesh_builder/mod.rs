@@ -1,3 +1,4 @@
 pub mod earcutr;
+pub mod lathe;
 #[cfg(feature = "bevy_prototype_lyon")]
 pub mod lyon;
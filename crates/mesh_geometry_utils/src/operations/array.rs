@@ -0,0 +1,125 @@
+//! Duplicating [`MeshPolygon`]s: translated, rotated and mirrored copies, linear and circular arrays.
+//!
+//! Copies keep the vertex ids of the original polygon.
+
+use crate::data_structures::{vertices::Vertex, MeshPolygon};
+
+use super::{geometry::map_points, symmetry::Symmetry};
+
+impl MeshPolygon {
+    /// Get a copy of the polygon moved by `offset`.
+    pub fn translated(&self, offset: Vertex) -> MeshPolygon {
+        map_points(self, |point| point + offset, false)
+    }
+
+    /// Get a copy of the polygon rotated by `angle` (radians, counter clockwise) around `center`.
+    pub fn rotated(&self, center: Vertex, angle: f32) -> MeshPolygon {
+        let rotation = Vertex::from_angle(angle);
+        map_points(
+            self,
+            |point| center + rotation.rotate(point - center),
+            false,
+        )
+    }
+
+    /// Get a copy of the polygon mirrored across the axis through `origin` along `direction`.
+    ///
+    /// The copy is [reversed](MeshPolygon::reverse) to keep the winding of the polygon.
+    pub fn mirrored(&self, origin: Vertex, direction: Vertex) -> MeshPolygon {
+        Symmetry::Mirror { origin, direction }.apply_to_polygon(self, 1)
+    }
+}
+
+/// Linear array of the polygon.
+///
+/// Returns the copies of the polygon moved by `spacing`, `2 * spacing`, ...,
+/// `(count - 1) * spacing`, `count` being the number of items including the original.
+pub fn linear_array(polygon: &MeshPolygon, count: usize, spacing: Vertex) -> Vec<MeshPolygon> {
+    (1..count)
+        .map(|index| polygon.translated(spacing * index as f32))
+        .collect()
+}
+
+/// Circular array of the polygon around `center`.
+///
+/// Returns the copies of the polygon rotated by `angle`, `2 * angle`, ...,
+/// `(count - 1) * angle` (radians, counter clockwise), `count` being the number
+/// of items including the original. Use `TAU / count` to spread the items over a full circle.
+pub fn circular_array(
+    polygon: &MeshPolygon,
+    center: Vertex,
+    count: usize,
+    angle: f32,
+) -> Vec<MeshPolygon> {
+    (1..count)
+        .map(|index| polygon.rotated(center, angle * index as f32))
+        .collect()
+}
+
+#[test]
+fn test_array() {
+    use std::f32::consts::{FRAC_PI_2, TAU};
+
+    use bevy::prelude::Vec2;
+
+    use crate::data_structures::EdgeCurve;
+
+    let mut polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(0., 1.),
+        Vec2::new(2., 1.),
+        Vec2::new(2., 0.),
+    ]);
+    let ids = polygon.vertices.ids().clone();
+    let edge = polygon
+        .edges
+        .filter_by_vertex(ids[2])
+        .last()
+        .cloned()
+        .unwrap();
+    polygon
+        .curves
+        .insert(edge.clone(), EdgeCurve::Arc { bulge: 0.5 });
+
+    // translated
+    let copy = polygon.translated(Vec2::new(3., 0.));
+    assert!(copy
+        .vertices
+        .get(ids[2])
+        .unwrap()
+        .abs_diff_eq(Vec2::new(5., 1.), 1e-6));
+    assert_eq!(copy.curves.get(&edge), polygon.curves.get(&edge));
+
+    // rotated
+    let copy = polygon.rotated(Vec2::ZERO, FRAC_PI_2);
+    assert!(copy
+        .vertices
+        .get(ids[3])
+        .unwrap()
+        .abs_diff_eq(Vec2::new(0., 2.), 1e-5));
+
+    // mirrored, winding and arc bulge kept
+    let copy = polygon.mirrored(Vec2::new(-1., 0.), Vec2::Y);
+    assert!(copy
+        .vertices
+        .get(ids[2])
+        .unwrap()
+        .abs_diff_eq(Vec2::new(-4., 1.), 1e-6));
+    assert!((copy.signed_area() - polygon.signed_area()).abs() < 1e-4);
+    assert!((copy.area() - polygon.area()).abs() < 1e-4);
+
+    // arrays
+    let copies = linear_array(&polygon, 4, Vec2::new(0., 2.));
+    assert_eq!(copies.len(), 3);
+    assert!(copies[2]
+        .vertices
+        .get(ids[0])
+        .unwrap()
+        .abs_diff_eq(Vec2::new(0., 6.), 1e-6));
+    let copies = circular_array(&polygon, Vec2::new(-1., 0.), 6, TAU / 6.);
+    assert_eq!(copies.len(), 5);
+    for copy in copies {
+        assert!((copy.area() - polygon.area()).abs() < 1e-4);
+    }
+    assert!(linear_array(&polygon, 1, Vec2::X).is_empty());
+}
@@ -0,0 +1,457 @@
+//! Geometric constraints on the edges and vertices of [`MeshPolygon`]s.
+//!
+//! Constraints are solved iteratively by projecting the vertices of each
+//! constraint in turn (Gauss-Seidel), until all of them are satisfied or the
+//! iterations run out. Locked vertices (eg: the dragged vertex) and vertices
+//! with a [`Constraint::FixedPosition`] are never moved by the other constraints.
+
+use bevy::utils::HashSet;
+
+use crate::data_structures::{vertices::Vertex, Edge, MeshPolygon, VertexId};
+
+/// Geometric constraint on a polygon.
+///
+/// Angles are in radians, measured from the canvas `x` axis towards the `z` axis.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constraint {
+    /// Edge keeps its length.
+    FixedLength {
+        /// Constrained edge.
+        edge: Edge,
+        /// Length of the edge.
+        length: f32,
+    },
+    /// Edge keeps its direction (`from` -> `to`).
+    FixedAngle {
+        /// Constrained edge.
+        edge: Edge,
+        /// Angle of the edge direction.
+        angle: f32,
+    },
+    /// Edges are parallel (in either direction).
+    Parallel(Edge, Edge),
+    /// Edges are perpendicular.
+    Perpendicular(Edge, Edge),
+    /// Edge is parallel to the canvas `x` axis.
+    Horizontal(Edge),
+    /// Edge is parallel to the canvas `z` axis.
+    Vertical(Edge),
+    /// Vertex stays at a position.
+    ///
+    /// Also used for vertices coincident with (a vertex of) another polygon.
+    FixedPosition {
+        /// Constrained vertex.
+        vertex: VertexId,
+        /// Position of the vertex.
+        position: Vertex,
+    },
+}
+
+impl Constraint {
+    /// Edges the constraint is attached to.
+    pub fn edges(&self) -> Vec<&Edge> {
+        match self {
+            Self::FixedLength { edge, .. }
+            | Self::FixedAngle { edge, .. }
+            | Self::Horizontal(edge)
+            | Self::Vertical(edge) => vec![edge],
+            Self::Parallel(edge_a, edge_b) | Self::Perpendicular(edge_a, edge_b) => {
+                vec![edge_a, edge_b]
+            }
+            Self::FixedPosition { .. } => vec![],
+        }
+    }
+
+    /// Check if all the edges/vertices of the constraint exist in the polygon.
+    pub fn is_valid_for(&self, polygon: &MeshPolygon) -> bool {
+        match self {
+            Self::FixedPosition { vertex, .. } => polygon.vertices.get(*vertex).is_some(),
+            _ => self
+                .edges()
+                .into_iter()
+                .all(|edge| polygon.edges.contains(edge)),
+        }
+    }
+
+    /// Error of the constraint, in polygon units (0 when satisfied).
+    pub fn residual(&self, polygon: &MeshPolygon) -> f32 {
+        let direction = |edge: &Edge| -> Option<Vertex> {
+            Some(*polygon.vertices.get(edge.to)? - *polygon.vertices.get(edge.from)?)
+        };
+        let residual = match self {
+            Self::FixedLength { edge, length } => {
+                direction(edge).map(|dir| (dir.length() - length).abs())
+            }
+            Self::FixedAngle { edge, angle } => {
+                direction(edge).map(|dir| dir.distance(Vertex::from_angle(*angle) * dir.length()))
+            }
+            Self::Horizontal(edge) => direction(edge).map(|dir| dir.y.abs()),
+            Self::Vertical(edge) => direction(edge).map(|dir| dir.x.abs()),
+            Self::Parallel(edge_a, edge_b) => direction(edge_a)
+                .zip(direction(edge_b))
+                .map(|(dir_a, dir_b)| dir_a.normalize_or_zero().perp_dot(dir_b).abs()),
+            Self::Perpendicular(edge_a, edge_b) => direction(edge_a)
+                .zip(direction(edge_b))
+                .map(|(dir_a, dir_b)| dir_a.normalize_or_zero().dot(dir_b).abs()),
+            Self::FixedPosition { vertex, position } => polygon
+                .vertices
+                .get(*vertex)
+                .map(|vert| vert.distance(*position)),
+        };
+        residual.unwrap_or(0.)
+    }
+}
+
+/// Options of the constraint solver.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverOptions {
+    /// Max number of iterations.
+    pub iterations: u32,
+    /// Max residual of a satisfied constraint.
+    pub tolerance: f32,
+}
+
+impl Default for SolverOptions {
+    fn default() -> Self {
+        Self {
+            iterations: 64,
+            tolerance: 1e-3,
+        }
+    }
+}
+
+/// Result of solving the constraints of a polygon.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SolveResult {
+    /// Number of iterations run.
+    pub iterations: u32,
+    /// Indices of the constraints left unsatisfied (conflicting).
+    pub conflicts: Vec<usize>,
+    /// Vertices moved by the solver.
+    pub moved_vertices: Vec<VertexId>,
+}
+
+impl SolveResult {
+    /// Check if all the constraints are satisfied.
+    pub fn is_solved(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// Solve the constraints by moving the vertices of the polygon.
+///
+/// `locked` vertices are not moved (except by their own [`Constraint::FixedPosition`]).
+/// Constraints on missing edges/vertices are skipped.
+pub fn solve_constraints(
+    polygon: &mut MeshPolygon,
+    constraints: &[Constraint],
+    locked: &[VertexId],
+    options: &SolverOptions,
+) -> SolveResult {
+    let constraints: Vec<(usize, &Constraint)> = constraints
+        .iter()
+        .enumerate()
+        .filter(|(_, constraint)| constraint.is_valid_for(polygon))
+        .collect();
+    let mut pinned: HashSet<VertexId> = locked.iter().cloned().collect();
+    pinned.extend(
+        constraints
+            .iter()
+            .filter_map(|(_, constraint)| match constraint {
+                Constraint::FixedPosition { vertex, .. } => Some(*vertex),
+                _ => None,
+            }),
+    );
+    let original = polygon.vertices.clone();
+    let mut result = SolveResult::default();
+    let is_satisfied = |polygon: &MeshPolygon| {
+        constraints
+            .iter()
+            .all(|(_, constraint)| constraint.residual(polygon) <= options.tolerance)
+    };
+    while result.iterations < options.iterations && !is_satisfied(polygon) {
+        for (_, constraint) in constraints.iter() {
+            project_constraint(polygon, constraint, &pinned);
+        }
+        result.iterations += 1;
+    }
+    result.conflicts = constraints
+        .iter()
+        .filter(|(_, constraint)| constraint.residual(polygon) > options.tolerance)
+        .map(|(index, _)| *index)
+        .collect();
+    result.moved_vertices = polygon
+        .vertices
+        .ids()
+        .iter()
+        .filter(|id| original.get(**id) != polygon.vertices.get(**id))
+        .cloned()
+        .collect();
+    result
+}
+
+/// Move the vertices of the polygon to satisfy the constraint.
+fn project_constraint(
+    polygon: &mut MeshPolygon,
+    constraint: &Constraint,
+    pinned: &HashSet<VertexId>,
+) {
+    match constraint {
+        Constraint::FixedLength { edge, length } => {
+            let Some((from_vert, to_vert)) = edge_vertices(polygon, edge) else {
+                return;
+            };
+            let (from_weight, to_weight) = edge_weights(edge, pinned);
+            let direction = to_vert - from_vert;
+            let current_length = direction.length();
+            if from_weight + to_weight == 0. || current_length <= f32::EPSILON {
+                return;
+            }
+            let correction = direction * (current_length - length) / current_length;
+            let total_weight = from_weight + to_weight;
+            set_edge_vertices(
+                polygon,
+                edge,
+                from_vert + correction * from_weight / total_weight,
+                to_vert - correction * to_weight / total_weight,
+            );
+        }
+        Constraint::FixedAngle { edge, angle } => {
+            align_edge(polygon, edge, Vertex::from_angle(*angle), pinned);
+        }
+        Constraint::Horizontal(edge) => {
+            if let Some((from_vert, to_vert)) = edge_vertices(polygon, edge) {
+                let sign = if to_vert.x < from_vert.x { -1. } else { 1. };
+                align_edge(polygon, edge, Vertex::X * sign, pinned);
+            }
+        }
+        Constraint::Vertical(edge) => {
+            if let Some((from_vert, to_vert)) = edge_vertices(polygon, edge) {
+                let sign = if to_vert.y < from_vert.y { -1. } else { 1. };
+                align_edge(polygon, edge, Vertex::Y * sign, pinned);
+            }
+        }
+        Constraint::Parallel(edge_a, edge_b) => {
+            align_edge_pair(polygon, edge_a, edge_b, false, pinned);
+        }
+        Constraint::Perpendicular(edge_a, edge_b) => {
+            align_edge_pair(polygon, edge_a, edge_b, true, pinned);
+        }
+        Constraint::FixedPosition { vertex, position } => {
+            polygon.move_vertex(*vertex, *position);
+        }
+    }
+}
+
+/// Rotate the edge (around its weighted center) to the `direction`, keeping its length.
+fn align_edge(
+    polygon: &mut MeshPolygon,
+    edge: &Edge,
+    direction: Vertex,
+    pinned: &HashSet<VertexId>,
+) {
+    let Some((from_vert, to_vert)) = edge_vertices(polygon, edge) else {
+        return;
+    };
+    let (from_weight, to_weight) = edge_weights(edge, pinned);
+    let total_weight = from_weight + to_weight;
+    if total_weight == 0. {
+        return;
+    }
+    let length = from_vert.distance(to_vert);
+    // pivot around the less movable vertex
+    let pivot = (from_vert * to_weight + to_vert * from_weight) / total_weight;
+    let aligned = direction * length;
+    set_edge_vertices(
+        polygon,
+        edge,
+        pivot - aligned * from_weight / total_weight,
+        pivot + aligned * to_weight / total_weight,
+    );
+}
+
+/// Align both edges to a common direction (rotated by 90 degrees for the
+/// second one when `is_perpendicular`), keeping their lengths and senses.
+///
+/// The less movable edge weighs more in the common direction.
+fn align_edge_pair(
+    polygon: &mut MeshPolygon,
+    edge_a: &Edge,
+    edge_b: &Edge,
+    is_perpendicular: bool,
+    pinned: &HashSet<VertexId>,
+) {
+    let (Some((from_a, to_a)), Some((from_b, to_b))) = (
+        edge_vertices(polygon, edge_a),
+        edge_vertices(polygon, edge_b),
+    ) else {
+        return;
+    };
+    let (Some(dir_a), Some(dir_b)) = (
+        (to_a - from_a).try_normalize(),
+        (to_b - from_b).try_normalize(),
+    ) else {
+        return;
+    };
+    // direction of b expressed in the frame of a
+    let dir_b_in_a = if is_perpendicular {
+        -dir_b.perp()
+    } else {
+        dir_b
+    };
+    let dir_b_in_a = if dir_b_in_a.dot(dir_a) < 0. {
+        -dir_b_in_a
+    } else {
+        dir_b_in_a
+    };
+    let mobility_a = sum_weights(edge_weights(edge_a, pinned));
+    let mobility_b = sum_weights(edge_weights(edge_b, pinned));
+    if mobility_a + mobility_b == 0. {
+        return;
+    }
+    let Some(common) = (dir_a * mobility_b + dir_b_in_a * mobility_a).try_normalize() else {
+        return;
+    };
+    align_edge(polygon, edge_a, common, pinned);
+    // back to the frame of b, keeping its sense
+    let target_b = if is_perpendicular {
+        common.perp()
+    } else {
+        common
+    };
+    let target_b = if target_b.dot(dir_b) < 0. {
+        -target_b
+    } else {
+        target_b
+    };
+    align_edge(polygon, edge_b, target_b, pinned);
+}
+
+/// Positions of the `from`/`to` vertices of the edge.
+fn edge_vertices(polygon: &MeshPolygon, edge: &Edge) -> Option<(Vertex, Vertex)> {
+    Some((
+        *polygon.vertices.get(edge.from)?,
+        *polygon.vertices.get(edge.to)?,
+    ))
+}
+
+/// Set the positions of the `from`/`to` vertices of the edge.
+fn set_edge_vertices(polygon: &mut MeshPolygon, edge: &Edge, from_vert: Vertex, to_vert: Vertex) {
+    polygon.move_vertex(edge.from, from_vert);
+    polygon.move_vertex(edge.to, to_vert);
+}
+
+/// Movability (inverse mass) of the `from`/`to` vertices of the edge.
+fn edge_weights(edge: &Edge, pinned: &HashSet<VertexId>) -> (f32, f32) {
+    let weight = |id: &VertexId| if pinned.contains(id) { 0. } else { 1. };
+    (weight(&edge.from), weight(&edge.to))
+}
+
+/// Sum of the `from`/`to` weights.
+fn sum_weights((from_weight, to_weight): (f32, f32)) -> f32 {
+    from_weight + to_weight
+}
+
+#[test]
+fn test_solve_constraints() {
+    use bevy::prelude::Vec2;
+
+    let mut polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(4., 0.),
+        Vec2::new(4., 3.),
+        Vec2::new(0., 3.),
+    ]);
+    let ids = polygon.vertices.ids().clone();
+    let vert = |polygon: &MeshPolygon, idx: usize| *polygon.vertices.get(ids[idx]).unwrap();
+    let bottom = Edge::new(ids[0], ids[1]);
+    let right = Edge::new(ids[1], ids[2]);
+    let top = Edge::new(ids[2], ids[3]);
+    let constraints = vec![
+        Constraint::FixedLength {
+            edge: bottom.clone(),
+            length: 4.,
+        },
+        Constraint::Horizontal(bottom.clone()),
+        Constraint::Perpendicular(bottom.clone(), right.clone()),
+        Constraint::Parallel(bottom.clone(), top.clone()),
+        Constraint::FixedPosition {
+            vertex: ids[0],
+            position: Vec2::new(0., 0.),
+        },
+    ];
+    // drag the bottom right vertex away
+    polygon.move_vertex(ids[1], Vec2::new(5., 1.));
+    let result = solve_constraints(&mut polygon, &constraints, &[], &SolverOptions::default());
+    assert!(result.is_solved());
+    assert!(result.moved_vertices.contains(&ids[1]));
+    assert!(vert(&polygon, 0).abs_diff_eq(Vec2::ZERO, 1e-5));
+    assert!(vert(&polygon, 1).abs_diff_eq(Vec2::new(4., 0.), 1e-2));
+    assert!((vert(&polygon, 2).x - vert(&polygon, 1).x).abs() < 1e-2);
+    assert!((vert(&polygon, 3).y - vert(&polygon, 2).y).abs() < 1e-2);
+
+    // locked (dragged) vertex stays, the rest follows
+    let constraints = vec![
+        Constraint::FixedLength {
+            edge: bottom.clone(),
+            length: 4.,
+        },
+        Constraint::Vertical(right.clone()),
+    ];
+    polygon.move_vertex(ids[1], Vec2::new(4., 1.));
+    let result = solve_constraints(
+        &mut polygon,
+        &constraints,
+        &[ids[1]],
+        &SolverOptions::default(),
+    );
+    assert!(result.is_solved());
+    assert_eq!(vert(&polygon, 1), Vec2::new(4., 1.));
+    assert!((vert(&polygon, 0).distance(vert(&polygon, 1)) - 4.).abs() < 1e-2);
+    assert!((vert(&polygon, 2).x - 4.).abs() < 1e-2);
+
+    // fixed angle
+    let constraints = vec![Constraint::FixedAngle {
+        edge: top.clone(),
+        angle: std::f32::consts::PI,
+    }];
+    let result = solve_constraints(&mut polygon, &constraints, &[], &SolverOptions::default());
+    assert!(result.is_solved());
+    assert!(
+        (vert(&polygon, 3).y - vert(&polygon, 2).y).abs() < 1e-2
+            && vert(&polygon, 3).x < vert(&polygon, 2).x
+    );
+}
+
+#[test]
+fn test_solve_constraints_conflicts() {
+    use bevy::prelude::Vec2;
+
+    let mut polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(4., 0.),
+        Vec2::new(4., 3.),
+    ]);
+    let ids = polygon.vertices.ids().clone();
+    let edge = Edge::new(ids[0], ids[1]);
+    // both ends fixed, length can not be satisfied
+    let constraints = vec![
+        Constraint::FixedPosition {
+            vertex: ids[0],
+            position: Vec2::new(0., 0.),
+        },
+        Constraint::FixedPosition {
+            vertex: ids[1],
+            position: Vec2::new(4., 0.),
+        },
+        Constraint::FixedLength {
+            edge: edge.clone(),
+            length: 2.,
+        },
+        // stale constraint, skipped
+        Constraint::Horizontal(Edge::new(ids[0], ids[2])),
+    ];
+    let result = solve_constraints(&mut polygon, &constraints, &[], &SolverOptions::default());
+    assert_eq!(result.conflicts, vec![2]);
+    assert!(result.moved_vertices.is_empty());
+}
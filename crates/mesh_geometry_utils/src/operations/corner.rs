@@ -0,0 +1,248 @@
+//! Fillet (round) and chamfer (cut) of [`MeshPolygon`] corners.
+//!
+//! Both replace a vertex between two straight edges. Sizes that don't fit the
+//! adjacent edges are clamped, which is reported in the [`CornerResult`].
+
+use bevy::prelude::Vec2;
+
+use crate::data_structures::{vertices::Vertex, Edge, MeshPolygon, VertexId};
+
+/// Distance below which generated points are merged with the neighboring vertices.
+const MERGE_DISTANCE: f32 = 1e-4;
+
+/// Result of a corner operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CornerResult {
+    /// Ids of the vertices replacing the corner, in order.
+    pub vertex_ids: Vec<VertexId>,
+    /// Applied size (radius for fillets, distance for chamfers).
+    pub size: f32,
+    /// If the requested size did not fit and was clamped.
+    pub is_clamped: bool,
+}
+
+/// Round the corner at the vertex with an arc of `segments` (+ 1) vertices of given `radius`.
+///
+/// The arc is tangent to both adjacent edges.
+/// Returns `None` if the corner can't be rounded (curved/collinear adjacent edges, etc)
+/// or rounding it would collapse a triangle.
+pub fn fillet_vertex(
+    polygon: &mut MeshPolygon,
+    vertex_id: VertexId,
+    radius: f32,
+    segments: u32,
+) -> Option<CornerResult> {
+    let corner = Corner::new(polygon, vertex_id)?;
+    if radius <= 0. || segments == 0 {
+        return None;
+    }
+    let half_angle = corner.angle() / 2.;
+    let tan_half_angle = half_angle.tan();
+    // distance of tangent points from the corner
+    let requested_distance = radius / tan_half_angle;
+    let distance = requested_distance.min(corner.max_distance());
+    let radius = distance * tan_half_angle;
+    let start = corner.vertex + corner.to_prev * distance;
+    let end = corner.vertex + corner.to_next * distance;
+    let bisector = (corner.to_prev + corner.to_next).normalize();
+    let center = corner.vertex + bisector * (radius / half_angle.sin());
+    let (from_center, to_center) = (start - center, end - center);
+    let sweep = from_center
+        .perp_dot(to_center)
+        .atan2(from_center.dot(to_center));
+    let arc = (0..=segments)
+        .map(|index| {
+            let rotation = Vec2::from_angle(sweep * index as f32 / segments as f32);
+            center + rotation.rotate(from_center)
+        })
+        .collect::<Vec<_>>();
+    let vertex_ids = corner.replace(polygon, arc)?;
+    Some(CornerResult {
+        vertex_ids,
+        size: radius,
+        is_clamped: distance < requested_distance,
+    })
+}
+
+/// Cut the corner at the vertex with a single edge, `distance` away from it along both edges.
+///
+/// Returns `None` if the corner can't be cut (curved/collinear adjacent edges, etc)
+/// or cutting it would collapse a triangle.
+pub fn chamfer_vertex(
+    polygon: &mut MeshPolygon,
+    vertex_id: VertexId,
+    distance: f32,
+) -> Option<CornerResult> {
+    let corner = Corner::new(polygon, vertex_id)?;
+    if distance <= 0. {
+        return None;
+    }
+    let applied_distance = distance.min(corner.max_distance());
+    let cut = vec![
+        corner.vertex + corner.to_prev * applied_distance,
+        corner.vertex + corner.to_next * applied_distance,
+    ];
+    let vertex_ids = corner.replace(polygon, cut)?;
+    Some(CornerResult {
+        vertex_ids,
+        size: applied_distance,
+        is_clamped: applied_distance < distance,
+    })
+}
+
+/// Corner at a vertex between 2 straight edges.
+struct Corner {
+    vertex_id: VertexId,
+    vertex: Vertex,
+    prev: Vertex,
+    next: Vertex,
+    /// Unit direction towards the previous vertex.
+    to_prev: Vec2,
+    /// Unit direction towards the next vertex.
+    to_next: Vec2,
+}
+
+impl Corner {
+    fn new(polygon: &MeshPolygon, vertex_id: VertexId) -> Option<Self> {
+        let ids = polygon.vertices.ids();
+        if ids.len() < 3 {
+            return None;
+        }
+        let idx = ids.iter().position(|id| *id == vertex_id)?;
+        let prev_id = ids[(idx + ids.len() - 1) % ids.len()];
+        let next_id = ids[(idx + 1) % ids.len()];
+        let (prev_edge, next_edge) = (Edge::new(prev_id, vertex_id), Edge::new(vertex_id, next_id));
+        if !polygon.edges.contains(&prev_edge)
+            || !polygon.edges.contains(&next_edge)
+            || polygon.edge_curve(&prev_edge).is_some()
+            || polygon.edge_curve(&next_edge).is_some()
+        {
+            return None;
+        }
+        let vertex = *polygon.vertices.get(vertex_id)?;
+        let prev = *polygon.vertices.get(prev_id)?;
+        let next = *polygon.vertices.get(next_id)?;
+        let to_prev = (prev - vertex).try_normalize()?;
+        let to_next = (next - vertex).try_normalize()?;
+        // collinear (straight or folded back) corners can't be rounded/cut
+        if to_prev.perp_dot(to_next).abs() <= 1e-5 {
+            return None;
+        }
+        Some(Self {
+            vertex_id,
+            vertex,
+            prev,
+            next,
+            to_prev,
+            to_next,
+        })
+    }
+
+    /// Interior angle (radians) between the edges.
+    fn angle(&self) -> f32 {
+        self.to_prev.angle_between(self.to_next).abs()
+    }
+
+    /// Max distance from the corner that fits on both adjacent edges.
+    fn max_distance(&self) -> f32 {
+        self.vertex
+            .distance(self.prev)
+            .min(self.vertex.distance(self.next))
+    }
+
+    /// Replace the corner vertex with the points, merging points at the neighbors.
+    ///
+    /// Returns the ids of the new vertices, `None` (polygon unchanged) if the
+    /// corner would be cut completely from a triangle.
+    fn replace(&self, polygon: &mut MeshPolygon, points: Vec<Vertex>) -> Option<Vec<VertexId>> {
+        let points = points
+            .into_iter()
+            .filter(|point| {
+                point.distance(self.prev) > MERGE_DISTANCE
+                    && point.distance(self.next) > MERGE_DISTANCE
+            })
+            .collect::<Vec<_>>();
+        if points.is_empty() {
+            // corner is cut completely, polygon needs at least 3 vertices left
+            if polygon.vertices.len() <= 3 {
+                return None;
+            }
+            polygon.remove_vertex(self.vertex_id);
+            return Some(vec![]);
+        }
+        Some(polygon.replace_vertex(self.vertex_id, points))
+    }
+}
+
+#[test]
+fn test_fillet_vertex() {
+    let mut polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(2., 0.),
+        Vec2::new(2., 2.),
+        Vec2::new(0., 2.),
+    ]);
+    let corner_id = polygon.vertices.ids()[1];
+    let result = fillet_vertex(&mut polygon, corner_id, 0.5, 4).unwrap();
+    assert!(!result.is_clamped);
+    assert_eq!(result.vertex_ids.len(), 5);
+    assert_eq!(polygon.vertices.len(), 8);
+    assert_eq!(polygon.edges.len(), 8);
+    assert!(polygon.vertices.get(corner_id).is_none());
+    // arc points are at radius from center, tangent points on the edges
+    let center = Vec2::new(1.5, 0.5);
+    for id in result.vertex_ids.iter() {
+        let vertex = polygon.vertices.get(*id).unwrap();
+        assert!((vertex.distance(center) - 0.5).abs() < 1e-5);
+    }
+    let first = polygon.vertices.get(result.vertex_ids[0]).unwrap();
+    assert!(first.abs_diff_eq(Vec2::new(1.5, 0.), 1e-5));
+
+    // too large radius is clamped to the edge length
+    let corner_id = polygon.vertices.ids()[6];
+    let result = fillet_vertex(&mut polygon, corner_id, 5., 4).unwrap();
+    assert!(result.is_clamped);
+    assert!((result.size - 1.5).abs() < 1e-5);
+}
+
+#[test]
+fn test_chamfer_vertex() {
+    let mut polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(2., 0.),
+        Vec2::new(2., 2.),
+        Vec2::new(0., 2.),
+    ]);
+    let corner_id = polygon.vertices.ids()[2];
+    let result = chamfer_vertex(&mut polygon, corner_id, 0.5).unwrap();
+    assert!(!result.is_clamped);
+    assert_eq!(polygon.vertices.len(), 5);
+    assert_eq!(polygon.edges.len(), 5);
+    let vertices = polygon.vertices.get_all_owned();
+    assert!(vertices[2].abs_diff_eq(Vec2::new(2., 1.5), 1e-5));
+    assert!(vertices[3].abs_diff_eq(Vec2::new(1.5, 2.), 1e-5));
+
+    // clamped chamfer over the whole edge merges into the neighbor
+    let corner_id = polygon.vertices.ids()[1];
+    let result = chamfer_vertex(&mut polygon, corner_id, 10.).unwrap();
+    assert!(result.is_clamped);
+    assert_eq!(result.vertex_ids.len(), 1);
+    assert_eq!(polygon.vertices.len(), 5);
+    assert_eq!(polygon.edges.len(), 5);
+}
+
+#[test]
+fn test_corner_keeps_triangle() {
+    // isosceles triangle, both edges at the corner are 2 long
+    let mut polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(2., 0.),
+        Vec2::new(0., 2.),
+    ]);
+    let corner_id = polygon.vertices.ids()[0];
+    // cut past the edge length, both points land on the neighbors
+    assert!(chamfer_vertex(&mut polygon, corner_id, 5.).is_none());
+    assert_eq!(polygon.vertices.len(), 3);
+    assert_eq!(polygon.edges.len(), 3);
+    assert!(polygon.vertices.get(corner_id).is_some());
+}
@@ -0,0 +1,133 @@
+//! Geometry helpers shared by the polygon operations.
+
+use crate::data_structures::{vertices::Vertex, Edge, EdgeCurve, MeshPolygon};
+
+/// Check if the polygon is closed (edge count matches vertex count).
+pub(crate) fn is_closed(polygon: &MeshPolygon) -> bool {
+    polygon.vertices.len() > 2 && polygon.edges.len() == polygon.vertices.len()
+}
+
+/// Edges of the polygon in vertex order.
+pub(crate) fn ring_edges(polygon: &MeshPolygon) -> Vec<Edge> {
+    let ids = polygon.vertices.ids();
+    (0..ids.len())
+        .map(|idx| Edge::new(ids[idx], ids[(idx + 1) % ids.len()]))
+        .filter(|edge| polygon.edges.contains(edge))
+        .collect()
+}
+
+/// Nearest point to `point` on the segment `start` -> `end`.
+pub(crate) fn nearest_point_on_segment(point: Vertex, start: Vertex, end: Vertex) -> Vertex {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+    if length_squared <= f32::EPSILON {
+        return start;
+    }
+    let t = ((point - start).dot(segment) / length_squared).clamp(0., 1.);
+    start + segment * t
+}
+
+/// Even-odd point in polygon test.
+pub(crate) fn is_inside(outline: &[Vertex], point: Vertex) -> bool {
+    let mut inside = false;
+    for (idx, a) in outline.iter().enumerate() {
+        let b = outline[(idx + 1) % outline.len()];
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Check if the segment properly crosses (not just touches) any outline segment.
+pub(crate) fn crosses_outline(outline: &[Vertex], start: Vertex, end: Vertex) -> bool {
+    let orientation = |a: Vertex, b: Vertex, c: Vertex| {
+        let cross = (b - a).perp_dot(c - a);
+        if cross.abs() <= 1e-6 {
+            0.
+        } else {
+            cross.signum()
+        }
+    };
+    outline.iter().enumerate().any(|(idx, a)| {
+        let b = outline[(idx + 1) % outline.len()];
+        let (o1, o2) = (orientation(start, end, *a), orientation(start, end, b));
+        let (o3, o4) = (orientation(*a, b, start), orientation(*a, b, end));
+        o1 * o2 < 0. && o3 * o4 < 0.
+    })
+}
+
+/// Signed area of the closed outline (shoelace), positive when counter clockwise.
+pub(crate) fn signed_area(vertices: &[Vertex]) -> f32 {
+    let mut area = 0.;
+    for (index, vertex) in vertices.iter().enumerate() {
+        let next = vertices[(index + 1) % vertices.len()];
+        area += vertex.perp_dot(next);
+    }
+    area / 2.
+}
+
+/// Distance from the point to the nearest outline segment.
+pub(crate) fn distance_to_outline(outline: &[Vertex], point: Vertex) -> f32 {
+    outline
+        .iter()
+        .enumerate()
+        .map(|(idx, a)| {
+            let b = outline[(idx + 1) % outline.len()];
+            nearest_point_on_segment(point, *a, b).distance(point)
+        })
+        .fold(f32::MAX, f32::min)
+}
+
+/// Signed distance from the point to the outline, positive inside.
+pub(crate) fn signed_distance_to_outline(outline: &[Vertex], point: Vertex) -> f32 {
+    let distance = distance_to_outline(outline, point);
+    if is_inside(outline, point) {
+        distance
+    } else {
+        -distance
+    }
+}
+
+/// Points of the edge from `from` to `to`, with a curved edge flattened.
+pub(crate) fn edge_polyline(polygon: &MeshPolygon, edge: &Edge) -> Option<Vec<Vertex>> {
+    let from_vert = *polygon.vertices.get(edge.from)?;
+    let to_vert = *polygon.vertices.get(edge.to)?;
+    let mut points = vec![from_vert];
+    if let Some(curve) = polygon.edge_curve(edge) {
+        points.extend(curve.flatten(from_vert, to_vert, polygon.flatten_tolerance));
+    }
+    points.push(to_vert);
+    Some(points)
+}
+
+/// Map the vertices and curve control points of the polygon.
+///
+/// For reflections (`is_reflection`), arcs are flipped and the polygon is
+/// reversed to keep its winding.
+pub(crate) fn map_points(
+    polygon: &MeshPolygon,
+    map: impl Fn(Vertex) -> Vertex,
+    is_reflection: bool,
+) -> MeshPolygon {
+    let mut mapped = polygon.clone();
+    for id in polygon.vertices.ids() {
+        if let Some(vertex) = mapped.vertices.get_mut(*id) {
+            *vertex = map(*vertex);
+        }
+    }
+    for curve in mapped.curves.0.values_mut() {
+        *curve = match *curve {
+            EdgeCurve::QuadraticBezier(ctrl) => EdgeCurve::QuadraticBezier(map(ctrl)),
+            EdgeCurve::CubicBezier(ctrl1, ctrl2) => EdgeCurve::CubicBezier(map(ctrl1), map(ctrl2)),
+            EdgeCurve::Arc { bulge } if is_reflection => EdgeCurve::Arc { bulge: -bulge },
+            curve => curve,
+        };
+    }
+    if is_reflection {
+        mapped.reverse();
+    }
+    mapped
+}
@@ -0,0 +1,413 @@
+//! Measurements of [`MeshPolygon`]s and the solids generated from them.
+//!
+//! Measurements use the [outline](MeshPolygon::outline), so curved edges are
+//! measured along their flattened curves.
+
+use std::{cmp::Ordering, collections::BinaryHeap, f32::consts::TAU};
+
+use bevy::{math::bounding::Aabb2d, prelude::Vec2};
+
+use crate::{
+    data_structures::{vertices::Vertex, Edge, MeshPolygon},
+    mesh_builder::lathe::LatheOptions,
+};
+
+use super::geometry::{ring_edges, signed_area, signed_distance_to_outline};
+
+/// Rectangle rotated around its center.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrientedRect {
+    /// Center of the rectangle.
+    pub center: Vec2,
+    /// Half of the size along the rotated x/y axes.
+    pub half_size: Vec2,
+    /// Rotation (radians) of the rectangle's x axis.
+    pub rotation: f32,
+}
+
+impl OrientedRect {
+    /// Corners of the rectangle, counter clockwise.
+    pub fn corners(&self) -> [Vec2; 4] {
+        let rotation = Vec2::from_angle(self.rotation);
+        [
+            Vec2::new(-1., -1.),
+            Vec2::new(1., -1.),
+            Vec2::new(1., 1.),
+            Vec2::new(-1., 1.),
+        ]
+        .map(|corner| self.center + rotation.rotate(corner * self.half_size))
+    }
+
+    /// Area of the rectangle.
+    pub fn area(&self) -> f32 {
+        4. * self.half_size.x * self.half_size.y
+    }
+}
+
+impl MeshPolygon {
+    /// Signed area of the polygon, positive when counter clockwise.
+    pub fn signed_area(&self) -> f32 {
+        signed_area(&self.outline())
+    }
+
+    /// Area of the polygon.
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    /// Length of each edge of the polygon, in order.
+    ///
+    /// Curved edges are measured along their curve.
+    pub fn edge_lengths(&self) -> Vec<(Edge, f32)> {
+        ring_edges(self)
+            .into_iter()
+            .filter_map(|edge| {
+                let from_vert = *self.vertices.get(edge.from)?;
+                let to_vert = *self.vertices.get(edge.to)?;
+                let mut points = vec![from_vert];
+                if let Some(curve) = self.edge_curve(&edge) {
+                    points.extend(curve.flatten(from_vert, to_vert, self.flatten_tolerance));
+                }
+                points.push(to_vert);
+                let length = points
+                    .windows(2)
+                    .map(|pair| pair[0].distance(pair[1]))
+                    .sum();
+                Some((edge, length))
+            })
+            .collect()
+    }
+
+    /// Perimeter (total length of the edges) of the polygon.
+    pub fn perimeter(&self) -> f32 {
+        self.edge_lengths().iter().map(|(_, length)| length).sum()
+    }
+
+    /// Centroid (center of mass) of the polygon area.
+    ///
+    /// Returns `None` for polygons without area.
+    pub fn centroid(&self) -> Option<Vec2> {
+        let outline = self.outline();
+        let area = signed_area(&outline);
+        if area.abs() <= f32::EPSILON {
+            return None;
+        }
+        let mut centroid = Vec2::ZERO;
+        for (idx, a) in outline.iter().enumerate() {
+            let b = outline[(idx + 1) % outline.len()];
+            centroid += (*a + b) * a.perp_dot(b);
+        }
+        Some(centroid / (6. * area))
+    }
+
+    /// Axis aligned bounding box of the polygon.
+    pub fn aabb(&self) -> Option<Aabb2d> {
+        let outline = self.outline();
+        let first = *outline.first()?;
+        let (min, max) = outline.iter().fold((first, first), |(min, max), point| {
+            (min.min(*point), max.max(*point))
+        });
+        Some(Aabb2d { min, max })
+    }
+
+    /// Minimum area bounding rectangle of the polygon, using rotating calipers.
+    pub fn min_oriented_bounding_rect(&self) -> Option<OrientedRect> {
+        let hull = convex_hull(self.outline());
+        if hull.len() < 3 {
+            return None;
+        }
+        let mut best: Option<OrientedRect> = None;
+        for (idx, a) in hull.iter().enumerate() {
+            let Some(axis) = (hull[(idx + 1) % hull.len()] - *a).try_normalize() else {
+                continue;
+            };
+            let normal = axis.perp();
+            let (mut min, mut max) = (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN));
+            for point in hull.iter() {
+                let projected = Vec2::new(point.dot(axis), point.dot(normal));
+                min = min.min(projected);
+                max = max.max(projected);
+            }
+            let half_size = (max - min) / 2.;
+            let center_local = (min + max) / 2.;
+            let rect = OrientedRect {
+                center: axis * center_local.x + normal * center_local.y,
+                half_size,
+                rotation: axis.to_angle(),
+            };
+            if !matches!(best, Some(best) if best.area() <= rect.area()) {
+                best = Some(rect);
+            }
+        }
+        best
+    }
+
+    /// Pole of inaccessibility, the point inside the polygon farthest from its boundary.
+    ///
+    /// Useful to place labels. Found using the polylabel algorithm up to the given `precision`.
+    pub fn pole_of_inaccessibility(&self, precision: f32) -> Option<Vec2> {
+        let outline = self.outline();
+        if outline.len() < 3 {
+            return None;
+        }
+        let aabb = self.aabb()?;
+        let size = aabb.max - aabb.min;
+        let cell_size = size.x.min(size.y);
+        if cell_size <= f32::EPSILON {
+            return Some(aabb.min);
+        }
+        let precision = precision.max(f32::EPSILON);
+        // cover the bounds with initial cells
+        let mut cells = BinaryHeap::new();
+        let half = cell_size / 2.;
+        let mut x = aabb.min.x;
+        while x < aabb.max.x {
+            let mut y = aabb.min.y;
+            while y < aabb.max.y {
+                cells.push(PoleCell::new(Vec2::new(x + half, y + half), half, &outline));
+                y += cell_size;
+            }
+            x += cell_size;
+        }
+        let mut best = PoleCell::new((aabb.min + aabb.max) / 2., 0., &outline);
+        if let Some(centroid) = self.centroid() {
+            let centroid_cell = PoleCell::new(centroid, 0., &outline);
+            if centroid_cell.distance > best.distance {
+                best = centroid_cell;
+            }
+        }
+        while let Some(cell) = cells.pop() {
+            let (center, half, max_distance) = (cell.center, cell.half / 2., cell.max_distance);
+            if cell.distance > best.distance {
+                best = cell;
+            }
+            if max_distance - best.distance <= precision {
+                continue;
+            }
+            for offset in [
+                Vec2::new(-half, -half),
+                Vec2::new(half, -half),
+                Vec2::new(-half, half),
+                Vec2::new(half, half),
+            ] {
+                cells.push(PoleCell::new(center + offset, half, &outline));
+            }
+        }
+        Some(best.center)
+    }
+
+    /// Volume of the solid extruded from the polygon.
+    ///
+    /// Ref: [`Self::extrude_to_bevy_mesh`].
+    pub fn extruded_volume(&self, extrude_size: f32) -> f32 {
+        self.area() * extrude_size.abs()
+    }
+
+    /// Surface area (top, bottom and sides) of the solid extruded from the polygon.
+    ///
+    /// Ref: [`Self::extrude_to_bevy_mesh`].
+    pub fn extruded_surface_area(&self, extrude_size: f32) -> f32 {
+        2. * self.area() + self.perimeter() * extrude_size.abs()
+    }
+
+    /// Volume of the solid revolved from the polygon (Pappus's centroid theorem).
+    ///
+    /// Assumes the profile does not cross the axis.
+    /// Ref: [`Self::lathe_to_bevy_mesh`].
+    pub fn lathe_volume(&self, options: &LatheOptions) -> f32 {
+        let Some(centroid) = self.centroid() else {
+            return 0.;
+        };
+        lathe_sweep_angle(options) * lathe_radius(options, centroid) * self.area()
+    }
+
+    /// Surface area of the solid revolved from the polygon (Pappus's centroid theorem).
+    ///
+    /// Includes the caps of partial revolves. Assumes the profile does not cross the axis.
+    /// Ref: [`Self::lathe_to_bevy_mesh`].
+    pub fn lathe_surface_area(&self, options: &LatheOptions) -> f32 {
+        let sweep_angle = lathe_sweep_angle(options);
+        let outline = self.outline();
+        let mut lateral_area = 0.;
+        for (idx, a) in outline.iter().enumerate() {
+            let b = outline[(idx + 1) % outline.len()];
+            let mid_radius = lathe_radius(options, (*a + b) / 2.);
+            lateral_area += sweep_angle * mid_radius * a.distance(b);
+        }
+        if sweep_angle < TAU {
+            lateral_area + 2. * self.area()
+        } else {
+            lateral_area
+        }
+    }
+}
+
+/// Sweep angle of the lathe, as used by the lathe mesh builder.
+fn lathe_sweep_angle(options: &LatheOptions) -> f32 {
+    options.sweep_angle.clamp(0., TAU)
+}
+
+/// Distance of the point from the lathe axis.
+fn lathe_radius(options: &LatheOptions, point: Vertex) -> f32 {
+    let Some(direction) = options.axis.direction.try_normalize() else {
+        return 0.;
+    };
+    (point - options.axis.origin).perp_dot(direction).abs()
+}
+
+/// Convex hull (counter clockwise) using the monotone chain algorithm.
+fn convex_hull(mut points: Vec<Vertex>) -> Vec<Vertex> {
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+    let mut hull: Vec<Vertex> = vec![];
+    for pass in 0..2 {
+        let start_len = hull.len();
+        let iter: Box<dyn Iterator<Item = &Vertex>> = if pass == 0 {
+            Box::new(points.iter())
+        } else {
+            Box::new(points.iter().rev())
+        };
+        for point in iter {
+            while hull.len() >= start_len + 2 {
+                let (a, b) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+                if (b - a).perp_dot(*point - a) > 0. {
+                    break;
+                }
+                hull.pop();
+            }
+            hull.push(*point);
+        }
+        // last point is the first of the next chain
+        hull.pop();
+    }
+    hull
+}
+
+/// Square cell used to search for the pole of inaccessibility.
+struct PoleCell {
+    center: Vec2,
+    half: f32,
+    /// Signed distance from the center to the outline.
+    distance: f32,
+    /// Max possible distance within the cell.
+    max_distance: f32,
+}
+
+impl PoleCell {
+    fn new(center: Vec2, half: f32, outline: &[Vertex]) -> Self {
+        let distance = signed_distance_to_outline(outline, center);
+        Self {
+            center,
+            half,
+            distance,
+            max_distance: distance + half * std::f32::consts::SQRT_2,
+        }
+    }
+}
+
+impl PartialEq for PoleCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.max_distance == other.max_distance
+    }
+}
+
+impl Eq for PoleCell {}
+
+impl PartialOrd for PoleCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PoleCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.max_distance.total_cmp(&other.max_distance)
+    }
+}
+
+#[test]
+fn test_measure_rectangle() {
+    let polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(4., 0.),
+        Vec2::new(4., 2.),
+        Vec2::new(0., 2.),
+    ]);
+    assert!((polygon.signed_area() - 8.).abs() < 1e-5);
+    let mut reversed = polygon.clone();
+    reversed.reverse();
+    assert!((reversed.signed_area() + 8.).abs() < 1e-5);
+    assert!((reversed.area() - 8.).abs() < 1e-5);
+    assert!((polygon.perimeter() - 12.).abs() < 1e-5);
+    let lengths = polygon
+        .edge_lengths()
+        .into_iter()
+        .map(|(_, length)| length)
+        .collect::<Vec<_>>();
+    assert_eq!(lengths, vec![4., 2., 4., 2.]);
+    assert!(polygon
+        .centroid()
+        .unwrap()
+        .abs_diff_eq(Vec2::new(2., 1.), 1e-5));
+    let aabb = polygon.aabb().unwrap();
+    assert_eq!((aabb.min, aabb.max), (Vec2::ZERO, Vec2::new(4., 2.)));
+    assert!(polygon
+        .pole_of_inaccessibility(0.01)
+        .unwrap()
+        .abs_diff_eq(Vec2::new(2., 1.), 0.05));
+    assert!((polygon.extruded_volume(3.) - 24.).abs() < 1e-5);
+    assert!((polygon.extruded_surface_area(3.) - (16. + 36.)).abs() < 1e-5);
+}
+
+#[test]
+fn test_measure_shapes() {
+    use std::f32::consts::PI;
+
+    // L shape, centroid from its 2 rectangles
+    let polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(3., 0.),
+        Vec2::new(3., 1.),
+        Vec2::new(1., 1.),
+        Vec2::new(1., 3.),
+        Vec2::new(0., 3.),
+    ]);
+    assert!((polygon.area() - 5.).abs() < 1e-5);
+    let expected_centroid = (Vec2::new(1.5, 0.5) * 3. + Vec2::new(0.5, 2.) * 2.) / 5.;
+    assert!(polygon
+        .centroid()
+        .unwrap()
+        .abs_diff_eq(expected_centroid, 1e-5));
+    // pole stays inside, away from the notch
+    let pole = polygon.pole_of_inaccessibility(0.01).unwrap();
+    assert!(super::geometry::is_inside(&polygon.outline(), pole));
+
+    // rotated square, oriented rect fits tightly
+    let square = MeshPolygon::from(vec![
+        Vec2::new(1., 0.),
+        Vec2::new(2., 1.),
+        Vec2::new(1., 2.),
+        Vec2::new(0., 1.),
+    ]);
+    let rect = square.min_oriented_bounding_rect().unwrap();
+    assert!((rect.area() - 2.).abs() < 1e-5);
+    assert!(rect.center.abs_diff_eq(Vec2::new(1., 1.), 1e-5));
+    let aabb = square.aabb().unwrap();
+    assert!(((aabb.max - aabb.min).x * (aabb.max - aabb.min).y - 4.).abs() < 1e-5);
+
+    // washer: profile 1..2 away from the axis (y axis), height 1
+    let profile = MeshPolygon::from(vec![
+        Vec2::new(1., 0.),
+        Vec2::new(2., 0.),
+        Vec2::new(2., 1.),
+        Vec2::new(1., 1.),
+    ]);
+    let options = LatheOptions::default();
+    assert!((profile.lathe_volume(&options) - 3. * PI).abs() < 1e-4);
+    // outer + inner walls + top/bottom rings
+    let expected_surface = 2. * PI * 2. + 2. * PI + 2. * (4. * PI - PI);
+    assert!((profile.lathe_surface_area(&options) - expected_surface).abs() < 1e-4);
+}
@@ -0,0 +1,234 @@
+//! Merging of adjacent [`MeshPolygon`]s sharing edges into one.
+//!
+//! Shared (coincident within tolerance) straight edges are dissolved and the
+//! outlines joined. Vertices touching the other polygon's edges are inserted
+//! first, so partially shared edges (T-junctions) merge too.
+
+use crate::data_structures::{Edge, MeshPolygon, VertexId};
+
+use super::geometry::{is_closed, nearest_point_on_segment, ring_edges, signed_area};
+
+/// Merge polygon `b` into polygon `a` along their shared edges.
+///
+/// The result keeps the winding and `VertexId`s of `a` (except for its removed
+/// vertices), vertices coming from `b` get new ids. Collinear vertices left
+/// over at the junctions are removed.
+///
+/// Returns `None` if the polygons don't share edges or share more than one
+/// contiguous run of edges (merging would leave holes).
+pub fn merge_polygons(a: &MeshPolygon, b: &MeshPolygon, tolerance: f32) -> Option<MeshPolygon> {
+    if !is_closed(a) || !is_closed(b) {
+        return None;
+    }
+    let mut merged = a.clone();
+    let mut other = b.clone();
+    // same winding, so shared edges run in opposite directions
+    if (signed_area(&merged.outline()) > 0.) != (signed_area(&other.outline()) > 0.) {
+        other.reverse();
+    }
+    // resolve T-junctions so that shared edges have coincident vertices
+    let mut leftovers = insert_touching_vertices(&mut merged, &other, tolerance);
+    let other_inserted = insert_touching_vertices(&mut other, &merged, tolerance);
+
+    let ids_a = merged.vertices.ids().clone();
+    let ids_b = other.vertices.ids().clone();
+    let (len_a, len_b) = (ids_a.len(), ids_b.len());
+    // index of coincident vertex in `b` for each vertex of `a`
+    let matches = ids_a
+        .iter()
+        .map(|id| {
+            let vertex = merged.vertices.get(*id)?;
+            ids_b.iter().position(|id_b| {
+                other
+                    .vertices
+                    .get(*id_b)
+                    .is_some_and(|vertex_b| vertex_b.distance(*vertex) <= tolerance)
+            })
+        })
+        .collect::<Vec<_>>();
+    // edge `k` of `a` is shared if it runs backwards along a straight edge of `b`
+    let shared = (0..len_a)
+        .map(|k| {
+            let next = (k + 1) % len_a;
+            let (Some(match_k), Some(match_next)) = (matches[k], matches[next]) else {
+                return false;
+            };
+            (match_next + 1) % len_b == match_k
+                && merged
+                    .edge_curve(&Edge::new(ids_a[k], ids_a[next]))
+                    .is_none()
+                && other
+                    .edge_curve(&Edge::new(ids_b[match_next], ids_b[match_k]))
+                    .is_none()
+        })
+        .collect::<Vec<_>>();
+    let shared_count = shared.iter().filter(|is_shared| **is_shared).count();
+    if shared_count == 0 || shared_count == len_a {
+        return None;
+    }
+    let start = (0..len_a).find(|k| shared[*k] && !shared[(k + len_a - 1) % len_a])?;
+    let run = (0..len_a)
+        .take_while(|offset| shared[(start + offset) % len_a])
+        .count();
+    if run != shared_count {
+        return None;
+    }
+    let (i, j) = (start, (start + run) % len_a);
+    let (match_i, match_j) = (matches[i]?, matches[j]?);
+    let b_portion_len = (match_j + len_b - match_i - 1) % len_b;
+    if len_a - (run - 1) + b_portion_len < 3 {
+        // degenerate result
+        return None;
+    }
+
+    // remove the shared chain of `a`...
+    for offset in 1..run {
+        merged.remove_vertex(ids_a[(i + offset) % len_a]);
+    }
+    // ...and replace it with the unshared portion of `b`
+    let mut new_ids = vec![ids_a[i]];
+    for offset in 1..=b_portion_len {
+        let id_b = ids_b[(match_i + offset) % len_b];
+        let vertex = *other.vertices.get(id_b)?;
+        let prev = *new_ids.last()?;
+        let id = merged.insert_vertex_on_edge(vertex, Edge::new(prev, ids_a[j]))?;
+        if other_inserted.contains(&id_b) {
+            leftovers.push(id);
+        }
+        new_ids.push(id);
+    }
+    new_ids.push(ids_a[j]);
+    for (offset, new_edge) in new_ids.windows(2).enumerate() {
+        let edge_b = Edge::new(
+            ids_b[(match_i + offset) % len_b],
+            ids_b[(match_i + offset + 1) % len_b],
+        );
+        if let Some(curve) = other.edge_curve(&edge_b) {
+            merged.set_edge_curve(Edge::new(new_edge[0], new_edge[1]), Some(curve));
+        }
+    }
+
+    // cleanup collinear leftovers
+    leftovers.extend([ids_a[i], ids_a[j]]);
+    for id in leftovers {
+        if merged.vertices.len() <= 3 {
+            break;
+        }
+        if is_collinear_vertex(&merged, id, tolerance) {
+            merged.remove_vertex(id);
+        }
+    }
+    Some(merged)
+}
+
+/// Insert the vertices of `source` lying on straight edges of `target` into `target`.
+///
+/// Returns the ids of the inserted vertices.
+fn insert_touching_vertices(
+    target: &mut MeshPolygon,
+    source: &MeshPolygon,
+    tolerance: f32,
+) -> Vec<VertexId> {
+    let mut inserted = vec![];
+    for vertex in source.vertices.get_all_owned() {
+        if target
+            .vertices
+            .iter()
+            .any(|target_vertex| target_vertex.distance(vertex) <= tolerance)
+        {
+            continue;
+        }
+        for edge in ring_edges(target) {
+            let (Some(from_vert), Some(to_vert)) =
+                (target.vertices.get(edge.from), target.vertices.get(edge.to))
+            else {
+                continue;
+            };
+            if target.edge_curve(&edge).is_some() {
+                continue;
+            }
+            let nearest = nearest_point_on_segment(vertex, *from_vert, *to_vert);
+            if nearest.distance(vertex) <= tolerance {
+                if let Some(id) = target.insert_vertex_on_edge(nearest, edge) {
+                    inserted.push(id);
+                }
+                break;
+            }
+        }
+    }
+    inserted
+}
+
+/// Check if the vertex lies (within tolerance) on the straight line between its neighbors.
+fn is_collinear_vertex(polygon: &MeshPolygon, vertex_id: VertexId, tolerance: f32) -> bool {
+    let ids = polygon.vertices.ids();
+    let Some(idx) = ids.iter().position(|id| *id == vertex_id) else {
+        return false;
+    };
+    let prev = ids[(idx + ids.len() - 1) % ids.len()];
+    let next = ids[(idx + 1) % ids.len()];
+    if polygon.edge_curve(&Edge::new(prev, vertex_id)).is_some()
+        || polygon.edge_curve(&Edge::new(vertex_id, next)).is_some()
+    {
+        return false;
+    }
+    let (Some(prev_vert), Some(vertex), Some(next_vert)) = (
+        polygon.vertices.get(prev),
+        polygon.vertices.get(vertex_id),
+        polygon.vertices.get(next),
+    ) else {
+        return false;
+    };
+    nearest_point_on_segment(*vertex, *prev_vert, *next_vert).distance(*vertex) <= tolerance
+}
+
+#[cfg(test)]
+fn rect(min: bevy::prelude::Vec2, max: bevy::prelude::Vec2) -> MeshPolygon {
+    use bevy::prelude::Vec2;
+
+    MeshPolygon::from(vec![
+        min,
+        Vec2::new(max.x, min.y),
+        max,
+        Vec2::new(min.x, max.y),
+    ])
+}
+
+#[test]
+fn test_merge_polygons_sharing_edge() {
+    use bevy::prelude::Vec2;
+
+    let a = rect(Vec2::new(0., 0.), Vec2::new(1., 1.));
+    let mut b = rect(Vec2::new(1., 0.), Vec2::new(2., 1.));
+    // winding of b does not matter
+    b.reverse();
+    let merged = merge_polygons(&a, &b, 0.01).unwrap();
+    assert_eq!(merged.vertices.len(), 4);
+    assert_eq!(merged.edges.len(), 4);
+    assert!((signed_area(&merged.outline()) - 2.).abs() < 1e-5);
+    // ids of the outer vertices of `a` are kept
+    let ids_a = a.vertices.ids();
+    assert!(merged.vertices.get(ids_a[0]).is_some());
+    assert!(merged.vertices.get(ids_a[3]).is_some());
+
+    // not adjacent
+    let c = rect(Vec2::new(3., 0.), Vec2::new(4., 1.));
+    assert!(merge_polygons(&a, &c, 0.01).is_none());
+}
+
+#[test]
+fn test_merge_polygons_partially_shared_edge() {
+    use bevy::prelude::Vec2;
+
+    let a = rect(Vec2::new(0., 0.), Vec2::new(2., 2.));
+    let b = rect(Vec2::new(2.005, 0.), Vec2::new(3., 1.));
+    let merged = merge_polygons(&a, &b, 0.01).unwrap();
+    let vertices = merged.vertices.get_all_owned();
+    assert_eq!(vertices.len(), 6, "{vertices:?}");
+    assert_eq!(merged.edges.len(), 6);
+    assert!((signed_area(&merged.outline()) - 5.).abs() < 0.02);
+    // collinear junction vertex at (2, 0) is removed
+    assert!(!vertices
+        .iter()
+        .any(|vertex| vertex.distance(Vec2::new(2., 0.)) < 0.01));
+}
@@ -0,0 +1,22 @@
+/// Duplicating polygons (translated, rotated and mirrored copies, linear and circular arrays).
+pub mod array;
+/// Geometric constraints (fixed length, parallel, perpendicular, etc) and their solver.
+pub mod constraints;
+/// Rounding (fillet) and cutting (chamfer) of polygon corners.
+pub mod corner;
+/// Shared geometry helpers.
+mod geometry;
+/// Measurements of polygons (area, perimeter, centroid, bounds, etc).
+pub mod measure;
+/// Merging adjacent polygons sharing edges.
+pub mod merge;
+/// Primitive shapes (rectangles, circles, ellipses, regular polygons).
+pub mod primitives;
+/// Spatial queries (containment, nearest point, ray and segment intersection).
+pub mod query;
+/// Splitting polygons along cut lines (knife).
+pub mod split;
+/// Freehand strokes into polygons (simplification and smoothing).
+pub mod stroke;
+/// Mirror and radial symmetry (welded symmetric polygons and linked copies).
+pub mod symmetry;
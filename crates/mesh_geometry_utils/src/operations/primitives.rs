@@ -0,0 +1,140 @@
+//! Builders for primitive shaped [`MeshPolygon`]s.
+//!
+//! All builders return `None` for degenerate input (zero sized shapes or too
+//! few segments/sides) so that callers can simply skip them.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::Vec2;
+
+use crate::data_structures::{vertices::Vertex, MeshPolygon};
+
+/// Size below which a shape is treated as degenerate.
+const MIN_SHAPE_SIZE: f32 = 1e-4;
+
+/// Axis aligned rectangle with `corner_a` and `corner_b` as opposite corners.
+pub fn rectangle(corner_a: Vertex, corner_b: Vertex) -> Option<MeshPolygon> {
+    let min = corner_a.min(corner_b);
+    let max = corner_a.max(corner_b);
+    let size = max - min;
+    if size.x < MIN_SHAPE_SIZE || size.y < MIN_SHAPE_SIZE {
+        return None;
+    }
+    Some(MeshPolygon::from(vec![
+        min,
+        Vec2::new(max.x, min.y),
+        max,
+        Vec2::new(min.x, max.y),
+    ]))
+}
+
+/// Rotated rectangle with `start` -> `end` as its base edge.
+///
+/// `width_point` sets the other side, the rectangle extends towards it up to its
+/// perpendicular distance from the base edge.
+pub fn rotated_rectangle(start: Vertex, end: Vertex, width_point: Vertex) -> Option<MeshPolygon> {
+    let base = end - start;
+    if base.length() < MIN_SHAPE_SIZE {
+        return None;
+    }
+    let normal = base.perp().normalize();
+    let width = (width_point - start).dot(normal);
+    if width.abs() < MIN_SHAPE_SIZE {
+        return None;
+    }
+    let offset = normal * width;
+    Some(MeshPolygon::from(vec![
+        start,
+        end,
+        end + offset,
+        start + offset,
+    ]))
+}
+
+/// Ellipse around `center` with `radii` along x/y axes, rotated by `rotation` (radians).
+///
+/// Approximated with `segments` vertices.
+pub fn ellipse(center: Vertex, radii: Vec2, rotation: f32, segments: u32) -> Option<MeshPolygon> {
+    let radii = radii.abs();
+    if segments < 3 || radii.x < MIN_SHAPE_SIZE || radii.y < MIN_SHAPE_SIZE {
+        return None;
+    }
+    let rotation = Vec2::from_angle(rotation);
+    let vertices = (0..segments)
+        .map(|index| {
+            let angle = TAU * index as f32 / segments as f32;
+            let point = Vec2::new(angle.cos() * radii.x, angle.sin() * radii.y);
+            center + rotation.rotate(point)
+        })
+        .collect::<Vec<_>>();
+    Some(MeshPolygon::from(vertices))
+}
+
+/// Circle around `center` with given `radius`, approximated with `segments` vertices.
+pub fn circle(center: Vertex, radius: f32, segments: u32) -> Option<MeshPolygon> {
+    ellipse(center, Vec2::splat(radius), 0., segments)
+}
+
+/// Regular polygon with `sides` around `center`, with one of its vertices at `first_vertex`.
+pub fn regular_polygon(center: Vertex, first_vertex: Vertex, sides: u32) -> Option<MeshPolygon> {
+    let radius_vec = first_vertex - center;
+    if sides < 3 || radius_vec.length() < MIN_SHAPE_SIZE {
+        return None;
+    }
+    let vertices = (0..sides)
+        .map(|index| {
+            let rotation = Vec2::from_angle(TAU * index as f32 / sides as f32);
+            center + rotation.rotate(radius_vec)
+        })
+        .collect::<Vec<_>>();
+    Some(MeshPolygon::from(vertices))
+}
+
+#[test]
+fn test_rectangle_primitives() {
+    let polygon = rectangle(Vec2::new(2., 3.), Vec2::new(0., 1.)).unwrap();
+    assert_eq!(polygon.vertices.len(), 4);
+    assert_eq!(polygon.edges.len(), 4);
+    assert_eq!(
+        polygon.vertices.get_all_owned(),
+        vec![
+            Vec2::new(0., 1.),
+            Vec2::new(2., 1.),
+            Vec2::new(2., 3.),
+            Vec2::new(0., 3.),
+        ]
+    );
+    assert!(rectangle(Vec2::ZERO, Vec2::new(1., 0.)).is_none());
+
+    let polygon = rotated_rectangle(Vec2::ZERO, Vec2::new(1., 1.), Vec2::new(-1., 1.)).unwrap();
+    let vertices = polygon.vertices.get_all_owned();
+    assert_eq!(vertices.len(), 4);
+    assert!(vertices[2].abs_diff_eq(Vec2::new(0., 2.), 1e-5));
+    assert!(vertices[3].abs_diff_eq(Vec2::new(-1., 1.), 1e-5));
+    assert!(rotated_rectangle(Vec2::ZERO, Vec2::X, Vec2::new(3., 0.)).is_none());
+}
+
+#[test]
+fn test_round_primitives() {
+    let polygon = circle(Vec2::new(1., 1.), 2., 16).unwrap();
+    assert_eq!(polygon.vertices.len(), 16);
+    assert_eq!(polygon.edges.len(), 16);
+    for vertex in polygon.vertices.iter() {
+        assert!((vertex.distance(Vec2::new(1., 1.)) - 2.).abs() < 1e-5);
+    }
+    assert!(circle(Vec2::ZERO, 1., 2).is_none());
+
+    let polygon = ellipse(Vec2::ZERO, Vec2::new(2., 1.), 0., 4).unwrap();
+    let vertices = polygon.vertices.get_all_owned();
+    assert!(vertices[0].abs_diff_eq(Vec2::new(2., 0.), 1e-5));
+    assert!(vertices[1].abs_diff_eq(Vec2::new(0., 1.), 1e-5));
+
+    let polygon = regular_polygon(Vec2::ZERO, Vec2::new(0., 1.), 6).unwrap();
+    let vertices = polygon.vertices.get_all_owned();
+    assert_eq!(vertices.len(), 6);
+    assert!(vertices[0].abs_diff_eq(Vec2::new(0., 1.), 1e-5));
+    for (index, vertex) in vertices.iter().enumerate() {
+        let next = vertices[(index + 1) % vertices.len()];
+        assert!((vertex.distance(next) - 1.).abs() < 1e-5);
+    }
+}
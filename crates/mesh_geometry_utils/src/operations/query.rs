@@ -0,0 +1,355 @@
+//! Spatial queries against [`MeshPolygon`]s.
+//!
+//! Queries use the [outline](MeshPolygon::outline), so curved edges are
+//! queried along their flattened curves. Edge parameters of curved edges are
+//! the curve parameter `t` (ref: [`EdgeCurve::nearest_t`](crate::data_structures::EdgeCurve::nearest_t)).
+
+use crate::data_structures::{vertices::Vertex, Edge, MeshPolygon};
+
+use super::geometry::{edge_polyline, is_inside, nearest_point_on_segment, ring_edges};
+
+/// Min length of directions and segments taken into account.
+const QUERY_EPSILON: f32 = 1e-6;
+
+/// Location of a point relative to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointContainment {
+    /// Point is strictly inside the polygon.
+    Inside,
+    /// Point is on the boundary (within tolerance).
+    Boundary,
+    /// Point is outside the polygon.
+    Outside,
+}
+
+impl PointContainment {
+    /// Check if the point is inside or on the boundary.
+    pub fn is_contained(&self) -> bool {
+        !matches!(self, Self::Outside)
+    }
+}
+
+/// Nearest point on the boundary of a polygon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundaryPoint {
+    /// Edge the point lies on.
+    pub edge: Edge,
+    /// Position of the point.
+    pub point: Vertex,
+    /// Parameter (0..=1) of the point along the edge.
+    pub t: f32,
+    /// Distance from the queried point.
+    pub distance: f32,
+}
+
+/// Intersection of a ray or segment with an edge of a polygon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgeHit {
+    /// Edge that was hit.
+    pub edge: Edge,
+    /// Position of the hit.
+    pub point: Vertex,
+    /// Parameter (0..=1) of the hit along the edge.
+    pub t: f32,
+    /// Distance from the origin of the ray/segment.
+    pub distance: f32,
+    /// Whether the ray/segment enters the polygon at the hit (exits otherwise).
+    pub is_entering: bool,
+}
+
+impl MeshPolygon {
+    /// Classify the point against the polygon.
+    ///
+    /// Points within `tolerance` of the boundary are [`PointContainment::Boundary`].
+    pub fn classify_point(&self, point: Vertex, tolerance: f32) -> PointContainment {
+        let outline = self.outline();
+        if outline.len() < 3 {
+            return PointContainment::Outside;
+        }
+        if self
+            .nearest_boundary_point(point)
+            .is_some_and(|nearest| nearest.distance <= tolerance)
+        {
+            PointContainment::Boundary
+        } else if is_inside(&outline, point) {
+            PointContainment::Inside
+        } else {
+            PointContainment::Outside
+        }
+    }
+
+    /// Check if the point is inside the polygon or within `tolerance` of its boundary.
+    pub fn contains_point(&self, point: Vertex, tolerance: f32) -> bool {
+        self.classify_point(point, tolerance).is_contained()
+    }
+
+    /// Get the nearest point on the boundary of the polygon.
+    pub fn nearest_boundary_point(&self, point: Vertex) -> Option<BoundaryPoint> {
+        let mut nearest: Option<(Edge, Vertex, f32)> = None;
+        for edge in ring_edges(self) {
+            let Some(points) = edge_polyline(self, &edge) else {
+                continue;
+            };
+            for segment in points.windows(2) {
+                let candidate = nearest_point_on_segment(point, segment[0], segment[1]);
+                let distance = candidate.distance(point);
+                if !matches!(nearest, Some((_, _, min_distance)) if min_distance <= distance) {
+                    nearest = Some((edge.clone(), candidate, distance));
+                }
+            }
+        }
+        let (edge, point, distance) = nearest?;
+        let t = self.edge_parameter(&edge, point);
+        Some(BoundaryPoint {
+            edge,
+            point,
+            t,
+            distance,
+        })
+    }
+
+    /// Signed distance from the point to the boundary, positive inside.
+    ///
+    /// Returns `None` for polygons without edges.
+    pub fn signed_distance(&self, point: Vertex) -> Option<f32> {
+        let distance = self.nearest_boundary_point(point)?.distance;
+        if is_inside(&self.outline(), point) {
+            Some(distance)
+        } else {
+            Some(-distance)
+        }
+    }
+
+    /// Intersect the segment `start` -> `end` with the edges of the polygon.
+    ///
+    /// Hits are sorted by distance from `start`.
+    pub fn intersect_segment(&self, start: Vertex, end: Vertex) -> Vec<EdgeHit> {
+        let length = start.distance(end);
+        if length <= QUERY_EPSILON {
+            return vec![];
+        }
+        self.edge_hits(start, (end - start) / length, length)
+    }
+
+    /// Intersect the ray from `origin` along `direction` with the edges of the polygon.
+    ///
+    /// Hits are sorted by distance from `origin`. For a ray starting inside the
+    /// polygon, the first hit is where the ray exits.
+    pub fn intersect_ray(&self, origin: Vertex, direction: Vertex) -> Vec<EdgeHit> {
+        let Some(direction) = direction.try_normalize() else {
+            return vec![];
+        };
+        self.edge_hits(origin, direction, f32::INFINITY)
+    }
+
+    /// Get the first hit of the ray from `origin` along `direction`.
+    pub fn raycast(&self, origin: Vertex, direction: Vertex) -> Option<EdgeHit> {
+        self.intersect_ray(origin, direction).into_iter().next()
+    }
+
+    /// Intersect the ray `origin + direction * distance` (`direction` normalized)
+    /// up to `max_distance` with the edges of the polygon.
+    fn edge_hits(&self, origin: Vertex, direction: Vertex, max_distance: f32) -> Vec<EdgeHit> {
+        let winding = self.signed_area().signum();
+        let mut hits: Vec<EdgeHit> = vec![];
+        for edge in ring_edges(self) {
+            let Some(points) = edge_polyline(self, &edge) else {
+                continue;
+            };
+            for segment in points.windows(2) {
+                let segment_dir = segment[1] - segment[0];
+                let denominator = direction.perp_dot(segment_dir);
+                // parallel (or collinear) segments are not hits
+                if denominator.abs() <= QUERY_EPSILON {
+                    continue;
+                }
+                let offset = segment[0] - origin;
+                let distance = offset.perp_dot(segment_dir) / denominator;
+                let segment_t = offset.perp_dot(direction) / denominator;
+                if !(0. ..=max_distance).contains(&distance) || !(0. ..=1.).contains(&segment_t) {
+                    continue;
+                }
+                let point = origin + direction * distance;
+                hits.push(EdgeHit {
+                    edge: edge.clone(),
+                    point,
+                    t: self.edge_parameter(&edge, point),
+                    distance,
+                    is_entering: segment_dir.perp_dot(direction) * winding > 0.,
+                });
+            }
+        }
+        hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        // hits through a shared vertex are reported once
+        hits.dedup_by(|b, a| {
+            (a.distance - b.distance).abs() <= QUERY_EPSILON && a.is_entering == b.is_entering
+        });
+        hits
+    }
+
+    /// Parameter (0..=1) of the point (on the edge) along the edge.
+    fn edge_parameter(&self, edge: &Edge, point: Vertex) -> f32 {
+        let (Some(from_vert), Some(to_vert)) =
+            (self.vertices.get(edge.from), self.vertices.get(edge.to))
+        else {
+            return 0.;
+        };
+        if let Some(curve) = self.edge_curve(edge) {
+            return curve.nearest_t(*from_vert, *to_vert, point);
+        }
+        let segment = *to_vert - *from_vert;
+        let length_squared = segment.length_squared();
+        if length_squared <= f32::EPSILON {
+            return 0.;
+        }
+        ((point - *from_vert).dot(segment) / length_squared).clamp(0., 1.)
+    }
+}
+
+/// Classify the point against the `outer` polygon with `holes` cut out of it.
+///
+/// Points within `tolerance` of the outer or any hole boundary are
+/// [`PointContainment::Boundary`], points inside a hole are [`PointContainment::Outside`].
+pub fn classify_point_with_holes(
+    outer: &MeshPolygon,
+    holes: &[MeshPolygon],
+    point: Vertex,
+    tolerance: f32,
+) -> PointContainment {
+    match outer.classify_point(point, tolerance) {
+        PointContainment::Inside => {
+            for hole in holes {
+                match hole.classify_point(point, tolerance) {
+                    PointContainment::Inside => return PointContainment::Outside,
+                    PointContainment::Boundary => return PointContainment::Boundary,
+                    PointContainment::Outside => {}
+                }
+            }
+            PointContainment::Inside
+        }
+        containment => containment,
+    }
+}
+
+#[test]
+fn test_point_queries() {
+    use crate::data_structures::EdgeCurve;
+    use bevy::prelude::Vec2;
+
+    let square = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(4., 0.),
+        Vec2::new(4., 4.),
+        Vec2::new(0., 4.),
+    ]);
+    let ids = square.vertices.ids();
+
+    assert_eq!(
+        square.classify_point(Vec2::new(2., 2.), 0.01),
+        PointContainment::Inside
+    );
+    assert_eq!(
+        square.classify_point(Vec2::new(4.005, 2.), 0.01),
+        PointContainment::Boundary
+    );
+    assert_eq!(
+        square.classify_point(Vec2::new(5., 2.), 0.01),
+        PointContainment::Outside
+    );
+    assert!(!square.contains_point(Vec2::new(4.005, 2.), 0.));
+
+    // nearest point on the right edge, halfway
+    let nearest = square.nearest_boundary_point(Vec2::new(5., 2.)).unwrap();
+    assert_eq!(nearest.edge, Edge::new(ids[1], ids[2]));
+    assert!(nearest.point.abs_diff_eq(Vec2::new(4., 2.), 1e-5));
+    assert!((nearest.t - 0.5).abs() < 1e-5);
+    assert!((nearest.distance - 1.).abs() < 1e-5);
+
+    assert!((square.signed_distance(Vec2::new(1., 2.)).unwrap() - 1.).abs() < 1e-5);
+    assert!((square.signed_distance(Vec2::new(-3., 2.)).unwrap() + 3.).abs() < 1e-5);
+
+    // holes
+    let hole = MeshPolygon::from(vec![
+        Vec2::new(1., 1.),
+        Vec2::new(3., 1.),
+        Vec2::new(3., 3.),
+        Vec2::new(1., 3.),
+    ]);
+    let holes = [hole];
+    assert_eq!(
+        classify_point_with_holes(&square, &holes, Vec2::new(2., 2.), 0.01),
+        PointContainment::Outside
+    );
+    assert_eq!(
+        classify_point_with_holes(&square, &holes, Vec2::new(1., 2.), 0.01),
+        PointContainment::Boundary
+    );
+    assert_eq!(
+        classify_point_with_holes(&square, &holes, Vec2::new(0.5, 2.), 0.01),
+        PointContainment::Inside
+    );
+
+    // curved edge bulging out of the top edge
+    let mut curved = square.clone();
+    let top_edge = Edge::new(ids[2], ids[3]);
+    curved.set_edge_curve(
+        top_edge.clone(),
+        Some(EdgeCurve::QuadraticBezier(Vec2::new(2., 8.))),
+    );
+    assert!(curved.contains_point(Vec2::new(2., 5.), 0.));
+    let nearest = curved.nearest_boundary_point(Vec2::new(2., 10.)).unwrap();
+    assert_eq!(nearest.edge, top_edge);
+    assert!((nearest.t - 0.5).abs() < 0.05);
+}
+
+#[test]
+fn test_ray_queries() {
+    use bevy::prelude::Vec2;
+
+    let square = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(4., 0.),
+        Vec2::new(4., 4.),
+        Vec2::new(0., 4.),
+    ]);
+    let ids = square.vertices.ids();
+
+    // from inside, the first hit is the exit
+    let exit = square
+        .raycast(Vec2::new(1., 2.), Vec2::new(2., 0.))
+        .unwrap();
+    assert_eq!(exit.edge, Edge::new(ids[1], ids[2]));
+    assert!(exit.point.abs_diff_eq(Vec2::new(4., 2.), 1e-5));
+    assert!((exit.distance - 3.).abs() < 1e-5);
+    assert!(!exit.is_entering);
+
+    // through the polygon, enters then exits
+    let hits = square.intersect_ray(Vec2::new(-1., 1.), Vec2::X);
+    assert_eq!(hits.len(), 2);
+    assert!(hits[0].is_entering && !hits[1].is_entering);
+    assert!((hits[0].distance - 1.).abs() < 1e-5);
+    assert!((hits[1].distance - 5.).abs() < 1e-5);
+    assert!((hits[0].t - 0.75).abs() < 1e-5);
+
+    // same result on the reversed (clockwise) polygon
+    let mut reversed = square.clone();
+    reversed.reverse();
+    let hits = reversed.intersect_ray(Vec2::new(-1., 1.), Vec2::X);
+    assert!(hits[0].is_entering && !hits[1].is_entering);
+
+    // through a corner, reported once
+    let hits = square.intersect_ray(Vec2::new(-1., -1.), Vec2::new(1., 1.));
+    assert_eq!(hits.len(), 2);
+
+    // segments stop at their end
+    assert_eq!(
+        square
+            .intersect_segment(Vec2::new(-1., 1.), Vec2::new(2., 1.))
+            .len(),
+        1
+    );
+    assert!(square
+        .intersect_segment(Vec2::new(1., 1.), Vec2::new(2., 2.))
+        .is_empty());
+    assert!(square.raycast(Vec2::new(5., 1.), Vec2::X).is_none());
+}
@@ -0,0 +1,229 @@
+//! Splitting of [`MeshPolygon`]s into two along a cut.
+//!
+//! A cut is either a diagonal between two existing vertices or a polyline
+//! running through the polygon between two points on its boundary. Both
+//! resulting polygons keep the winding (and the curved edges) of the original.
+
+use crate::data_structures::{vertices::Vertex, Edge, MeshPolygon, VertexId};
+
+use super::geometry::{crosses_outline, is_closed, is_inside};
+
+/// Split the polygon along the diagonal between two of its vertices.
+///
+/// Returns `None` if the vertices are the same/adjacent or the diagonal
+/// does not lie inside the polygon.
+pub fn split_by_diagonal(
+    polygon: &MeshPolygon,
+    from: VertexId,
+    to: VertexId,
+) -> Option<(MeshPolygon, MeshPolygon)> {
+    split_between(polygon, from, to, &[])
+}
+
+/// Split the polygon along a polyline cut.
+///
+/// The first and last points of the `cut` need to be on the boundary (within
+/// `tolerance`), they snap to existing vertices within `tolerance` and are
+/// inserted as new vertices otherwise. The points in between need to be
+/// inside the polygon.
+///
+/// Returns `None` if the cut does not split the polygon into two.
+pub fn split_by_polyline(
+    polygon: &MeshPolygon,
+    cut: &[Vertex],
+    tolerance: f32,
+) -> Option<(MeshPolygon, MeshPolygon)> {
+    let (Some(start), Some(end)) = (cut.first(), cut.last()) else {
+        return None;
+    };
+    if cut.len() < 2 || !is_closed(polygon) {
+        return None;
+    }
+    let mut polygon = polygon.clone();
+    let from = snap_or_insert_on_boundary(&mut polygon, *start, tolerance)?;
+    let to = snap_or_insert_on_boundary(&mut polygon, *end, tolerance)?;
+    split_between(&polygon, from, to, &cut[1..cut.len() - 1])
+}
+
+/// Find the nearest point on the boundary of the polygon.
+///
+/// Returns the edge (in polygon order), the nearest point and its distance.
+pub fn nearest_boundary_point(polygon: &MeshPolygon, point: Vertex) -> Option<(Edge, Vertex, f32)> {
+    let nearest = polygon.nearest_boundary_point(point)?;
+    Some((nearest.edge, nearest.point, nearest.distance))
+}
+
+/// Get vertex at the boundary point, snapping to existing vertices or inserting a new one.
+fn snap_or_insert_on_boundary(
+    polygon: &mut MeshPolygon,
+    point: Vertex,
+    tolerance: f32,
+) -> Option<VertexId> {
+    let (edge, boundary_point, distance) = nearest_boundary_point(polygon, point)?;
+    if distance > tolerance {
+        return None;
+    }
+    for id in [edge.from, edge.to] {
+        if polygon
+            .vertices
+            .get(id)
+            .is_some_and(|vertex| vertex.distance(boundary_point) <= tolerance)
+        {
+            return Some(id);
+        }
+    }
+    polygon.insert_vertex_on_edge(boundary_point, edge)
+}
+
+/// Split the polygon between 2 of its vertices, through the `interior` points.
+fn split_between(
+    polygon: &MeshPolygon,
+    from: VertexId,
+    to: VertexId,
+    interior: &[Vertex],
+) -> Option<(MeshPolygon, MeshPolygon)> {
+    if from == to || !is_closed(polygon) {
+        return None;
+    }
+    let ids = polygon.vertices.ids();
+    let from_idx = ids.iter().position(|id| *id == from)?;
+    let to_idx = ids.iter().position(|id| *id == to)?;
+    let length = ids.len();
+    let is_adjacent = (from_idx + 1) % length == to_idx || (to_idx + 1) % length == from_idx;
+    if is_adjacent && interior.is_empty() {
+        // cut along an existing edge
+        return None;
+    }
+    // validate the cut lies inside the polygon
+    let mut cut = vec![*polygon.vertices.get(from)?];
+    cut.extend_from_slice(interior);
+    cut.push(*polygon.vertices.get(to)?);
+    let outline = polygon.outline();
+    for point in interior {
+        if !is_inside(&outline, *point) {
+            return None;
+        }
+    }
+    for segment in cut.windows(2) {
+        if segment[0].distance_squared(segment[1]) <= f32::EPSILON
+            || !is_inside(&outline, segment[0].lerp(segment[1], 0.5))
+            || crosses_outline(&outline, segment[0], segment[1])
+        {
+            return None;
+        }
+    }
+    // walk the boundary both ways, each closed back along the cut
+    let boundary_a = ring_walk(ids, from_idx, to_idx);
+    let boundary_b = ring_walk(ids, to_idx, from_idx);
+    let interior_reversed = interior.iter().rev().cloned().collect::<Vec<_>>();
+    Some((
+        build_piece(polygon, &boundary_a, &interior_reversed),
+        build_piece(polygon, &boundary_b, interior),
+    ))
+}
+
+/// Ids from `start_idx` to `end_idx` (both inclusive) going forward around the ring.
+fn ring_walk(ids: &[VertexId], start_idx: usize, end_idx: usize) -> Vec<VertexId> {
+    let mut walk = vec![];
+    let mut idx = start_idx;
+    loop {
+        walk.push(ids[idx]);
+        if idx == end_idx {
+            break;
+        }
+        idx = (idx + 1) % ids.len();
+    }
+    walk
+}
+
+/// Build polygon from the boundary vertices followed by the cut points.
+///
+/// Curves of the boundary edges are carried over.
+fn build_piece(polygon: &MeshPolygon, boundary: &[VertexId], cut: &[Vertex]) -> MeshPolygon {
+    let mut piece = MeshPolygon::new();
+    piece.flatten_tolerance = polygon.flatten_tolerance;
+    let new_ids = boundary
+        .iter()
+        .filter_map(|id| polygon.vertices.get(*id))
+        .map(|vertex| piece.push_vertex(*vertex))
+        .collect::<Vec<_>>();
+    for vertex in cut {
+        piece.push_vertex(*vertex);
+    }
+    piece.close();
+    for (old, new) in boundary.windows(2).zip(new_ids.windows(2)) {
+        if let Some(curve) = polygon.edge_curve(&Edge::new(old[0], old[1])) {
+            piece.set_edge_curve(Edge::new(new[0], new[1]), Some(curve));
+        }
+    }
+    piece
+}
+
+#[cfg(test)]
+fn polygon_area(polygon: &MeshPolygon) -> f32 {
+    super::geometry::signed_area(&polygon.outline())
+}
+
+#[test]
+fn test_split_by_diagonal() {
+    use bevy::prelude::Vec2;
+
+    let polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(1., 0.),
+        Vec2::new(1., 1.),
+        Vec2::new(0., 1.),
+    ]);
+    let ids = polygon.vertices.ids().clone();
+    let (a, b) = split_by_diagonal(&polygon, ids[0], ids[2]).unwrap();
+    assert_eq!(a.vertices.len(), 3);
+    assert_eq!(b.vertices.len(), 3);
+    assert_eq!(a.edges.len(), 3);
+    // winding is kept
+    assert!((polygon_area(&a) - 0.5).abs() < 1e-6);
+    assert!((polygon_area(&b) - 0.5).abs() < 1e-6);
+    // adjacent vertices can't be split
+    assert!(split_by_diagonal(&polygon, ids[0], ids[1]).is_none());
+
+    // diagonal outside a concave (L shaped) polygon
+    let polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(2., 0.),
+        Vec2::new(2., 1.),
+        Vec2::new(1., 1.),
+        Vec2::new(1., 2.),
+        Vec2::new(0., 2.),
+    ]);
+    let ids = polygon.vertices.ids().clone();
+    assert!(split_by_diagonal(&polygon, ids[2], ids[4]).is_none());
+    assert!(split_by_diagonal(&polygon, ids[0], ids[3]).is_some());
+}
+
+#[test]
+fn test_split_by_polyline() {
+    use bevy::prelude::Vec2;
+
+    let polygon = MeshPolygon::from(vec![
+        Vec2::new(0., 0.),
+        Vec2::new(2., 0.),
+        Vec2::new(2., 2.),
+        Vec2::new(0., 2.),
+    ]);
+    let cut = vec![Vec2::new(0., 1.), Vec2::new(1., 1.5), Vec2::new(2., 1.)];
+    let (a, b) = split_by_polyline(&polygon, &cut, 0.01).unwrap();
+    assert_eq!(a.vertices.len() + b.vertices.len(), 4 + 2 * 3);
+    assert!((polygon_area(&a) + polygon_area(&b) - 4.).abs() < 1e-5);
+    assert!(polygon_area(&a) > 0. && polygon_area(&b) > 0.);
+
+    // snaps to existing vertices
+    let cut = vec![Vec2::new(0.005, 0.), Vec2::new(2., 2.)];
+    let (a, b) = split_by_polyline(&polygon, &cut, 0.01).unwrap();
+    assert_eq!(a.vertices.len(), 3);
+    assert_eq!(b.vertices.len(), 3);
+
+    // start not on boundary / leaving the polygon
+    let cut = vec![Vec2::new(0.5, 1.), Vec2::new(2., 1.)];
+    assert!(split_by_polyline(&polygon, &cut, 0.01).is_none());
+    let cut = vec![Vec2::new(0., 1.), Vec2::new(1., 3.), Vec2::new(2., 1.)];
+    assert!(split_by_polyline(&polygon, &cut, 0.01).is_none());
+}
@@ -0,0 +1,140 @@
+//! Conversion of freehand strokes (eg: sampled cursor positions) into [`MeshPolygon`]s.
+//!
+//! Strokes are treated as closed outlines, simplified using [`geo`] and
+//! optionally smoothed with Chaikin's corner cutting.
+
+use bevy::prelude::Vec2;
+use geo::{coord, ChaikinSmoothing, LineString, Simplify, SimplifyVw};
+
+use crate::data_structures::{vertices::Vertex, MeshPolygon};
+
+use super::geometry::signed_area;
+
+/// Algorithm used to simplify strokes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StrokeSimplification {
+    /// Ramer–Douglas–Peucker, `tolerance` is the max distance of removed points from the outline.
+    #[default]
+    RamerDouglasPeucker,
+    /// Visvalingam–Whyatt, `tolerance` squared is the min area of the triangle kept at each point.
+    Visvalingam,
+}
+
+/// Options used to convert strokes into polygons.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrokeOptions {
+    /// Algorithm used to simplify the stroke.
+    pub simplification: StrokeSimplification,
+    /// Simplification tolerance, in canvas units.
+    pub tolerance: f32,
+    /// Iterations of smoothing applied after simplification. `0` disables smoothing.
+    pub smoothing_iterations: u32,
+}
+
+impl Default for StrokeOptions {
+    fn default() -> Self {
+        Self {
+            simplification: StrokeSimplification::RamerDouglasPeucker,
+            tolerance: 0.1,
+            smoothing_iterations: 0,
+        }
+    }
+}
+
+/// Convert a stroke into a closed [`MeshPolygon`].
+///
+/// Returns `None` if less than 3 vertices remain after simplification or
+/// the outline encloses no area.
+pub fn stroke_to_mesh_polygon(stroke: &[Vertex], options: &StrokeOptions) -> Option<MeshPolygon> {
+    let mut points = stroke.to_vec();
+    points.dedup();
+    if points.len() < 3 {
+        return None;
+    }
+    // close the ring, so that simplification/smoothing wraps around
+    let mut line_string = LineString::from(
+        points
+            .iter()
+            .map(|point| coord! {x: point.x, y: point.y})
+            .collect::<Vec<_>>(),
+    );
+    line_string.close();
+    let tolerance = options.tolerance.max(0.);
+    let mut line_string = match options.simplification {
+        StrokeSimplification::RamerDouglasPeucker => line_string.simplify(&tolerance),
+        StrokeSimplification::Visvalingam => line_string.simplify_vw(&(tolerance * tolerance)),
+    };
+    if options.smoothing_iterations > 0 {
+        line_string = line_string.chaikin_smoothing(options.smoothing_iterations as usize);
+    }
+    let mut vertices = line_string
+        .coords()
+        .map(|coord| Vec2::new(coord.x, coord.y))
+        .collect::<Vec<_>>();
+    // drop the closing vertex, polygon closes itself
+    if vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    if vertices.len() < 3 || signed_area(&vertices).abs() <= f32::EPSILON {
+        return None;
+    }
+    Some(MeshPolygon::from(vertices))
+}
+
+#[cfg(test)]
+fn noisy_circle_stroke(samples: usize) -> Vec<Vertex> {
+    (0..samples)
+        .map(|index| {
+            let angle = std::f32::consts::TAU * index as f32 / samples as f32;
+            let noise = if index % 2 == 0 { 0.005 } else { -0.005 };
+            Vec2::from_angle(angle) * (1. + noise)
+        })
+        .collect()
+}
+
+#[test]
+fn test_stroke_simplification() {
+    let stroke = noisy_circle_stroke(400);
+    for simplification in [
+        StrokeSimplification::RamerDouglasPeucker,
+        StrokeSimplification::Visvalingam,
+    ] {
+        let options = StrokeOptions {
+            simplification,
+            tolerance: 0.05,
+            smoothing_iterations: 0,
+        };
+        let polygon = stroke_to_mesh_polygon(&stroke, &options).unwrap();
+        let vertices_len = polygon.vertices.len();
+        assert!((3..100).contains(&vertices_len), "{vertices_len}");
+        assert_eq!(polygon.edges.len(), vertices_len);
+        // simplified points stay on the stroke
+        for vertex in polygon.vertices.iter() {
+            assert!((vertex.length() - 1.).abs() < 0.01);
+        }
+    }
+}
+
+#[test]
+fn test_stroke_smoothing_and_degenerate() {
+    let square = vec![
+        Vec2::new(0., 0.),
+        Vec2::new(1., 0.),
+        Vec2::new(1., 1.),
+        Vec2::new(0., 1.),
+    ];
+    let options = StrokeOptions {
+        smoothing_iterations: 2,
+        ..Default::default()
+    };
+    let polygon = stroke_to_mesh_polygon(&square, &options).unwrap();
+    assert!(polygon.vertices.len() > square.len());
+    // corners are cut
+    for vertex in polygon.vertices.iter() {
+        assert!(!square.contains(vertex));
+    }
+
+    let line = vec![Vec2::ZERO, Vec2::X, Vec2::X * 2., Vec2::X * 3.];
+    assert!(stroke_to_mesh_polygon(&line, &StrokeOptions::default()).is_none());
+    assert!(stroke_to_mesh_polygon(&[Vec2::ZERO, Vec2::X], &StrokeOptions::default()).is_none());
+}
@@ -0,0 +1,310 @@
+//! Mirror and radial symmetry of [`MeshPolygon`]s.
+//!
+//! A symmetry has a number of images (transformations), the image `0` being
+//! the identity. Mirror symmetry has 2 images (identity and reflection),
+//! radial symmetry with `N` copies has `N` images (rotations by `k * 360 / N` degrees).
+
+use std::f32::consts::TAU;
+
+use crate::data_structures::{vertices::Vertex, MeshPolygon, VertexId};
+
+use super::geometry::map_points;
+
+/// Symmetry on the canvas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Symmetry {
+    /// Reflection about an axis.
+    Mirror {
+        /// Point on the axis.
+        origin: Vertex,
+        /// Direction of the axis.
+        direction: Vertex,
+    },
+    /// Rotation around a center.
+    Radial {
+        /// Center of the rotations.
+        center: Vertex,
+        /// Number of copies (including the original).
+        copies: u32,
+    },
+}
+
+impl Symmetry {
+    /// Number of images, including the identity.
+    pub fn image_count(&self) -> usize {
+        match *self {
+            Self::Mirror { .. } => 2,
+            Self::Radial { copies, .. } => copies.max(1) as usize,
+        }
+    }
+
+    /// Check if the image flips the winding (reflection).
+    pub fn is_reflection(&self, index: usize) -> bool {
+        matches!(self, Self::Mirror { .. }) && index % 2 == 1
+    }
+
+    /// Apply the image `index` to the point.
+    pub fn apply(&self, point: Vertex, index: usize) -> Vertex {
+        match *self {
+            Self::Mirror { origin, direction } => {
+                if !self.is_reflection(index) {
+                    return point;
+                }
+                let Some(direction) = direction.try_normalize() else {
+                    return point;
+                };
+                let offset = point - origin;
+                origin + direction * offset.dot(direction) * 2. - offset
+            }
+            Self::Radial { center, .. } => {
+                center + Vertex::from_angle(self.image_angle(index)).rotate(point - center)
+            }
+        }
+    }
+
+    /// Apply the inverse of the image `index` to the point.
+    pub fn apply_inverse(&self, point: Vertex, index: usize) -> Vertex {
+        match *self {
+            Self::Mirror { .. } => self.apply(point, index),
+            Self::Radial { center, .. } => {
+                center + Vertex::from_angle(-self.image_angle(index)).rotate(point - center)
+            }
+        }
+    }
+
+    /// Check if the point is its own image (on the mirror axis or at the radial center).
+    pub fn is_fixed_point(&self, point: Vertex, tolerance: f32) -> bool {
+        point.distance(self.fixed_point_nearest(point)) <= tolerance
+    }
+
+    /// Nearest point that is its own image (projection on the mirror axis or the radial center).
+    pub fn fixed_point_nearest(&self, point: Vertex) -> Vertex {
+        match *self {
+            Self::Mirror { origin, direction } => {
+                let Some(direction) = direction.try_normalize() else {
+                    return point;
+                };
+                origin + direction * (point - origin).dot(direction)
+            }
+            Self::Radial { center, .. } => center,
+        }
+    }
+
+    /// Apply the image `index` to the polygon (vertices and curves).
+    ///
+    /// Reflected polygons are reversed to keep their winding.
+    pub fn apply_to_polygon(&self, polygon: &MeshPolygon, index: usize) -> MeshPolygon {
+        map_points(
+            polygon,
+            |point| self.apply(point, index),
+            self.is_reflection(index),
+        )
+    }
+
+    /// Apply the inverse of the image `index` to the polygon (vertices and curves).
+    ///
+    /// Reflected polygons are reversed to keep their winding.
+    pub fn apply_inverse_to_polygon(&self, polygon: &MeshPolygon, index: usize) -> MeshPolygon {
+        map_points(
+            polygon,
+            |point| self.apply_inverse(point, index),
+            self.is_reflection(index),
+        )
+    }
+
+    /// Move the vertex of the symmetric polygon along with its symmetric counterparts.
+    ///
+    /// Counterparts are the vertices (within `tolerance`) at the images of the
+    /// current position of the vertex. Vertices on the mirror axis (at the
+    /// radial center) stay on it.
+    ///
+    /// Returns the moved vertices.
+    pub fn move_vertex(
+        &self,
+        polygon: &mut MeshPolygon,
+        vertex_id: VertexId,
+        position: Vertex,
+        tolerance: f32,
+    ) -> Vec<VertexId> {
+        let Some(current) = polygon.vertices.get(vertex_id).cloned() else {
+            return vec![];
+        };
+        let position = if self.is_fixed_point(current, tolerance) {
+            self.fixed_point_nearest(position)
+        } else {
+            position
+        };
+        let mut counterparts = vec![];
+        for index in 1..self.image_count() {
+            let image = self.apply(current, index);
+            if image.distance(current) <= tolerance {
+                continue;
+            }
+            let nearest = polygon
+                .vertices
+                .enumerate()
+                .filter(|(id, _)| *id != vertex_id)
+                .map(|(id, vertex)| (id, vertex.distance(image)))
+                .filter(|(_, distance)| *distance <= tolerance)
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((id, _)) = nearest {
+                counterparts.push((id, index));
+            }
+        }
+        let mut moved = vec![];
+        if polygon.move_vertex(vertex_id, position) {
+            moved.push(vertex_id);
+        }
+        for (id, index) in counterparts {
+            if polygon.move_vertex(id, self.apply(position, index)) {
+                moved.push(id);
+            }
+        }
+        moved
+    }
+
+    /// Angle of the radial image `index`.
+    fn image_angle(&self, index: usize) -> f32 {
+        TAU * index as f32 / self.image_count() as f32
+    }
+}
+
+/// Build a single (welded) symmetric polygon from the open `path` drawn in one
+/// half (mirror) or one sector (radial) of the symmetry.
+///
+/// Path ends within `tolerance` of the mirror axis (radial center or the
+/// start of the next sector) are welded with their images.
+///
+/// Returns `None` if the welded polygon has less than 3 vertices.
+pub fn symmetric_polygon(
+    path: &[Vertex],
+    symmetry: &Symmetry,
+    tolerance: f32,
+) -> Option<MeshPolygon> {
+    let mut path = path.to_vec();
+    let (first, last) = (*path.first()?, *path.last()?);
+    // snap the ends to be welded...
+    for (index, end) in [(0, first), (path.len() - 1, last)] {
+        if symmetry.is_fixed_point(end, tolerance) {
+            path[index] = symmetry.fixed_point_nearest(end);
+        }
+    }
+    if let Symmetry::Radial { .. } = symmetry {
+        let next_first = symmetry.apply(path[0], 1);
+        if let Some(last) = path.last_mut() {
+            if last.distance(next_first) <= tolerance {
+                *last = next_first;
+            }
+        }
+    }
+    let mut outline: Vec<Vertex> = vec![];
+    for index in 0..symmetry.image_count() {
+        let mut image: Vec<Vertex> = path
+            .iter()
+            .map(|point| symmetry.apply(*point, index))
+            .collect();
+        if symmetry.is_reflection(index) {
+            image.reverse();
+        }
+        outline.extend(image);
+    }
+    // weld consecutive (cyclic) duplicates...
+    let mut welded: Vec<Vertex> = vec![];
+    for point in outline {
+        if !matches!(welded.last(), Some(last) if last.distance(point) <= tolerance) {
+            welded.push(point);
+        }
+    }
+    while welded.len() > 1
+        && matches!((welded.first(), welded.last()), (Some(first), Some(last)) if first.distance(*last) <= tolerance)
+    {
+        welded.pop();
+    }
+    if welded.len() < 3 {
+        return None;
+    }
+    Some(MeshPolygon::from(welded))
+}
+
+#[test]
+fn test_mirror_symmetry() {
+    use bevy::prelude::Vec2;
+
+    // mirror about the z (y) axis
+    let symmetry = Symmetry::Mirror {
+        origin: Vec2::ZERO,
+        direction: Vec2::Y,
+    };
+    assert!(symmetry
+        .apply(Vec2::new(2., 1.), 1)
+        .abs_diff_eq(Vec2::new(-2., 1.), 1e-6));
+
+    // half of a house outline, ends on the axis
+    let path = vec![
+        Vec2::new(0.02, 0.),
+        Vec2::new(2., 0.),
+        Vec2::new(2., 2.),
+        Vec2::new(0., 3.),
+    ];
+    let polygon = symmetric_polygon(&path, &symmetry, 0.05).unwrap();
+    assert_eq!(polygon.vertices.len(), 6);
+    assert!((polygon.area() - 10.).abs() < 1e-4);
+
+    // moving a vertex moves its counterpart, axis vertices stay on the axis
+    let mut polygon = polygon;
+    let ids = polygon.vertices.ids().clone();
+    let moved = symmetry.move_vertex(&mut polygon, ids[1], Vec2::new(3., 0.), 0.05);
+    assert_eq!(moved.len(), 2);
+    assert!(polygon
+        .vertices
+        .iter()
+        .any(|vertex| vertex.abs_diff_eq(Vec2::new(-3., 0.), 1e-5)));
+    symmetry.move_vertex(&mut polygon, ids[0], Vec2::new(0.5, -1.), 0.05);
+    assert!(polygon
+        .vertices
+        .get(ids[0])
+        .unwrap()
+        .abs_diff_eq(Vec2::new(0., -1.), 1e-5));
+
+    // reflected copies keep the winding
+    let copy = symmetry.apply_to_polygon(&polygon, 1);
+    assert!((copy.signed_area() - polygon.signed_area()).abs() < 1e-4);
+    let back = symmetry.apply_inverse_to_polygon(&copy, 1);
+    for id in polygon.vertices.ids() {
+        assert!(back
+            .vertices
+            .get(*id)
+            .unwrap()
+            .abs_diff_eq(*polygon.vertices.get(*id).unwrap(), 1e-5));
+    }
+}
+
+#[test]
+fn test_radial_symmetry() {
+    use bevy::prelude::Vec2;
+
+    let symmetry = Symmetry::Radial {
+        center: Vec2::ZERO,
+        copies: 4,
+    };
+    assert!(symmetry
+        .apply(Vec2::new(1., 0.), 1)
+        .abs_diff_eq(Vec2::new(0., 1.), 1e-6));
+    assert!(symmetry
+        .apply_inverse(Vec2::new(0., 1.), 1)
+        .abs_diff_eq(Vec2::new(1., 0.), 1e-6));
+
+    // one side of a square, welded into the full square
+    let path = vec![Vec2::new(1., -1.), Vec2::new(1., 1.)];
+    let polygon = symmetric_polygon(&path, &symmetry, 0.01).unwrap();
+    assert_eq!(polygon.vertices.len(), 4);
+    assert!((polygon.area() - 4.).abs() < 1e-4);
+
+    // star like sector
+    let path = vec![Vec2::new(2., 0.), Vec2::new(0.5, 0.5)];
+    let polygon = symmetric_polygon(&path, &symmetry, 0.01).unwrap();
+    assert_eq!(polygon.vertices.len(), 8);
+
+    let copy = symmetry.apply_to_polygon(&polygon, 2);
+    assert!((copy.signed_area() - polygon.signed_area()).abs() < 1e-4);
+}
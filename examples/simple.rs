@@ -6,13 +6,25 @@
 //! `Key2`: For Create Mode (Allows creating new meshes created with this plugin)
 //! `MouseButton::Left` Click on Canvas: [Create Mode] Used to create vertex.
 //! `MouseButton::Right` Click on Canvas: [Create Mode] Used to close the polygon and extrude it into a Mesh.
+//! `Tab`: [Create Mode] Cycle create tools (polygon, rectangle, rotated rectangle, circle, ellipse, regular polygon, lasso). Shapes are dragged out (lasso sketched) holding `LMB`.
 //! `CtrlLeft` + `LMB` Click: [Edit Mode] Insert new vertex on edge
 //! `AltLeft` + `LMB` Click: [Edit Mode] Delete existing vertex.
+//! `ShiftLeft` + `LMB` Click: [Edit Mode] Toggle edge between straight and curved (drag its tangent handles to shape it).
+//! `KeyF` / `KeyC`: [Edit Mode] Round (fillet) / cut (chamfer) the corner at the last pressed vertex.
+//! `KeyK`: [Edit Mode] Toggle knife on selected mesh, `LMB` clicks the cut path from boundary to boundary (`RMB` cancels).
+//! `KeyS` + `LMB` Click on mesh: [Edit Mode] Add/remove mesh to/from multi selection, `KeyM` merges the selected adjacent meshes.
+//! `Delete`: [Edit Mode] Delete the selected mesh.
+//! `KeyD` / `KeyX`: [Edit Mode] Duplicate (offset) / mirror (across the canvas `z` axis) the selected mesh.
+//! `KeyA` / `KeyR`: [Edit Mode] Create a linear / circular (around the canvas origin) array of the selected mesh.
+//! `Enter`: Type exact coordinates (`x,z`, `@dx,dz` or `@length<angle`), `Enter` submits and `Escape` closes. [Create Mode] Adds the next vertex. [Edit Mode] Moves the last pressed vertex.
+//! `KeyL`: Show/hide the dimension labels (edge lengths and area).
+//! `KeyY`: Toggle symmetry (mirror about the canvas `z` axis by default). [Create Mode] The drawn path is welded with its mirror image. [Edit Mode] Moved vertices move their mirrored counterparts.
 
 use bevy::prelude::*;
 use bevy_mesh_drawing::prelude::{
-    Canvas, MeshDrawingCamera, MeshDrawingPlugin, MeshDrawingPluginInputBinds,
-    MeshDrawingPluginSettings, PolygonalMesh,
+    Canvas, DrawingModeChanged, MeshDrawingCamera, MeshDrawingPlugin, MeshDrawingPluginInputBinds,
+    MeshDrawingPluginSettings, PolygonalMesh, PolygonalMeshCreated, PolygonalMeshDeleted,
+    PolygonalMeshGeometryChanged, PolygonalMeshSelectionChanged,
 };
 
 pub fn main() {
@@ -37,7 +49,7 @@ pub fn main() {
         })
         .insert_resource(ClearColor(Color::srgb(0.0, 0.0, 0.0)))
         .add_systems(Startup, setup)
-        .add_systems(Update, handle_polygonal_mesh_add)
+        .add_systems(Update, handle_polygonal_mesh_events)
         .run();
 }
 
@@ -85,9 +97,43 @@ pub fn setup(
 }
 
 /// Drawn meshes will be created with [`PolygonalMesh`] component.
-pub fn handle_polygonal_mesh_add(query: Query<Entity, Added<PolygonalMesh>>) {
-    for entity in query.iter() {
+///
+/// Lifecycle events notify about created, changed, deleted and selected meshes.
+pub fn handle_polygonal_mesh_events(
+    mut created_events: EventReader<PolygonalMeshCreated>,
+    mut geometry_changed_events: EventReader<PolygonalMeshGeometryChanged>,
+    mut deleted_events: EventReader<PolygonalMeshDeleted>,
+    mut selection_changed_events: EventReader<PolygonalMeshSelectionChanged>,
+    mut mode_changed_events: EventReader<DrawingModeChanged>,
+    query: Query<&PolygonalMesh>,
+) {
+    for PolygonalMeshCreated { entity } in created_events.read() {
         // Use the created mesh here...
         info!("Created polygonal mesh: {:?}", entity);
     }
+    for PolygonalMeshGeometryChanged {
+        entity,
+        kind,
+        vertices,
+    } in geometry_changed_events.read()
+    {
+        if let Ok(polygonal_mesh) = query.get(*entity) {
+            info!(
+                "Polygonal mesh {:?} changed ({:?} {:?}), area: {:.2}",
+                entity,
+                kind,
+                vertices,
+                polygonal_mesh.mesh_polygon.area()
+            );
+        }
+    }
+    for PolygonalMeshDeleted { entity } in deleted_events.read() {
+        info!("Deleted polygonal mesh: {:?}", entity);
+    }
+    for PolygonalMeshSelectionChanged { selected, .. } in selection_changed_events.read() {
+        info!("Selected polygonal mesh: {:?}", selected);
+    }
+    for DrawingModeChanged { mode } in mode_changed_events.read() {
+        info!("Drawing mode: {:?}", mode);
+    }
 }